@@ -0,0 +1,93 @@
+//! Measures the cost of the connection to the driver.
+//!
+//! ```sh
+//! cargo bench --bench connection
+//! ```
+//!
+//! - idle: cpu time spent by this process while nothing is sent to the driver
+//! - screenshot: throughput of large `screenshot` responses
+use playwright::{api::Viewport, Playwright};
+use std::time::{Duration, Instant};
+
+const IDLE: Duration = Duration::from_secs(3);
+const SCREENSHOTS: usize = 20;
+
+/// Random pixels do not compress so every screenshot is several megabytes
+const NOISE: &str = r#"<canvas id="c" width="1920" height="1080"></canvas>
+<script>
+const c = document.getElementById('c').getContext('2d');
+const img = c.createImageData(1920, 1080);
+for (let i = 0; i < img.data.length; i++) img.data[i] = Math.random() * 256;
+c.putImageData(img, 0, 0);
+</script>"#;
+
+#[tokio::main]
+async fn main() -> Result<(), playwright::Error> {
+    let playwright = Playwright::initialize().await?;
+    playwright.install_chromium()?;
+    idle().await;
+    screenshot(&playwright).await?;
+    Ok(())
+}
+
+async fn idle() {
+    let before = cpu_time();
+    tokio::time::sleep(IDLE).await;
+    match (before, cpu_time()) {
+        (Some(a), Some(b)) => {
+            let used = b - a;
+            println!(
+                "idle: {:?} cpu in {:?} ({:.2}%)",
+                used,
+                IDLE,
+                used.as_secs_f64() / IDLE.as_secs_f64() * 100.
+            );
+        }
+        _ => println!("idle: cpu time is not available on this platform")
+    }
+}
+
+async fn screenshot(playwright: &Playwright) -> Result<(), playwright::Error> {
+    let browser = playwright.chromium().launcher().launch().await?;
+    let context = browser.context_builder().build().await?;
+    let page = context.new_page().await?;
+    page.set_viewport_size(Viewport {
+        width: 1920,
+        height: 1080
+    })
+    .await?;
+    page.set_content_builder(NOISE).set_content().await?;
+    // warm up
+    page.screenshot_builder().screenshot().await?;
+    let start = Instant::now();
+    let mut bytes = 0;
+    for _ in 0..SCREENSHOTS {
+        bytes += page.screenshot_builder().screenshot().await?.len();
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "screenshot: {} x {:.1} MiB in {:?} ({:.1} MiB/s)",
+        SCREENSHOTS,
+        bytes as f64 / SCREENSHOTS as f64 / 1024. / 1024.,
+        elapsed,
+        bytes as f64 / 1024. / 1024. / elapsed.as_secs_f64()
+    );
+    browser.close().await?;
+    Ok(())
+}
+
+#[cfg(unix)]
+fn cpu_time() -> Option<Duration> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    let usage = unsafe { usage.assume_init() };
+    let time = |t: libc::timeval| {
+        Duration::from_secs(t.tv_sec as u64) + Duration::from_micros(t.tv_usec as u64)
+    };
+    Some(time(usage.ru_utime) + time(usage.ru_stime))
+}
+
+#[cfg(not(unix))]
+fn cpu_time() -> Option<Duration> { None }
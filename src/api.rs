@@ -26,6 +26,89 @@ macro_rules! setter {
     };
 }
 
+/// Setters of [`crate::imp::screenshot::ScreenshotOptions`] which every screenshot builder has
+macro_rules! screenshot_options {
+    () => {
+        /// Hides default white background and allows capturing screenshots with transparency. Not applicable to `jpeg` images.
+        /// Defaults to `false`.
+        pub fn omit_background(mut self, x: bool) -> Self {
+            self.args.options.omit_background = Some(x);
+            self
+        }
+
+        /// When `Disabled`, stops CSS animations, CSS transitions and Web Animations. Finite animations are fast-forwarded to
+        /// completion and infinite ones are cancelled to their initial state. Defaults to `Allow`.
+        pub fn animations(mut self, x: ScreenshotAnimations) -> Self {
+            self.args.options.animations = Some(x);
+            self
+        }
+
+        /// When `Hide`, text caret is hidden. Defaults to `Hide`.
+        pub fn caret(mut self, x: ScreenshotCaret) -> Self {
+            self.args.options.caret = Some(x);
+            self
+        }
+
+        /// `Css` keeps a single pixel per css pixel, which makes screenshots smaller on high-dpi devices. Defaults to `Device`.
+        pub fn scale(mut self, x: ScreenshotScale) -> Self {
+            self.args.options.scale = Some(x);
+            self
+        }
+
+        /// Covers the elements which match the selectors, locators or handles with a box of [`Self::mask_color`].
+        /// Selectors are resolved in the main frame of a page, or in the frame of an element. ElementHandles are found by an
+        /// attribute which they are given during the screenshot.
+        pub fn mask<I, M>(mut self, x: I) -> Self
+        where
+            I: IntoIterator<Item = M>,
+            M: Into<Mask>
+        {
+            self.args.options.mask = Some(x.into_iter().map(|m| m.into().0).collect());
+            self
+        }
+
+        /// CSS color of the masks. Defaults to `#FF00FF`.
+        ///
+        /// The driver can't paint masks of other colors, so these are drawn by overlays which are added to the page and
+        /// removed after the screenshot. They cover the bounding boxes of the elements, which may be off for fixed or
+        /// transformed elements and elements in scroll containers.
+        pub fn mask_color(mut self, x: &str) -> Self {
+            self.args.options.mask_color = Some(x.into());
+            self
+        }
+
+        pub fn clear_omit_background(mut self) -> Self {
+            self.args.options.omit_background = None;
+            self
+        }
+
+        pub fn clear_animations(mut self) -> Self {
+            self.args.options.animations = None;
+            self
+        }
+
+        pub fn clear_caret(mut self) -> Self {
+            self.args.options.caret = None;
+            self
+        }
+
+        pub fn clear_scale(mut self) -> Self {
+            self.args.options.scale = None;
+            self
+        }
+
+        pub fn clear_mask(mut self) -> Self {
+            self.args.options.mask = None;
+            self
+        }
+
+        pub fn clear_mask_color(mut self) -> Self {
+            self.args.options.mask_color = None;
+            self
+        }
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! subscribe_event {
@@ -51,8 +134,14 @@ pub mod input_device;
 pub mod playwright;
 
 pub mod accessibility;
+pub mod api_request_context;
+pub mod api_response;
+pub mod assertions;
+pub mod binding_call;
 pub mod browser;
 pub mod browser_context;
+pub mod browser_installer;
+pub mod browser_server;
 pub mod browser_type;
 pub mod console_message;
 pub mod dialog;
@@ -61,11 +150,13 @@ pub mod element_handle;
 pub mod file_chooser;
 pub mod frame;
 pub mod js_handle;
+pub mod locator;
 pub mod page;
 pub mod request;
 pub mod response;
 pub mod route;
 pub mod selectors;
+pub mod tracing;
 pub mod video;
 pub mod websocket;
 pub mod worker;
@@ -74,28 +165,39 @@ pub use crate::imp::{core::DateTime, utils::*};
 
 pub use self::playwright::Playwright;
 pub use accessibility::Accessibility;
+pub use api_request_context::{ApiRequest, ApiRequestContext};
+pub use api_response::ApiResponse;
+pub use assertions::{
+    expect, ApiResponseAssertions, LocatorAssertions, PageAssertions, TextMatcher
+};
+pub use binding_call::BindingSource;
 pub use browser::Browser;
 pub use browser_context::BrowserContext;
+pub use browser_installer::{
+    BrowserInstaller, InstallDepsReport, InstallProgress, InstalledBrowser, RequiredBrowser
+};
+pub use browser_server::BrowserServer;
 pub use browser_type::BrowserType;
 pub use console_message::ConsoleMessage;
 pub use dialog::Dialog;
 pub use download::Download;
-pub use element_handle::ElementHandle;
+pub use element_handle::{ElementHandle, Mask};
 pub use file_chooser::FileChooser;
 pub use frame::Frame;
 pub use input_device::{Keyboard, Mouse, TouchScreen};
 pub use js_handle::JsHandle;
+pub use locator::{FrameLocator, Locator};
 pub use page::Page;
 pub use request::Request;
 pub use response::Response;
 pub use route::Route;
 pub use selectors::Selectors;
+pub use tracing::Tracing;
 pub use video::Video;
 pub use websocket::WebSocket;
 pub use worker::Worker;
 
 // Artifact
-// BindingCall
 // Stream
 
 // Android
@@ -0,0 +1,264 @@
+use crate::{
+    api::ApiResponse,
+    imp::{
+        api_request_context::{ApiRequestContext as Impl, FetchArgs, FormField, NewRequestArgs},
+        core::*,
+        playwright::Playwright,
+        prelude::*,
+        utils::{File, Header, HttpCredentials, ProxySettings, StorageState}
+    },
+    Error
+};
+
+/// Exposes API that can be used for the Web API testing. Each Playwright browser context has an [`ApiRequestContext`]
+/// instance attached which shares cookies with the page context. Its also possible to create a new [`ApiRequestContext`]
+/// instance manually via [`ApiRequest::new_context_builder`].
+pub struct ApiRequest {
+    inner: Weak<Playwright>
+}
+
+impl ApiRequest {
+    pub(crate) fn new(inner: Weak<Playwright>) -> Self { Self { inner } }
+
+    /// Creates new instances of [`ApiRequestContext`].
+    pub fn new_context_builder(&self) -> NewContextBuilder<'_, '_> {
+        NewContextBuilder::new(self.inner.clone())
+    }
+}
+
+/// [`ApiRequest::new_context_builder`]
+pub struct NewContextBuilder<'a, 'b> {
+    inner: Weak<Playwright>,
+    args: NewRequestArgs<'a, 'b>
+}
+
+impl<'a, 'b> NewContextBuilder<'a, 'b> {
+    fn new(inner: Weak<Playwright>) -> Self {
+        Self {
+            inner,
+            args: NewRequestArgs::default()
+        }
+    }
+
+    pub async fn new_context(self) -> ArcResult<ApiRequestContext> {
+        let Self { inner, args } = self;
+        let r = upgrade(&inner)?.new_request(args).await?;
+        Ok(ApiRequestContext::new(r))
+    }
+
+    /// An object containing additional HTTP headers to be sent with every request.
+    pub fn extra_http_headers<T>(mut self, headers: T) -> Self
+    where
+        T: IntoIterator<Item = (String, String)>
+    {
+        self.args.extra_http_headers = Some(headers.into_iter().map(Header::from).collect());
+        self
+    }
+
+    pub fn clear_extra_http_headers(mut self) -> Self {
+        self.args.extra_http_headers = None;
+        self
+    }
+
+    setter! {
+        /// Methods like [`ApiRequestContext::get_builder`] take the base URL into consideration by using the
+        /// [`URL()`](https://developer.mozilla.org/en-US/docs/Web/API/URL/URL) constructor for building the corresponding URL.
+        base_url: Option<&'a str>,
+        /// Credentials for [HTTP authentication](https://developer.mozilla.org/en-US/docs/Web/HTTP/Authentication).
+        http_credentials: Option<HttpCredentials>,
+        /// Whether to ignore HTTPS errors when sending network requests. Defaults to `false`.
+        ignore_https_errors: Option<bool>,
+        /// Network proxy settings.
+        proxy: Option<ProxySettings>,
+        /// Populates context with given storage state. This option can be used to initialize context with logged-in
+        /// information obtained via [`method: BrowserContext.storageState`] or [`method: APIRequestContext.storageState`].
+        storage_state: Option<StorageState>,
+        /// Maximum time in milliseconds to wait for the response. Defaults to `30000` (30 seconds). Pass `0` to disable
+        /// timeout.
+        timeout: Option<f64>,
+        /// Specific user agent to use in this context.
+        user_agent: Option<&'b str>
+    }
+}
+
+/// This API is used for the Web API testing. You can use it to trigger API endpoints, configure micro-services, prepare
+/// environment or the service to your e2e test. When used on [`BrowserContext`](crate::api::BrowserContext), the cookies
+/// are shared with the browser context and the responses update them.
+///
+/// ```js
+/// const context = await browser.newContext();
+/// await context.request.post('https://example.com/api/login', { form: { user: 'user', password: 'pass' } });
+/// // The page is now logged in.
+/// const page = await context.newPage();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ApiRequestContext {
+    inner: Weak<Impl>
+}
+
+impl PartialEq for ApiRequestContext {
+    fn eq(&self, other: &Self) -> bool {
+        let a = self.inner.upgrade();
+        let b = other.inner.upgrade();
+        a.and_then(|a| b.map(|b| (a, b)))
+            .map(|(a, b)| a.guid() == b.guid())
+            .unwrap_or_default()
+    }
+}
+
+macro_rules! method_builder {
+    ($f: ident, $m: literal) => {
+        #[doc = concat!("Sends HTTP(S) [", $m, "](https://developer.mozilla.org/en-US/docs/Web/HTTP/Methods/", $m, ") request and returns its response.")]
+        pub fn $f<'a>(&self, url: &'a str) -> FetchBuilder<'a> {
+            FetchBuilder::new(self.inner.clone(), url, Some($m))
+        }
+    };
+}
+
+impl ApiRequestContext {
+    pub(crate) fn new(inner: Weak<Impl>) -> Self { Self { inner } }
+
+    /// Sends HTTP(S) request and returns its response. The method defaults to `GET` unless the request has data.
+    pub fn fetch_builder<'a>(&self, url: &'a str) -> FetchBuilder<'a> {
+        FetchBuilder::new(self.inner.clone(), url, None)
+    }
+
+    method_builder! {delete_builder, "DELETE"}
+    method_builder! {get_builder, "GET"}
+    method_builder! {head_builder, "HEAD"}
+    method_builder! {patch_builder, "PATCH"}
+    method_builder! {post_builder, "POST"}
+    method_builder! {put_builder, "PUT"}
+
+    /// Returns storage state for this request context, contains current cookies and local storage snapshot if it was
+    /// passed to the constructor.
+    pub async fn storage_state(&self) -> ArcResult<StorageState> {
+        upgrade(&self.inner)?.storage_state().await
+    }
+
+    /// All responses returned by [`FetchBuilder::fetch`] are stored in the memory, so that you can later call
+    /// [`ApiResponse::body`]. This method discards all stored responses, and makes [`ApiResponse::body`] throw "Response
+    /// disposed" error.
+    pub async fn dispose(&self) -> ArcResult<()> { upgrade(&self.inner)?.dispose().await }
+}
+
+/// [`ApiRequestContext::fetch_builder`] and the builders of each HTTP method
+pub struct FetchBuilder<'a> {
+    inner: Weak<Impl>,
+    args: FetchArgs<'a>
+}
+
+impl<'a> FetchBuilder<'a> {
+    fn new(inner: Weak<Impl>, url: &'a str, method: Option<&'a str>) -> Self {
+        Self {
+            inner,
+            args: FetchArgs::new(url, method)
+        }
+    }
+
+    pub async fn fetch(self) -> ArcResult<ApiResponse> {
+        let Self { inner, args } = self;
+        let res = upgrade(&inner)?.fetch(args).await?;
+        Ok(ApiResponse::new(inner, res))
+    }
+
+    /// Allows to set HTTP headers.
+    pub fn headers<T>(mut self, headers: T) -> Self
+    where
+        T: IntoIterator<Item = (String, String)>
+    {
+        self.args.headers = Some(headers.into_iter().map(Header::from).collect());
+        self
+    }
+
+    /// Query parameters to be sent with the URL.
+    pub fn params<T>(mut self, params: T) -> Self
+    where
+        T: IntoIterator<Item = (String, String)>
+    {
+        self.args.params = Some(params.into_iter().map(Header::from).collect());
+        self
+    }
+
+    /// Sets the post data of the request.
+    pub fn data(mut self, x: &[u8]) -> Self {
+        self.args.post_data = Some(base64::encode(x));
+        self
+    }
+
+    /// Serializes `x` as the json body of the request and sets `content-type` header to `application/json` if not
+    /// explicitly set.
+    pub fn json<T: Serialize>(mut self, x: &T) -> Result<Self, Error> {
+        self.args.json_data = Some(serde_json::to_value(x)?);
+        Ok(self)
+    }
+
+    /// Sends the fields as html form using `application/x-www-form-urlencoded` encoding and sets `content-type` header to
+    /// `application/x-www-form-urlencoded` if not explicitly set.
+    pub fn form<T>(mut self, fields: T) -> Self
+    where
+        T: IntoIterator<Item = (String, String)>
+    {
+        self.args.form_data = Some(fields.into_iter().map(Header::from).collect());
+        self
+    }
+
+    /// Adds a text field to the request body sent using `multipart/form-data` encoding, and sets `content-type` header to
+    /// `multipart/form-data` if not explicitly set.
+    pub fn multipart_field(mut self, name: &str, value: &str) -> Self {
+        self.args
+            .multipart_data
+            .get_or_insert_with(Vec::new)
+            .push(FormField {
+                name: name.into(),
+                value: Some(value.into()),
+                file: None
+            });
+        self
+    }
+
+    /// Adds a file field to the request body sent using `multipart/form-data` encoding.
+    pub fn multipart_file(mut self, name: &str, file: File) -> Self {
+        self.args
+            .multipart_data
+            .get_or_insert_with(Vec::new)
+            .push(FormField {
+                name: name.into(),
+                value: None,
+                file: Some(file)
+            });
+        self
+    }
+
+    pub fn clear_headers(mut self) -> Self {
+        self.args.headers = None;
+        self
+    }
+
+    pub fn clear_params(mut self) -> Self {
+        self.args.params = None;
+        self
+    }
+
+    /// Clears the post data, json, form and multipart body.
+    pub fn clear_data(mut self) -> Self {
+        self.args.post_data = None;
+        self.args.json_data = None;
+        self.args.form_data = None;
+        self.args.multipart_data = None;
+        self
+    }
+
+    setter! {
+        /// Whether to throw on response codes other than 2xx and 3xx. By default response object is returned for all status
+        /// codes.
+        fail_on_status_code: Option<bool>,
+        /// Whether to ignore HTTPS errors when sending network requests. Defaults to `false`.
+        ignore_https_errors: Option<bool>,
+        /// If set changes the fetch method (e.g. [PUT](https://developer.mozilla.org/en-US/docs/Web/HTTP/Methods/PUT) or
+        /// [POST](https://developer.mozilla.org/en-US/docs/Web/HTTP/Methods/POST)). If not specified, GET method is used.
+        method: Option<&'a str>,
+        /// Request timeout in milliseconds. Defaults to `30000` (30 seconds). Pass `0` to disable timeout.
+        timeout: Option<f64>
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    imp::{
+        api_request_context::{ApiRequestContext, FetchedResponse},
+        core::*,
+        prelude::*,
+        utils::Header
+    },
+    Error
+};
+
+/// [`ApiResponse`] class represents responses returned by [`FetchBuilder::fetch`](crate::api::api_request_context::FetchBuilder::fetch).
+#[derive(Debug, Clone)]
+pub struct ApiResponse {
+    context: Weak<ApiRequestContext>,
+    inner: FetchedResponse
+}
+
+impl ApiResponse {
+    pub(crate) fn new(context: Weak<ApiRequestContext>, inner: FetchedResponse) -> Self {
+        Self { context, inner }
+    }
+
+    /// Contains the URL of the response.
+    pub fn url(&self) -> &str { &self.inner.url }
+
+    /// Contains the status code of the response (e.g., 200 for a success).
+    pub fn status(&self) -> i32 { self.inner.status }
+
+    /// Contains the status text of the response (e.g. usually an "OK" for a success).
+    pub fn status_text(&self) -> &str { &self.inner.status_text }
+
+    /// Contains a boolean stating whether the response was successful (status in the range 200-299) or not.
+    pub fn ok(&self) -> bool { (200..300).contains(&self.inner.status) }
+
+    /// An array with all the request HTTP headers associated with this response. Header names are lower-cased.
+    pub fn headers(&self) -> &[Header] { &self.inner.headers }
+
+    /// Returns the value of the first header with the given name. The name is case insensitive.
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        self.inner
+            .headers
+            .iter()
+            .find(|h| h.name.eq_ignore_ascii_case(name))
+            .map(|h| &*h.value)
+    }
+
+    /// Returns the buffer with response body.
+    pub async fn body(&self) -> ArcResult<Vec<u8>> {
+        upgrade(&self.context)?
+            .fetch_response_body(&self.inner.fetch_uid)
+            .await
+    }
+
+    /// Returns the text representation of response body.
+    pub async fn text(&self) -> ArcResult<String> {
+        Ok(String::from_utf8(self.body().await?).map_err(Error::InvalidUtf8)?)
+    }
+
+    /// Returns the JSON representation of response body.
+    pub async fn json<T: DeserializeOwned>(&self) -> ArcResult<T> {
+        Ok(serde_json::from_slice(&self.body().await?).map_err(Error::Serde)?)
+    }
+
+    /// Disposes the body of this response. If not called then the body will stay in memory until the context closes.
+    pub async fn dispose(&self) -> ArcResult<()> {
+        upgrade(&self.context)?
+            .dispose_api_response(&self.inner.fetch_uid)
+            .await
+    }
+
+    pub(crate) async fn log(&self) -> ArcResult<Vec<String>> {
+        upgrade(&self.context)?
+            .fetch_log(&self.inner.fetch_uid)
+            .await
+    }
+}
@@ -0,0 +1,495 @@
+use crate::{
+    api::{ApiResponse, Locator, Page},
+    imp::{
+        core::*,
+        frame::{ExpectArgs, ExpectResult, ExpectedTextValue},
+        prelude::*
+    },
+    Error
+};
+use regex::Regex;
+use std::fmt;
+
+/// Default time in milliseconds assertions keep retrying.
+pub const DEFAULT_EXPECT_TIMEOUT: f64 = 5000.;
+
+/// Creates web-first assertions that wait until the condition is met or the timeout expires.
+///
+/// ```js
+/// await expect(page.locator('.status')).toHaveText('Submitted');
+/// await expect(page).toHaveTitle(/Playwright/);
+/// ```
+pub fn expect<T: Expect>(actual: T) -> T::Assertions { actual.expect() }
+
+pub trait Expect {
+    type Assertions;
+
+    fn expect(self) -> Self::Assertions;
+}
+
+impl Expect for &Locator {
+    type Assertions = LocatorAssertions;
+
+    fn expect(self) -> LocatorAssertions {
+        LocatorAssertions {
+            locator: self.clone(),
+            is_not: false,
+            timeout: None
+        }
+    }
+}
+
+impl Expect for &Page {
+    type Assertions = PageAssertions;
+
+    fn expect(self) -> PageAssertions {
+        PageAssertions {
+            root: self.locator(":root"),
+            is_not: false,
+            timeout: None
+        }
+    }
+}
+
+impl Expect for &ApiResponse {
+    type Assertions = ApiResponseAssertions;
+
+    fn expect(self) -> ApiResponseAssertions {
+        ApiResponseAssertions {
+            response: self.clone(),
+            is_not: false
+        }
+    }
+}
+
+/// Expected text. Strings are compared as they are and regular expressions are evaluated in the browser, so only the
+/// syntax common to Rust and JavaScript is portable.
+#[derive(Debug, Clone)]
+pub enum TextMatcher {
+    Text(String),
+    Regex(Regex)
+}
+
+impl TextMatcher {
+    fn to_expected(&self, match_substring: bool, normalize_white_space: bool) -> ExpectedTextValue {
+        let mut x = ExpectedTextValue {
+            match_substring: Some(match_substring),
+            normalize_white_space: Some(normalize_white_space),
+            ..ExpectedTextValue::default()
+        };
+        match self {
+            Self::Text(s) => x.string = Some(s.clone()),
+            Self::Regex(r) => x.regex_source = Some(r.as_str().to_owned())
+        }
+        x
+    }
+}
+
+impl fmt::Display for TextMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(s) => write!(f, "{:?}", s),
+            Self::Regex(r) => write!(f, "/{}/", r.as_str())
+        }
+    }
+}
+
+impl From<&str> for TextMatcher {
+    fn from(x: &str) -> Self { Self::Text(x.to_owned()) }
+}
+
+impl From<String> for TextMatcher {
+    fn from(x: String) -> Self { Self::Text(x) }
+}
+
+impl From<Regex> for TextMatcher {
+    fn from(x: Regex) -> Self { Self::Regex(x) }
+}
+
+macro_rules! state {
+    ($f: ident, $e: literal, $m: literal) => {
+        #[doc = concat!("Ensures the locator points to ", $m, " element.")]
+        pub async fn $f(&self) -> ArcResult<()> {
+            let args = ExpectArgs::new(self.locator.selector(), $e);
+            self.expect_impl(args, None, concat!("Locator expected to be ", $m))
+                .await
+        }
+    };
+}
+
+/// Assertions on [`Locator`] created by [`expect`].
+#[derive(Debug, Clone)]
+pub struct LocatorAssertions {
+    locator: Locator,
+    is_not: bool,
+    timeout: Option<f64>
+}
+
+impl LocatorAssertions {
+    /// Makes the assertion check for the opposite condition.
+    pub fn not(mut self) -> Self {
+        self.is_not = !self.is_not;
+        self
+    }
+
+    /// Time to retry the assertion for in milliseconds. Defaults to [`DEFAULT_EXPECT_TIMEOUT`].
+    pub fn timeout(mut self, x: f64) -> Self {
+        self.timeout = Some(x);
+        self
+    }
+
+    state! {to_be_checked, "to.be.checked", "checked"}
+    state! {to_be_disabled, "to.be.disabled", "disabled"}
+    state! {to_be_editable, "to.be.editable", "editable"}
+    state! {to_be_empty, "to.be.empty", "empty"}
+    state! {to_be_enabled, "to.be.enabled", "enabled"}
+    state! {to_be_focused, "to.be.focused", "focused"}
+    state! {to_be_hidden, "to.be.hidden", "hidden"}
+    state! {to_be_visible, "to.be.visible", "visible"}
+
+    /// Ensures the locator points to an element that contains the given text.
+    pub async fn to_contain_text<M: Into<TextMatcher>>(&self, expected: M) -> ArcResult<()> {
+        let expected = expected.into();
+        let mut args = ExpectArgs::new(self.locator.selector(), "to.have.text");
+        args.expected_text = Some(vec![expected.to_expected(true, true)]);
+        self.expect_impl(
+            args,
+            Some(expected.to_string()),
+            "Locator expected to contain text"
+        )
+        .await
+    }
+
+    /// Ensures the locator points to an element with given attribute.
+    pub async fn to_have_attribute<M: Into<TextMatcher>>(
+        &self,
+        name: &str,
+        value: M
+    ) -> ArcResult<()> {
+        let expected = value.into();
+        let mut args = ExpectArgs::new(self.locator.selector(), "to.have.attribute");
+        args.expression_arg = Some(name.into());
+        args.expected_text = Some(vec![expected.to_expected(false, false)]);
+        self.expect_impl(
+            args,
+            Some(expected.to_string()),
+            "Locator expected to have attribute"
+        )
+        .await
+    }
+
+    /// Ensures the locator points to an element with given CSS class.
+    pub async fn to_have_class<M: Into<TextMatcher>>(&self, expected: M) -> ArcResult<()> {
+        let expected = expected.into();
+        let mut args = ExpectArgs::new(self.locator.selector(), "to.have.class");
+        args.expected_text = Some(vec![expected.to_expected(false, false)]);
+        self.expect_impl(
+            args,
+            Some(expected.to_string()),
+            "Locator expected to have class"
+        )
+        .await
+    }
+
+    /// Ensures the locator resolves to an exact number of DOM nodes.
+    pub async fn to_have_count(&self, count: usize) -> ArcResult<()> {
+        let mut args = ExpectArgs::new(self.locator.selector(), "to.have.count");
+        args.expected_number = Some(count as f64);
+        self.expect_impl(
+            args,
+            Some(count.to_string()),
+            "Locator expected to have count"
+        )
+        .await
+    }
+
+    /// Ensures the locator resolves to an element with the given computed CSS style.
+    pub async fn to_have_css<M: Into<TextMatcher>>(&self, name: &str, value: M) -> ArcResult<()> {
+        let expected = value.into();
+        let mut args = ExpectArgs::new(self.locator.selector(), "to.have.css");
+        args.expression_arg = Some(name.into());
+        args.expected_text = Some(vec![expected.to_expected(false, false)]);
+        self.expect_impl(
+            args,
+            Some(expected.to_string()),
+            "Locator expected to have CSS"
+        )
+        .await
+    }
+
+    /// Ensures the locator points to an element with the given DOM Node ID.
+    pub async fn to_have_id<M: Into<TextMatcher>>(&self, id: M) -> ArcResult<()> {
+        let expected = id.into();
+        let mut args = ExpectArgs::new(self.locator.selector(), "to.have.id");
+        args.expected_text = Some(vec![expected.to_expected(false, false)]);
+        self.expect_impl(
+            args,
+            Some(expected.to_string()),
+            "Locator expected to have ID"
+        )
+        .await
+    }
+
+    /// Ensures the locator points to an element with given JavaScript property.
+    pub async fn to_have_js_property<T: Serialize>(&self, name: &str, value: T) -> ArcResult<()> {
+        let expected = serde_json::to_string(&value).map_err(Error::Serde)?;
+        let mut args = ExpectArgs::new(self.locator.selector(), "to.have.property");
+        args.expression_arg = Some(name.into());
+        args.expected_value = Some(ser::to_value(&value).map_err(Error::SerializationPwJson)?);
+        self.expect_impl(args, Some(expected), "Locator expected to have JS Property")
+            .await
+    }
+
+    /// Ensures the locator points to an element with the given text. Whitespaces are normalized.
+    pub async fn to_have_text<M: Into<TextMatcher>>(&self, expected: M) -> ArcResult<()> {
+        let expected = expected.into();
+        let mut args = ExpectArgs::new(self.locator.selector(), "to.have.text");
+        args.expected_text = Some(vec![expected.to_expected(false, true)]);
+        self.expect_impl(
+            args,
+            Some(expected.to_string()),
+            "Locator expected to have text"
+        )
+        .await
+    }
+
+    /// Ensures the locator resolves to elements with the given texts in order.
+    pub async fn to_have_texts<I, M>(&self, expected: I) -> ArcResult<()>
+    where
+        I: IntoIterator<Item = M>,
+        M: Into<TextMatcher>
+    {
+        let expected: Vec<TextMatcher> = expected.into_iter().map(Into::into).collect();
+        let mut args = ExpectArgs::new(self.locator.selector(), "to.have.text.array");
+        args.expected_text = Some(
+            expected
+                .iter()
+                .map(|x| x.to_expected(false, true))
+                .collect()
+        );
+        let expected = expected
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.expect_impl(
+            args,
+            Some(format!("[{}]", expected)),
+            "Locator expected to have text"
+        )
+        .await
+    }
+
+    /// Ensures the locator points to an element with the given input value.
+    pub async fn to_have_value<M: Into<TextMatcher>>(&self, value: M) -> ArcResult<()> {
+        let expected = value.into();
+        let mut args = ExpectArgs::new(self.locator.selector(), "to.have.value");
+        args.expected_text = Some(vec![expected.to_expected(false, false)]);
+        self.expect_impl(
+            args,
+            Some(expected.to_string()),
+            "Locator expected to have Value"
+        )
+        .await
+    }
+
+    async fn expect_impl(
+        &self,
+        args: ExpectArgs<'_>,
+        expected: Option<String>,
+        message: &str
+    ) -> ArcResult<()> {
+        expect_impl(
+            &self.locator,
+            args,
+            self.is_not,
+            self.timeout,
+            expected,
+            message
+        )
+        .await
+    }
+}
+
+/// Assertions on [`Page`] created by [`expect`].
+#[derive(Debug, Clone)]
+pub struct PageAssertions {
+    root: Locator,
+    is_not: bool,
+    timeout: Option<f64>
+}
+
+impl PageAssertions {
+    /// Makes the assertion check for the opposite condition.
+    pub fn not(mut self) -> Self {
+        self.is_not = !self.is_not;
+        self
+    }
+
+    /// Time to retry the assertion for in milliseconds. Defaults to [`DEFAULT_EXPECT_TIMEOUT`].
+    pub fn timeout(mut self, x: f64) -> Self {
+        self.timeout = Some(x);
+        self
+    }
+
+    /// Ensures the page has the given title.
+    pub async fn to_have_title<M: Into<TextMatcher>>(&self, expected: M) -> ArcResult<()> {
+        let expected = expected.into();
+        let mut args = ExpectArgs::new(self.root.selector(), "to.have.title");
+        args.expected_text = Some(vec![expected.to_expected(false, true)]);
+        expect_impl(
+            &self.root,
+            args,
+            self.is_not,
+            self.timeout,
+            Some(expected.to_string()),
+            "Page title expected to be"
+        )
+        .await
+    }
+
+    /// Ensures the page is navigated to the given URL.
+    pub async fn to_have_url<M: Into<TextMatcher>>(&self, expected: M) -> ArcResult<()> {
+        let expected = expected.into();
+        let mut args = ExpectArgs::new(self.root.selector(), "to.have.url");
+        args.expected_text = Some(vec![expected.to_expected(false, false)]);
+        expect_impl(
+            &self.root,
+            args,
+            self.is_not,
+            self.timeout,
+            Some(expected.to_string()),
+            "Page URL expected to be"
+        )
+        .await
+    }
+}
+
+/// Assertions on [`ApiResponse`] created by [`expect`].
+#[derive(Debug, Clone)]
+pub struct ApiResponseAssertions {
+    response: ApiResponse,
+    is_not: bool
+}
+
+impl ApiResponseAssertions {
+    /// Makes the assertion check for the opposite condition.
+    pub fn not(mut self) -> Self {
+        self.is_not = !self.is_not;
+        self
+    }
+
+    /// Ensures the response status code is within `200..299` range.
+    pub async fn to_be_ok(&self) -> ArcResult<()> {
+        if self.response.ok() != self.is_not {
+            return Ok(());
+        }
+        let result = ExpectResult {
+            matches: self.response.ok(),
+            received: Some(self.response.status().into()),
+            log: self.response.log().await?
+        };
+        let message = failure_message(
+            "Response status expected to be within [200..299] range",
+            self.is_not,
+            None,
+            &result
+        );
+        Err(Error::AssertionFailed(message).into())
+    }
+}
+
+async fn expect_impl(
+    locator: &Locator,
+    mut args: ExpectArgs<'_>,
+    is_not: bool,
+    timeout: Option<f64>,
+    expected: Option<String>,
+    message: &str
+) -> ArcResult<()> {
+    args.is_not = is_not;
+    args.timeout = Some(timeout.unwrap_or(DEFAULT_EXPECT_TIMEOUT));
+    let result = upgrade(&locator.frame())?.expect(args).await?;
+    if result.matches == is_not {
+        let message = failure_message(message, is_not, expected.as_deref(), &result);
+        return Err(Error::AssertionFailed(message).into());
+    }
+    Ok(())
+}
+
+fn failure_message(
+    message: &str,
+    is_not: bool,
+    expected: Option<&str>,
+    result: &ExpectResult
+) -> String {
+    let mut s = if is_not {
+        message.replacen("expected to", "expected not to", 1)
+    } else {
+        message.to_owned()
+    };
+    if let Some(expected) = expected {
+        s.push_str(&format!("\nExpected: {}", expected));
+    }
+    match &result.received {
+        Some(v) => s.push_str(&format!("\nReceived: {}", v)),
+        None => s.push_str("\nReceived: <none>")
+    }
+    let log: Vec<&str> = result
+        .log
+        .iter()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .collect();
+    if !log.is_empty() {
+        s.push_str("\nCall log:");
+        for l in log {
+            s.push_str("\n  - ");
+            s.push_str(l);
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message() {
+        let result = ExpectResult {
+            matches: false,
+            received: Some("bar".into()),
+            log: vec!["  waiting for selector \"p\"".into(), "".into()]
+        };
+        let s = failure_message(
+            "Locator expected to have text",
+            false,
+            Some("\"foo\""),
+            &result
+        );
+        assert_eq!(
+            s,
+            "Locator expected to have text\nExpected: \"foo\"\nReceived: \"bar\"\nCall log:\n  - waiting for selector \"p\""
+        );
+        let result = ExpectResult {
+            matches: true,
+            received: None,
+            log: vec![]
+        };
+        let s = failure_message("Locator expected to be visible", true, None, &result);
+        assert_eq!(s, "Locator expected not to be visible\nReceived: <none>");
+    }
+
+    #[test]
+    fn text_matcher() {
+        let m = TextMatcher::from(Regex::new("^a.c$").unwrap());
+        assert_eq!(m.to_string(), "/^a.c$/");
+        let v = serde_json::to_value(m.to_expected(true, false)).unwrap();
+        assert_eq!(
+            v,
+            serde_json::json!({"regexSource": "^a.c$", "matchSubstring": true, "normalizeWhiteSpace": false})
+        );
+        assert_eq!(TextMatcher::from("a").to_string(), "\"a\"");
+    }
+}
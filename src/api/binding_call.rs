@@ -0,0 +1,45 @@
+use crate::{
+    api::{BrowserContext, Frame, Page},
+    imp::{binding_call::BindingFn, core::*, frame::Frame as FrameImpl, prelude::*}
+};
+use std::fmt::Display;
+
+/// The caller of a function exposed with [`Page::expose_binding`] or
+/// [`BrowserContext::expose_binding`].
+pub struct BindingSource {
+    pub context: BrowserContext,
+    pub page: Page,
+    pub frame: Frame
+}
+
+impl BindingSource {
+    fn new(frame: Weak<FrameImpl>) -> Self {
+        let page = weak_and_then(&frame, |rc| rc.page().unwrap_or_default());
+        let context = weak_and_then(&page, |rc| rc.browser_context());
+        Self {
+            context: BrowserContext::new(context),
+            page: Page::new(page),
+            frame: Frame::new(frame)
+        }
+    }
+}
+
+/// Arguments of the JavaScript call are deserialized into `A`, e.g. a tuple `(i32, String)`.
+/// The resolved value is returned to the page and the error is thrown there as `Error(e.to_string())`.
+pub(crate) fn binding_fn<F, Fut, A, R, E>(f: F) -> BindingFn
+where
+    F: Fn(BindingSource, A) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<R, E>> + Send + 'static,
+    A: DeserializeOwned,
+    R: Serialize,
+    E: Display
+{
+    Arc::new(move |frame, args| {
+        let fut = de::from_value::<A>(&args).map(|a| f(BindingSource::new(frame), a));
+        Box::pin(async move {
+            let result = fut.map_err(|e| e.to_string())?.await;
+            let value = result.map_err(|e| e.to_string())?;
+            ser::to_value(&value).map_err(|e| e.to_string())
+        })
+    })
+}
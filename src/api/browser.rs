@@ -14,7 +14,9 @@ use crate::{
 
 #[derive(Debug)]
 pub struct Browser {
-    inner: Weak<imp::browser::Browser>
+    inner: Weak<imp::browser::Browser>,
+    /// Owned by browsers from [`BrowserType::connect_builder`](crate::api::BrowserType::connect_builder)
+    _conn: Option<Connection>
 }
 
 impl PartialEq for Browser {
@@ -28,7 +30,14 @@ impl PartialEq for Browser {
 }
 
 impl Browser {
-    pub(crate) fn new(inner: Weak<imp::browser::Browser>) -> Self { Self { inner } }
+    pub(crate) fn new(inner: Weak<imp::browser::Browser>) -> Self { Self { inner, _conn: None } }
+
+    pub(crate) fn with_connection(inner: Weak<imp::browser::Browser>, conn: Connection) -> Self {
+        Self {
+            inner,
+            _conn: Some(conn)
+        }
+    }
 
     /// Returns an array of all open browser contexts. In a newly created browser, this will return zero browser contexts.
     ///
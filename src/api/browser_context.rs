@@ -1,14 +1,18 @@
 pub use crate::imp::browser_context::EventType;
 use crate::{
-    api::{Browser, Page},
+    api::{
+        route::{HarTarget, RouteFromHarBuilder},
+        ApiRequestContext, BindingSource, Browser, Page, Route, Tracing
+    },
     imp::{
         browser_context::{BrowserContext as Impl, Evt},
         core::*,
         prelude::*,
-        utils::{Cookie, Geolocation, StorageState}
+        utils::{Cookie, Geolocation, StorageState, UrlMatcher}
     },
     Error
 };
+use std::fmt::Display;
 
 /// BrowserContexts provide a way to operate multiple independent browser sessions.
 ///
@@ -72,6 +76,18 @@ impl BrowserContext {
         upgrade(&self.inner)?.cookies(urls).await
     }
 
+    /// API testing helper associated with this context. Requests made with this API will use context cookies.
+    pub fn request(&self) -> ApiRequestContext {
+        let inner = weak_and_then(&self.inner, |rc| rc.request());
+        ApiRequestContext::new(inner)
+    }
+
+    /// Collects traces of this context that can be opened in the Playwright trace viewer.
+    pub fn tracing(&self) -> Tracing {
+        let inner = weak_and_then(&self.inner, |rc| rc.tracing());
+        Tracing::new(inner)
+    }
+
     /// Adds cookies into this browser context. All pages within this context will have these cookies installed.
     pub async fn add_cookies(&self, cookies: &[Cookie]) -> ArcResult<()> {
         upgrade(&self.inner)?.add_cookies(cookies).await
@@ -181,13 +197,89 @@ impl BrowserContext {
         upgrade(&self.inner)?.set_extra_http_headers(headers).await
     }
 
-    // async fn expose_binding(&mut self) -> Result<(), Error> { unimplemented!() }
+    /// The method adds a function called `name` on the `window` object of every frame in every page in the context. When
+    /// called, the function executes `f` and returns a [Promise] which resolves to the return value of `f`.
+    ///
+    /// The first argument of `f` contains information about the caller: [`BindingSource`]. The second one is the
+    /// arguments of the JavaScript call deserialized into `A`, e.g. a tuple.
+    /// If `f` returns an error, the promise is rejected with its message.
+    ///
+    /// See [`Page::expose_binding`] for page-only version.
+    pub async fn expose_binding<F, Fut, A, R, E>(&self, name: &str, f: F) -> ArcResult<()>
+    where
+        F: Fn(BindingSource, A) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R, E>> + Send + 'static,
+        A: DeserializeOwned,
+        R: Serialize,
+        E: Display
+    {
+        let f = crate::api::binding_call::binding_fn(f);
+        upgrade(&self.inner)?.expose_binding(name, f).await
+    }
 
-    // async fn expose_function(&mut self) -> Result<(), Error> { unimplemented!() }
+    /// The method adds a function called `name` on the `window` object of every frame in every page in the context. When
+    /// called, the function executes `f` and returns a [Promise] which resolves to the return value of `f`.
+    ///
+    /// See [`Page::expose_function`] for page-only version.
+    pub async fn expose_function<F, Fut, A, R, E>(&self, name: &str, f: F) -> ArcResult<()>
+    where
+        F: Fn(A) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R, E>> + Send + 'static,
+        A: DeserializeOwned,
+        R: Serialize,
+        E: Display
+    {
+        self.expose_binding(name, move |_, a| f(a)).await
+    }
 
-    // async fn route(&mut self) -> Result<(), Error> { unimplemented!() }
+    /// Routing provides the capability to modify network requests that are made by any page in the browser context. Once route
+    /// is enabled, every request matching the url pattern will stall unless it's continued, fulfilled or aborted.
+    ///
+    /// An example of a naive handler that aborts all image requests:
+    ///
+    /// ```js
+    /// const context = await browser.newContext();
+    /// await context.route('**/*.{png,jpg,jpeg}', route => route.abort());
+    /// const page = await context.newPage();
+    /// await page.goto('https://example.com');
+    /// await browser.close();
+    /// ```
+    ///
+    /// Page routes (set up with [`Page::route`]) take precedence over browser context routes when request matches both
+    /// handlers. Among the context routes the last registered one wins, and requests nothing matches are continued.
+    ///
+    /// > NOTE: Enabling routing disables http cache.
+    pub async fn route<M, F, Fut>(&self, url: M, handler: F) -> ArcResult<()>
+    where
+        M: Into<UrlMatcher>,
+        F: Fn(Route) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ArcResult<()>> + Send + 'static
+    {
+        let handler = crate::api::route::route_handler(url.into(), handler);
+        upgrade(&self.inner)?.route(handler).await
+    }
 
-    // async fn unroute(&mut self) -> Result<(), Error> { unimplemented!() }
+    /// Serves requests of the context from a HAR file, such as one recorded with
+    /// [`RecordHar`](crate::api::browser_type::RecordHar). `har` is either a `.har` file or a `.zip` with the resources
+    /// attached. Requests are matched on URL and method, and on headers and post data when there are several candidates.
+    ///
+    /// ```js
+    /// await context.routeFromHAR('example.har', { url: '**/api/**', notFound: 'fallback' });
+    /// ```
+    ///
+    /// With [`RouteFromHarBuilder::update`] the HAR is recorded from the network instead, which is how it is created or
+    /// refreshed.
+    pub fn route_from_har_builder<'a>(&self, har: &'a Path) -> RouteFromHarBuilder<'a> {
+        RouteFromHarBuilder::new(HarTarget::Context(self.inner.clone()), har)
+    }
+
+    /// Removes all routes created with [`BrowserContext::route`] for the equal `url` matcher.
+    pub async fn unroute<M>(&self, url: M) -> ArcResult<()>
+    where
+        M: Into<UrlMatcher>
+    {
+        upgrade(&self.inner)?.unroute(&url.into()).await
+    }
 
     pub async fn expect_event(&self, evt: EventType) -> Result<Event, Error> {
         let stream = upgrade(&self.inner)?.subscribe_event();
@@ -195,6 +287,56 @@ impl BrowserContext {
         expect_event(stream, evt, timeout).await.map(Event::from)
     }
 
+    /// Waits for the first event of `evt` for which `predicate` returns true.
+    /// `timeout` is in milliseconds, defaults to the default timeout and `0` disables it.
+    pub async fn wait_for_event<F>(
+        &self,
+        evt: EventType,
+        mut predicate: F,
+        timeout: Option<f64>
+    ) -> Result<Event, Error>
+    where
+        F: FnMut(&Event) -> bool
+    {
+        let (stream, default_timeout) = {
+            let inner = upgrade(&self.inner)?;
+            (inner.subscribe_event(), inner.default_timeout())
+        };
+        let timeout = timeout.map(|t| t as u32).unwrap_or(default_timeout);
+        let e =
+            wait_for_event(stream, evt, |e| predicate(&Event::from(e.clone())), timeout).await?;
+        Ok(Event::from(e))
+    }
+
+    /// Runs `action` and waits for a page which is created in the context meanwhile, such as a popup or a new tab.
+    /// The event is subscribed before `action` starts, so it cannot be missed.
+    ///
+    /// ```js
+    /// const [newPage] = await Promise.all([
+    ///  context.waitForEvent('page'),
+    ///  page.click('a[target=_blank]'),
+    /// ]);
+    /// ```
+    pub async fn expect_page<F, Fut, T>(&self, action: F) -> ArcResult<Page>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ArcResult<T>>
+    {
+        let (stream, timeout) = {
+            let inner = upgrade(&self.inner)?;
+            (inner.subscribe_event(), inner.default_timeout())
+        };
+        let wait = async move {
+            wait_for_event(stream, EventType::Page, |_| true, timeout)
+                .await
+                .map_err(Arc::new)
+        };
+        match futures::future::try_join(wait, action()).await?.0 {
+            Evt::Page(x) => Ok(Page::new(x)),
+            _ => unreachable!()
+        }
+    }
+
     /// Returns storage state for this browser context, contains current cookies and local storage snapshot.
     pub async fn storage_state(&self) -> ArcResult<StorageState> {
         // path no supported
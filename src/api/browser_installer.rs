@@ -0,0 +1,433 @@
+use crate::imp::core::Driver;
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus, Stdio},
+    thread
+};
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+/// Manages the browsers which the driver downloads. Nothing is launched except the driver cli for installation.
+///
+/// Browsers are looked up in [`BrowserInstaller::browsers_path`], which is the `PLAYWRIGHT_BROWSERS_PATH` directory
+/// or `ms-playwright` in the cache directory by default.
+#[derive(Debug, Clone)]
+pub struct BrowserInstaller {
+    driver: Driver,
+    browsers_path: Option<PathBuf>
+}
+
+/// A browser which the driver requires, from `package/browsers.json` of the driver
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequiredBrowser {
+    pub name: String,
+    pub revision: String,
+    /// Installed by `playwright install` without browser names
+    pub install_by_default: bool,
+    pub browser_version: Option<String>,
+    /// Revisions for specific host platforms such as `mac10.14`.
+    /// [`BrowserInstaller::is_installed`] only checks [`RequiredBrowser::revision`].
+    #[serde(default)]
+    pub revision_overrides: HashMap<String, String>
+}
+
+/// A browser directory found in [`BrowserInstaller::browsers_path`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledBrowser {
+    pub name: String,
+    pub revision: String,
+    pub path: PathBuf,
+    /// False if the download was interrupted
+    pub complete: bool
+}
+
+/// A line of the output of `playwright install`
+#[derive(Debug, Clone, PartialEq)]
+pub enum InstallProgress {
+    /// `Downloading Chromium 105.0.5195.19 (playwright build v1019) from https://...`
+    Downloading { title: String, url: Option<String> },
+    /// `|■■■■■■■■     |  10% of 130.4 Mb`
+    Progress { percent: u8, total: Option<String> },
+    /// `Chromium 105.0.5195.19 (playwright build v1019) downloaded to /path/to/chromium-1019`
+    Downloaded { title: String, path: PathBuf },
+    /// Any other line
+    Log(String)
+}
+
+/// Output of `playwright install-deps`
+#[derive(Debug, Clone)]
+pub struct InstallDepsReport {
+    pub status: ExitStatus,
+    pub stdout: String,
+    pub stderr: String
+}
+
+impl InstallDepsReport {
+    pub fn success(&self) -> bool { self.status.success() }
+}
+
+const BROWSERS_PATH_ENV: &str = "PLAYWRIGHT_BROWSERS_PATH";
+const MARKER: &str = "INSTALLATION_COMPLETE";
+
+impl BrowserInstaller {
+    pub fn new(driver: Driver) -> Self {
+        Self {
+            driver,
+            browsers_path: None
+        }
+    }
+
+    /// Uses the directory instead of `PLAYWRIGHT_BROWSERS_PATH` of this process.
+    /// It is passed to the driver as `PLAYWRIGHT_BROWSERS_PATH`.
+    pub fn with_browsers_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.browsers_path = Some(path.into());
+        self
+    }
+
+    /// Resolves the directory of browsers in the same way as the driver.
+    /// `PLAYWRIGHT_BROWSERS_PATH=0` means `.local-browsers` in the driver package.
+    pub fn browsers_path(&self) -> PathBuf {
+        let configured = self
+            .browsers_path
+            .clone()
+            .or_else(|| env::var_os(BROWSERS_PATH_ENV).map(PathBuf::from));
+        match configured {
+            Some(p) if p.as_os_str() == "0" => {
+                self.driver.path().join("package").join(".local-browsers")
+            }
+            Some(p) if p.is_relative() => env::current_dir().map(|d| d.join(&p)).unwrap_or(p),
+            Some(p) => p,
+            None => dirs::cache_dir()
+                .unwrap_or_else(env::temp_dir)
+                .join("ms-playwright")
+        }
+    }
+
+    /// Browsers and revisions which this driver requires
+    pub fn required(&self) -> io::Result<Vec<RequiredBrowser>> {
+        #[derive(Deserialize)]
+        struct BrowsersJson {
+            browsers: Vec<RequiredBrowser>
+        }
+        let path = self.driver.path().join("package").join("browsers.json");
+        let json: BrowsersJson = serde_json::from_slice(&fs::read(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(json.browsers)
+    }
+
+    /// Browsers in [`BrowserInstaller::browsers_path`], including revisions which other drivers require
+    pub fn installed(&self) -> io::Result<Vec<InstalledBrowser>> {
+        let dir = self.browsers_path();
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+        let mut installed = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+            let file_name = entry.file_name();
+            let (name, revision) = match file_name.to_str().and_then(|s| s.rsplit_once('-')) {
+                Some((n, r)) if !n.is_empty() && r.chars().all(|c| c.is_ascii_digit()) => (n, r),
+                _ => continue
+            };
+            let path = entry.path();
+            installed.push(InstalledBrowser {
+                name: name.replace('_', "-"),
+                revision: revision.into(),
+                complete: path.join(MARKER).is_file(),
+                path
+            });
+        }
+        installed.sort_by(|a, b| (&a.name, &a.revision).cmp(&(&b.name, &b.revision)));
+        Ok(installed)
+    }
+
+    /// Whether the revision of the browser which this driver requires is completely installed
+    pub fn is_installed(&self, browser: &str) -> io::Result<bool> {
+        let required = self.required()?;
+        let required = required.iter().find(|b| b.name == browser).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} is not a browser of this driver", browser)
+            )
+        })?;
+        Ok(self
+            .installed()?
+            .iter()
+            .any(|b| b.complete && b.name == required.name && b.revision == required.revision))
+    }
+
+    /// Runs `playwright install` for the browsers, or the default browsers if empty.
+    /// Each line of the output is passed to `on_progress`.
+    pub fn install<F>(&self, browsers: &[&str], mut on_progress: F) -> io::Result<()>
+    where
+        F: FnMut(InstallProgress)
+    {
+        let mut child = self
+            .command(&["install"], browsers)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let stderr = read_in_background(child.stderr.take());
+        if let Some(stdout) = child.stdout.take() {
+            for line in BufReader::new(stdout).lines() {
+                let line = line?;
+                if !line.trim().is_empty() {
+                    on_progress(InstallProgress::parse(&line));
+                }
+            }
+        }
+        let status = child.wait()?;
+        let stderr = stderr.join().unwrap_or_default();
+        if !status.success() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("Exit with {}: {}", status, stderr.trim())
+            ));
+        }
+        Ok(())
+    }
+
+    /// Removes every installed revision of the browsers. Returns the removed directories.
+    pub fn uninstall(&self, browsers: &[&str]) -> io::Result<Vec<PathBuf>> {
+        let mut removed = Vec::new();
+        for b in self.installed()? {
+            if browsers.contains(&b.name.as_str()) {
+                fs::remove_dir_all(&b.path)?;
+                removed.push(b.path);
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Removes every installed browser in [`BrowserInstaller::browsers_path`]. Returns the removed directories.
+    pub fn uninstall_all(&self) -> io::Result<Vec<PathBuf>> {
+        let mut removed = Vec::new();
+        for b in self.installed()? {
+            fs::remove_dir_all(&b.path)?;
+            removed.push(b.path);
+        }
+        Ok(removed)
+    }
+
+    /// Runs `playwright install-deps` for the browsers, or the default browsers if empty.
+    /// With `dry_run` the driver only prints the commands. A failure of the command is reported in
+    /// [`InstallDepsReport::status`] instead of an error.
+    pub fn install_deps(&self, browsers: &[&str], dry_run: bool) -> io::Result<InstallDepsReport> {
+        let mut command = self.command(&["install-deps"], browsers);
+        if dry_run {
+            command.arg("--dry-run");
+        }
+        let output = command.stdin(Stdio::null()).output()?;
+        Ok(InstallDepsReport {
+            status: output.status,
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned()
+        })
+    }
+
+    fn command(&self, args: &[&str], browsers: &[&str]) -> Command {
+        let mut command = Command::new(self.driver.executable());
+        command.args(args).args(browsers);
+        if let Some(p) = &self.browsers_path {
+            command.env(BROWSERS_PATH_ENV, p);
+        }
+        #[cfg(target_os = "windows")]
+        command.creation_flags(0x08000000);
+        command
+    }
+}
+
+fn read_in_background<R: Read + Send + 'static>(r: Option<R>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut s = String::new();
+        if let Some(mut r) = r {
+            let _ = r.read_to_string(&mut s);
+        }
+        s
+    })
+}
+
+impl InstallProgress {
+    pub(crate) fn parse(line: &str) -> Self {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("Downloading ") {
+            let (title, url) = match rest.rsplit_once(" from ") {
+                Some((t, u)) => (t, Some(u.to_owned())),
+                None => (rest, None)
+            };
+            return Self::Downloading {
+                title: title.into(),
+                url
+            };
+        }
+        if let Some((title, path)) = line.split_once(" downloaded to ") {
+            return Self::Downloaded {
+                title: title.into(),
+                path: Path::new(path).into()
+            };
+        }
+        if let Some(p) = parse_percent(line) {
+            return p;
+        }
+        Self::Log(line.into())
+    }
+}
+
+fn parse_percent(line: &str) -> Option<InstallProgress> {
+    let (head, tail) = line.split_once('%')?;
+    let digits = head
+        .trim_end()
+        .rsplit(|c: char| !c.is_ascii_digit())
+        .next()?;
+    let percent = digits.parse::<u8>().ok().filter(|p| *p <= 100)?;
+    let total = tail
+        .trim()
+        .strip_prefix("of ")
+        .map(|t| t.trim().to_owned())
+        .filter(|t| !t.is_empty());
+    Some(InstallProgress::Progress { percent, total })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn driver(dir: &Path) -> Driver {
+        let package = dir.join("package");
+        fs::create_dir_all(&package).unwrap();
+        fs::write(
+            package.join("browsers.json"),
+            r#"{
+                "comment": "",
+                "browsers": [
+                    {"name": "chromium", "revision": "1019", "installByDefault": true, "browserVersion": "105.0.5195.19"},
+                    {"name": "firefox-beta", "revision": "1350", "installByDefault": false},
+                    {"name": "webkit", "revision": "1699", "installByDefault": true, "revisionOverrides": {"mac10.14": "1446"}}
+                ]
+            }"#
+        )
+        .unwrap();
+        Driver::new(dir)
+    }
+
+    fn browser(base: &Path, dir: &str, complete: bool) {
+        fs::create_dir_all(base.join(dir)).unwrap();
+        if complete {
+            fs::write(base.join(dir).join(MARKER), "").unwrap();
+        }
+    }
+
+    #[test]
+    fn parse_progress() {
+        assert_eq!(
+            InstallProgress::parse(
+                "Downloading Chromium 105.0.5195.19 (playwright build v1019) from https://example.com/chromium-linux.zip"
+            ),
+            InstallProgress::Downloading {
+                title: "Chromium 105.0.5195.19 (playwright build v1019)".into(),
+                url: Some("https://example.com/chromium-linux.zip".into())
+            }
+        );
+        assert_eq!(
+            InstallProgress::parse("|■■■■■■■■          |  10% of 130.4 Mb"),
+            InstallProgress::Progress {
+                percent: 10,
+                total: Some("130.4 Mb".into())
+            }
+        );
+        assert_eq!(
+            InstallProgress::parse(
+                "Chromium 105.0.5195.19 (playwright build v1019) downloaded to /cache/chromium-1019"
+            ),
+            InstallProgress::Downloaded {
+                title: "Chromium 105.0.5195.19 (playwright build v1019)".into(),
+                path: "/cache/chromium-1019".into()
+            }
+        );
+        assert_eq!(
+            InstallProgress::parse("Removing unused browser"),
+            InstallProgress::Log("Removing unused browser".into())
+        );
+    }
+
+    #[test]
+    fn installed() {
+        let tmp = tempdir::TempDir::new("playwright-rust-browsers").unwrap();
+        let base = tmp.path().join("browsers");
+        let installer = BrowserInstaller::new(driver(tmp.path())).with_browsers_path(&base);
+        assert_eq!(installer.browsers_path(), base);
+        assert!(installer.installed().unwrap().is_empty());
+        browser(&base, "chromium-1000", true);
+        browser(&base, "chromium-1019", true);
+        browser(&base, "firefox_beta-1350", true);
+        browser(&base, "webkit-1699", false);
+        browser(&base, ".links", false);
+        let installed = installer.installed().unwrap();
+        let names = installed
+            .iter()
+            .map(|b| (b.name.as_str(), b.revision.as_str(), b.complete))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                ("chromium", "1000", true),
+                ("chromium", "1019", true),
+                ("firefox-beta", "1350", true),
+                ("webkit", "1699", false)
+            ]
+        );
+        assert_eq!(installer.required().unwrap().len(), 3);
+        assert!(installer.is_installed("chromium").unwrap());
+        assert!(installer.is_installed("firefox-beta").unwrap());
+        assert!(!installer.is_installed("webkit").unwrap());
+        assert!(installer.is_installed("opera").is_err());
+        let removed = installer.uninstall(&["chromium"]).unwrap();
+        assert_eq!(removed.len(), 2);
+        assert!(!installer.is_installed("chromium").unwrap());
+        assert_eq!(installer.uninstall_all().unwrap().len(), 2);
+        assert!(installer.installed().unwrap().is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn install() {
+        use std::os::unix::fs::PermissionsExt;
+        let tmp = tempdir::TempDir::new("playwright-rust-browsers").unwrap();
+        let d = driver(tmp.path());
+        let exec = d.executable();
+        fs::write(
+            &exec,
+            "#!/bin/sh\n\
+             echo \"Downloading Chromium (playwright build v1019) from https://example.com/$2\"\n\
+             echo '|■■■■■■■■■■| 100% of 1.0 Mb'\n\
+             echo \"Chromium (playwright build v1019) downloaded to $PLAYWRIGHT_BROWSERS_PATH/chromium-1019\"\n\
+             [ \"$2\" = chromium ] || { echo \"unknown $2\" >&2; exit 1; }\n"
+        )
+        .unwrap();
+        fs::set_permissions(&exec, fs::Permissions::from_mode(0o755)).unwrap();
+        let base = tmp.path().join("browsers");
+        let installer = BrowserInstaller::new(d).with_browsers_path(&base);
+        let mut progress = Vec::new();
+        installer
+            .install(&["chromium"], |p| progress.push(p))
+            .unwrap();
+        assert_eq!(progress.len(), 3);
+        assert_eq!(
+            progress[2],
+            InstallProgress::Downloaded {
+                title: "Chromium (playwright build v1019)".into(),
+                path: base.join("chromium-1019")
+            }
+        );
+        let err = installer.install(&["opera"], |_| ()).unwrap_err();
+        assert!(err.to_string().contains("unknown opera"), "{}", err);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::imp::{browser_server::BrowserServer as Impl, core::*};
+
+/// A browser launched by [`BrowserType::launch_server_builder`](crate::api::BrowserType::launch_server_builder).
+/// Clients connect to it with [`BrowserType::connect_builder`](crate::api::BrowserType::connect_builder).
+/// The server is killed when this is dropped.
+///
+/// ```js
+/// const { chromium } = require('playwright');  // Or 'webkit' or 'firefox'.
+///
+/// (async () => {
+///   const browserServer = await chromium.launchServer();
+///   const wsEndpoint = browserServer.wsEndpoint();
+///   // Use web socket endpoint later to establish a connection.
+///   const browser = await chromium.connect(wsEndpoint);
+///   // Close browser instance.
+///   await browserServer.close();
+/// })();
+/// ```
+#[derive(Debug)]
+pub struct BrowserServer {
+    inner: Impl
+}
+
+impl BrowserServer {
+    pub(crate) fn new(inner: Impl) -> Self { Self { inner } }
+
+    /// Browser websocket url.
+    ///
+    /// Browser websocket endpoint which can be used as an argument to [`BrowserType::connect_builder`](crate::api::BrowserType::connect_builder)
+    /// to establish connection to the browser.
+    pub fn ws_endpoint(&self) -> &str { self.inner.ws_endpoint() }
+
+    /// Process id of the server which hosts the browser.
+    pub fn process(&self) -> u32 { self.inner.process() }
+
+    /// Closes the browser gracefully and makes sure the process is terminated.
+    pub async fn close(&self) -> ArcResult<()> { self.inner.close().await }
+
+    /// Kills the browser process and waits for the process to exit.
+    pub async fn kill(&self) -> ArcResult<()> { self.inner.kill().await }
+}
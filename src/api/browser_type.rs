@@ -1,9 +1,14 @@
 pub use crate::imp::browser_type::{RecordHar, RecordVideo};
 use crate::{
-    api::{browser::Browser, browser_context::BrowserContext, playwright::DeviceDescriptor},
+    api::{
+        browser::Browser, browser_context::BrowserContext, browser_server::BrowserServer,
+        playwright::DeviceDescriptor
+    },
     imp::{
+        browser_server::LaunchServerArgs,
         browser_type::{
-            BrowserType as Impl, ConnectOverCdpArgs, LaunchArgs, LaunchPersistentContextArgs
+            BrowserType as Impl, ConnectArgs, ConnectOverCdpArgs, LaunchArgs,
+            LaunchPersistentContextArgs
         },
         core::*,
         prelude::*,
@@ -88,8 +93,34 @@ impl BrowserType {
         ConnectOverCdpBuilder::new(self.inner.clone(), endpoint_url)
     }
 
-    // connect
-    // launch_server
+    /// This methods attaches Playwright to an existing browser instance launched by `playwright run-server` or
+    /// [`method: BrowserType.launchServer`]. The connection is closed when the returned [`Browser`] is dropped.
+    /// A browser websocket endpoint to connect to. Only `ws://` endpoints are supported.
+    pub fn connect_builder<'a>(&self, ws_endpoint: &'a str) -> ConnectBuilder<'a> {
+        ConnectBuilder::new(self.inner.clone(), ws_endpoint)
+    }
+
+    /// Returns the browser app instance. You can connect to it via [`BrowserType::connect_builder`], which requires the
+    /// major/minor client/server version to match (1.2.3 → is compatible with 1.2.x).
+    ///
+    /// Launches browser server that client can connect to. An example of launching a browser executable and connecting to it
+    /// later:
+    ///
+    /// ```js
+    /// const { chromium } = require('playwright');  // Or 'webkit' or 'firefox'.
+    ///
+    /// (async () => {
+    ///   const browserServer = await chromium.launchServer();
+    ///   const wsEndpoint = browserServer.wsEndpoint();
+    ///   // Use web socket endpoint later to establish a connection.
+    ///   const browser = await chromium.connect(wsEndpoint);
+    ///   // Close browser instance.
+    ///   await browserServer.close();
+    /// })();
+    /// ```
+    pub fn launch_server_builder(&self) -> LaunchServerBuilder<'_, '_, '_, '_> {
+        LaunchServerBuilder::new(self.inner.clone())
+    }
 }
 
 /// [`BrowserType::launcher`]
@@ -162,6 +193,113 @@ impl<'a, 'b, 'c> Launcher<'a, 'b, 'c> {
     // logger: Option<Logger>,
 }
 
+/// [`BrowserType::launch_server_builder`]
+///
+/// Has launch args and the address to listen on
+pub struct LaunchServerBuilder<'a, 'b, 'c, 'd> {
+    inner: Weak<Impl>,
+    args: LaunchArgs<'a, 'b, 'c>,
+    port: Option<u16>,
+    ws_path: Option<&'d str>
+}
+
+impl<'a, 'b, 'c, 'd> LaunchServerBuilder<'a, 'b, 'c, 'd> {
+    pub async fn launch_server(self) -> ArcResult<BrowserServer> {
+        let Self {
+            inner,
+            args,
+            port,
+            ws_path
+        } = self;
+        let args = LaunchServerArgs {
+            launch: args,
+            port,
+            ws_path
+        };
+        let r = upgrade(&inner)?.launch_server(args).await?;
+        Ok(BrowserServer::new(r))
+    }
+
+    fn new(inner: Weak<Impl>) -> Self {
+        Self {
+            inner,
+            args: LaunchArgs::default(),
+            port: None,
+            ws_path: None
+        }
+    }
+
+    /// Port to use for the web socket. Defaults to 0 that picks any available port.
+    pub fn port(mut self, x: u16) -> Self {
+        self.port = Some(x);
+        self
+    }
+
+    /// Path at which to serve the Browser Server. For security, this defaults to an unguessable string.
+    ///
+    /// > **WARNING** Any process or web page (including those running in Playwright) with knowledge of the `wsPath` can take
+    /// control of the OS user. For this reason, you should use an unguessable token when using this option.
+    pub fn ws_path(mut self, x: &'d str) -> Self {
+        self.ws_path = Some(x);
+        self
+    }
+
+    pub fn clear_port(mut self) -> Self {
+        self.port = None;
+        self
+    }
+
+    pub fn clear_ws_path(mut self) -> Self {
+        self.ws_path = None;
+        self
+    }
+
+    setter! {
+        /// Path to a browser executable to run instead of the bundled one. If `executablePath` is a relative path, then it is
+        /// resolved relative to the current working directory. Note that Playwright only works with the bundled Chromium, Firefox
+        /// or WebKit, use at your own risk.
+        executable: Option<&'a Path>,
+        /// Additional arguments to pass to the browser instance. The list of Chromium flags can be found
+        /// [here](http://peter.sh/experiments/chromium-command-line-switches/).
+        args: Option<&'b [String]>,
+        /// If `true`, Playwright does not pass its own configurations args and only uses the ones from `args`. Dangerous option;
+        /// use with care. Defaults to `false`.
+        ignore_all_default_args: Option<bool>,
+        /// Close the browser process on Ctrl-C. Defaults to `true`.
+        handle_sigint: Option<bool>,
+        /// Close the browser process on SIGTERM. Defaults to `true`.
+        handle_sigterm: Option<bool>,
+        /// Close the browser process on SIGHUP. Defaults to `true`.
+        handle_sighup: Option<bool>,
+        /// Maximum time in milliseconds to wait for the browser instance to start. Defaults to `30000` (30 seconds). Pass `0` to
+        /// disable timeout.
+        timeout: Option<f64>,
+        /// **Chromium-only** Whether to auto-open a Developer Tools panel for each tab. If this option is `true`, the `headless`
+        /// option will be set `false`.
+        devtools: Option<bool>,
+        /// Network proxy settings.
+        proxy: Option<ProxySettings>,
+        /// If specified, accepted downloads are downloaded into this directory. Otherwise, temporary directory is created and is
+        /// deleted when browser is closed.
+        downloads: Option<&'c Path>,
+        /// Slows down Playwright operations by the specified amount of milliseconds. Useful so that you can see what is going on.
+        slowmo: Option<f64>,
+        /// Specify environment variables that will be visible to the browser. Defaults to `process.env`.
+        env: Option<Map<String, Value>>,
+        /// Whether to run browser in headless mode. More details for
+        /// [Chromium](https://developers.google.com/web/updates/2017/04/headless-chrome) and
+        /// [Firefox](https://developer.mozilla.org/en-US/docs/Mozilla/Firefox/Headless_mode). Defaults to `true` unless the
+        /// `devtools` option is `true`.
+        headless: Option<bool>,
+        /// Enable Chromium sandboxing. Defaults to `false`.
+        chromium_sandbox: Option<bool>,
+        /// Firefox user preferences. Learn more about the Firefox user preferences at
+        /// [`about:config`](https://support.mozilla.org/en-US/kb/about-config-editor-firefox).
+        firefox_user_prefs: Option<Map<String, Value>>,
+        channel: Option<BrowserChannel>
+    }
+}
+
 /// [`BrowserType::persistent_context_launcher`]
 ///
 /// Has launch args and context args
@@ -298,6 +436,38 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
     //#[doc = "**DEPRECATED** Use `recordVideo` instead."] videos_path: Option<path>,
 }
 
+/// [`BrowserType::connect_builder`]
+pub struct ConnectBuilder<'a> {
+    inner: Weak<Impl>,
+    args: ConnectArgs<'a>
+}
+
+impl<'a> ConnectBuilder<'a> {
+    pub async fn connect(self) -> ArcResult<Browser> {
+        let Self { inner, args } = self;
+        let (conn, r) = upgrade(&inner)?.connect(args).await?;
+        Ok(Browser::with_connection(r, conn))
+    }
+
+    fn new(inner: Weak<Impl>, ws_endpoint: &'a str) -> Self {
+        Self {
+            inner,
+            args: ConnectArgs::new(ws_endpoint)
+        }
+    }
+
+    setter! {
+        /// Additional HTTP headers to be sent with web socket connect request. Optional.
+        headers: Option<HashMap<String, String>>,
+        /// Maximum time in milliseconds to wait for the connection to be established. Defaults to `30000` (30 seconds). Pass `0`
+        /// to disable timeout.
+        timeout: Option<f64>,
+        /// Slows down Playwright operations by the specified amount of milliseconds. Useful so that you can see what is going on.
+        /// Defaults to 0.
+        slowmo: Option<f64>
+    }
+}
+
 pub struct ConnectOverCdpBuilder<'a> {
     inner: Weak<Impl>,
     args: ConnectOverCdpArgs<'a>
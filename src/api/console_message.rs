@@ -1,6 +1,10 @@
+pub use crate::imp::console_message::ConsoleType;
 use crate::{
-    api::JsHandle,
-    imp::{console_message::ConsoleMessage as Impl, core::*, prelude::*, utils::SourceLocation}
+    api::{ElementHandle, JsHandle},
+    imp::{
+        console_message::ConsoleMessage as Impl, core::*, frame::Handle, prelude::*,
+        utils::SourceLocation
+    }
 };
 
 /// `ConsoleMessage` objects are dispatched by page via the [page::Event::Console](crate::api::page::Event::Console) event.
@@ -12,10 +16,8 @@ pub struct ConsoleMessage {
 impl ConsoleMessage {
     pub(crate) fn new(inner: Weak<Impl>) -> Self { Self { inner } }
 
-    /// One of the following values: `'log'`, `'debug'`, `'info'`, `'error'`, `'warning'`, `'dir'`, `'dirxml'`, `'table'`,
-    /// `'trace'`, `'clear'`, `'startGroup'`, `'startGroupCollapsed'`, `'endGroup'`, `'assert'`, `'profile'`, `'profileEnd'`,
-    /// `'count'`, `'timeEnd'`.
-    pub fn r#type(&self) -> Result<String, Error> { Ok(upgrade(&self.inner)?.r#type().into()) }
+    /// The method which logged the message, such as `console.log` or `console.error`
+    pub fn r#type(&self) -> Result<ConsoleType, Error> { Ok(upgrade(&self.inner)?.r#type()) }
 
     /// The text of the console message.
     pub fn text(&self) -> Result<String, Error> { Ok(upgrade(&self.inner)?.text().into()) }
@@ -24,4 +26,52 @@ impl ConsoleMessage {
     pub fn location(&self) -> Result<SourceLocation, Error> {
         Ok(upgrade(&self.inner)?.location().to_owned())
     }
+
+    /// List of arguments passed to a `console` function call.
+    ///
+    /// ```js
+    /// page.on('console', async msg => {
+    ///  for (let i = 0; i < msg.args().length; ++i)
+    ///    console.log(`${i}: ${await msg.args()[i].jsonValue()}`);
+    /// });
+    /// ```
+    ///
+    /// Unlike `Vec<JsHandle>` of the upstream API, DOM elements are returned as [`ConsoleArg::Element`], because an
+    /// [`ElementHandle`] is not a [`JsHandle`] in this crate.
+    pub fn args(&self) -> Result<Vec<ConsoleArg>, Error> {
+        Ok(upgrade(&self.inner)?
+            .args()
+            .iter()
+            .cloned()
+            .map(|h| match h {
+                Handle::Js(x) => ConsoleArg::Js(JsHandle::new(x)),
+                Handle::Element(x) => ConsoleArg::Element(ElementHandle::new(x))
+            })
+            .collect())
+    }
+}
+
+/// An argument of a `console` function call
+#[derive(Debug, Clone)]
+pub enum ConsoleArg {
+    Js(JsHandle),
+    /// DOM elements
+    Element(ElementHandle)
+}
+
+impl ConsoleArg {
+    /// Returns `None` for DOM elements
+    pub fn js_handle(self) -> Option<JsHandle> {
+        match self {
+            Self::Js(x) => Some(x),
+            Self::Element(_) => None
+        }
+    }
+
+    pub fn element_handle(self) -> Option<ElementHandle> {
+        match self {
+            Self::Js(_) => None,
+            Self::Element(x) => Some(x)
+        }
+    }
 }
@@ -1,3 +1,4 @@
+pub use crate::imp::dialog::DialogType;
 use crate::imp::{core::*, dialog::Dialog as Impl, prelude::*};
 
 /// `Dialog` objects are dispatched by page via the [page::Event::Dialog](crate::api::page::Event::Dialog) event.
@@ -23,6 +24,7 @@ use crate::imp::{core::*, dialog::Dialog as Impl, prelude::*};
 /// present, it **must** either [`method: Dialog.accept`] or [`method: Dialog.dismiss`] the dialog - otherwise the page will
 /// [freeze](https://developer.mozilla.org/en-US/docs/Web/JavaScript/EventLoop#never_blocking) waiting for the dialog, and
 /// actions like click will never finish.
+#[derive(Clone)]
 pub struct Dialog {
     inner: Weak<Impl>
 }
@@ -30,15 +32,23 @@ pub struct Dialog {
 impl Dialog {
     pub(crate) fn new(inner: Weak<Impl>) -> Self { Self { inner } }
 
-    ///// Returns when the dialog has been accepted.
-    ///// A text to enter in prompt. Does not cause any effects if the dialog's `type` is not prompt. Optional.
-    // fn accept(&self, prompt_text: Option<String>) -> Result<(), Arc<Error>> { todo!() }
-    ///// If dialog is prompt, returns default prompt value. Otherwise, returns empty string.
-    // fn default_value(&self) -> Result<String, Error> { todo!() }
-    ///// Returns when the dialog has been dismissed.
-    // fn dismiss(&self) -> Result<(), Arc<Error>> { todo!() }
-    ///// A message displayed in the dialog.
-    // fn message(&self) -> Result<String, Error> { todo!() }
-    ///// Returns dialog's type, can be one of `alert`, `beforeunload`, `confirm` or `prompt`.
-    // fn r#type(&self) -> Result<String, Error> { todo!() }
+    /// Returns when the dialog has been accepted.
+    /// A text to enter in prompt. Does not cause any effects if the dialog's `type` is not prompt. Optional.
+    pub async fn accept(&self, prompt_text: Option<&str>) -> ArcResult<()> {
+        upgrade(&self.inner)?.accept(prompt_text).await
+    }
+
+    /// If dialog is prompt, returns default prompt value. Otherwise, returns empty string.
+    pub fn default_value(&self) -> Result<String, Error> {
+        Ok(upgrade(&self.inner)?.default_value().into())
+    }
+
+    /// Returns when the dialog has been dismissed.
+    pub async fn dismiss(&self) -> ArcResult<()> { upgrade(&self.inner)?.dismiss().await }
+
+    /// A message displayed in the dialog.
+    pub fn message(&self) -> Result<String, Error> { Ok(upgrade(&self.inner)?.message().into()) }
+
+    /// Returns dialog's type, can be one of `alert`, `beforeunload`, `confirm` or `prompt`.
+    pub fn r#type(&self) -> Result<DialogType, Error> { Ok(upgrade(&self.inner)?.r#type()) }
 }
@@ -8,8 +8,10 @@ use crate::{api::Frame, imp::{
         WaitForSelectorArgs, SetInputFilePathsArgs
     },
     prelude::*,
+    screenshot::MaskTarget,
     utils::{
-        ElementState, File, FloatRect, KeyboardModifier, MouseButton, Position, ScreenshotType,
+        ElementState, File, FloatRect, KeyboardModifier, MouseButton, Position,
+        ScreenshotAnimations, ScreenshotCaret, ScreenshotScale, ScreenshotType,
         WaitForSelectorState,
     },
 }};
@@ -36,7 +38,7 @@ use crate::{api::Frame, imp::{
 ///
 /// ElementHandle instances can be used as an argument in [`method: Page.evalOnSelector`] and [`method: Page.evaluate`]
 /// methods.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ElementHandle {
     inner: Weak<Impl>,
 }
@@ -648,9 +650,8 @@ impl<'a> ScreenshotBuilder<'a> {
     }
 
     setter! {
-        /// Hides default white background and allows capturing screenshots with transparency. Not applicable to `jpeg` images.
-        /// Defaults to `false`.
-        omit_background: Option<bool>,
+        /// An area of the element to capture, relative to its top-left corner. Parts outside of the element are cut off.
+        clip: Option<FloatRect>,
         /// The file path to save the image to. The screenshot type will be inferred from file extension. If `path` is a relative
         /// path, then it is resolved relative to the current working directory. If no path is provided, the image won't be saved to
         /// the disk.
@@ -659,12 +660,34 @@ impl<'a> ScreenshotBuilder<'a> {
         timeout: Option<f64>
     }
 
+    screenshot_options!();
+
     pub fn clear_type(mut self) -> Self {
         self.args.r#type = None;
         self
     }
 }
 
+/// Something to cover in a screenshot. Made from a selector, a [`crate::api::Locator`] or an [`ElementHandle`].
+#[derive(Debug, Clone)]
+pub struct Mask(pub(crate) MaskTarget);
+
+impl From<&str> for Mask {
+    fn from(selector: &str) -> Self { Self(MaskTarget::Selector(selector.into())) }
+}
+
+impl From<String> for Mask {
+    fn from(selector: String) -> Self { Self(MaskTarget::Selector(selector)) }
+}
+
+impl From<&ElementHandle> for Mask {
+    fn from(e: &ElementHandle) -> Self { Self(MaskTarget::Element(e.inner.clone())) }
+}
+
+impl From<ElementHandle> for Mask {
+    fn from(e: ElementHandle) -> Self { Self(MaskTarget::Element(e.inner)) }
+}
+
 pub struct WaitForSelectorBuilder<'a> {
     inner: Weak<Impl>,
     args: WaitForSelectorArgs<'a>,
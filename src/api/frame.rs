@@ -1,15 +1,15 @@
 pub use crate::imp::frame::{FrameNavigatedEvent, FrameState, Polling};
 use crate::{
-    api::{ElementHandle, JsHandle, Page, Response},
+    api::{ElementHandle, FrameLocator, JsHandle, Locator, Page, Response},
     imp::{
         core::*,
         frame::{
             AddScriptTagArgs, CheckArgs, ClickArgs, Evt, FillArgs, Frame as Impl, GotoArgs,
             HoverArgs, Opt, PressArgs, SelectOptionArgs, SetContentArgs, SetInputFilesArgs,
-            TapArgs, TypeArgs, WaitForFunctionArgs, WaitForSelectorArgs
+            TapArgs, TypeArgs, WaitForFunctionArgs, WaitForSelectorArgs, WaitForUrlArgs
         },
         prelude::*,
-        utils::{DocumentLoadState, File, KeyboardModifier, MouseButton, Position}
+        utils::{DocumentLoadState, File, KeyboardModifier, MouseButton, Position, UrlMatcher}
     }
 };
 use crate::protocol::generated::LifecycleEvent;
@@ -62,7 +62,7 @@ impl PartialEq for Frame {
 macro_rules! is_checked {
     ($f: ident) => {
         pub async fn $f(&self, selector: &str, timeout: Option<f64>) -> ArcResult<bool> {
-            upgrade(&self.inner)?.$f(selector, timeout).await
+            upgrade(&self.inner)?.$f(selector, timeout, None).await
         }
     };
 }
@@ -181,7 +181,7 @@ impl Frame {
     /// This method fetches an element with `selector` and focuses it. If there's no element matching `selector`, the method
     /// waits until a matching element appears in the DOM.
     pub async fn focus(&self, selector: &str, timeout: Option<f64>) -> ArcResult<()> {
-        upgrade(&self.inner)?.focus(selector, timeout).await
+        upgrade(&self.inner)?.focus(selector, timeout, None).await
     }
 
     /// Returns `element.textContent`.
@@ -190,17 +190,23 @@ impl Frame {
         selector: &str,
         timeout: Option<f64>
     ) -> ArcResult<Option<String>> {
-        upgrade(&self.inner)?.text_content(selector, timeout).await
+        upgrade(&self.inner)?
+            .text_content(selector, timeout, None)
+            .await
     }
 
     /// Returns `element.innerText`.
     pub async fn inner_text(&self, selector: &str, timeout: Option<f64>) -> ArcResult<String> {
-        upgrade(&self.inner)?.inner_text(selector, timeout).await
+        upgrade(&self.inner)?
+            .inner_text(selector, timeout, None)
+            .await
     }
 
     /// Returns `element.innerHTML`.
     pub async fn inner_html(&self, selector: &str, timeout: Option<f64>) -> ArcResult<String> {
-        upgrade(&self.inner)?.inner_html(selector, timeout).await
+        upgrade(&self.inner)?
+            .inner_html(selector, timeout, None)
+            .await
     }
 
     /// Returns element attribute value.
@@ -211,10 +217,28 @@ impl Frame {
         timeout: Option<f64>
     ) -> ArcResult<Option<String>> {
         upgrade(&self.inner)?
-            .get_attribute(selector, name, timeout)
+            .get_attribute(selector, name, timeout, None)
             .await
     }
 
+    /// The method returns an element locator that can be used to perform actions in the frame. Locator is resolved to the
+    /// element immediately before performing an action, so a series of actions on the same locator can in fact be performed on
+    /// different DOM elements. That would happen if the DOM structure between those actions has changed.
+    pub fn locator(&self, selector: &str) -> Locator {
+        Locator::new(self.inner.clone(), selector.to_owned())
+    }
+
+    /// When working with iframes, you can create a frame locator that will enter the iframe and allow selecting elements in
+    /// that iframe.
+    ///
+    /// ```js
+    /// const locator = frame.frameLocator('#my-iframe').locator('text=Submit');
+    /// await locator.click();
+    /// ```
+    pub fn frame_locator(&self, selector: &str) -> FrameLocator {
+        FrameLocator::new(self.inner.clone(), selector.to_owned())
+    }
+
     pub async fn query_selector(&self, selector: &str) -> ArcResult<Option<ElementHandle>> {
         Ok(upgrade(&self.inner)?
             .query_selector(selector)
@@ -514,7 +538,7 @@ impl Frame {
         U: DeserializeOwned
     {
         upgrade(&self.inner)?
-            .evaluate_on_selector(selector, expression, arg)
+            .evaluate_on_selector(selector, expression, arg, None)
             .await
     }
 
@@ -584,7 +608,7 @@ impl Frame {
     {
         // timeout not supported
         upgrade(&self.inner)?
-            .dispatch_event(selector, r#type, event_init)
+            .dispatch_event(selector, r#type, event_init, None)
             .await
     }
 
@@ -646,7 +670,62 @@ impl Frame {
 
     subscribe_event! {}
 
-    // wait_for_url
+    /// Waits for the required load state to be reached. It resolves immediately if the frame has already reached it.
+    ///
+    /// ```js
+    /// await frame.click('button'); // Click triggers navigation.
+    /// await frame.waitForLoadState(); // Waits for 'load' state by default.
+    /// ```
+    /// # Args
+    /// ## state
+    /// Defaults to `load`.
+    /// ## timeout
+    /// Maximum time in milliseconds, defaults to the default navigation timeout of the page. `0` disables it.
+    pub async fn wait_for_load_state(
+        &self,
+        state: Option<DocumentLoadState>,
+        timeout: Option<f64>
+    ) -> ArcResult<()> {
+        upgrade(&self.inner)?
+            .wait_for_load_state(state, timeout)
+            .await
+    }
+
+    /// Waits for the frame to navigate to a URL matching `url`. It resolves once `wait_until` is reached if the frame
+    /// is already there.
+    ///
+    /// ```js
+    /// await frame.click('a.delayed-navigation'); // Clicking the link will indirectly cause a navigation
+    /// await frame.waitForURL('**/target.html');
+    /// ```
+    pub fn wait_for_url_builder<M>(&self, url: M) -> WaitForUrlBuilder
+    where
+        M: Into<UrlMatcher>
+    {
+        WaitForUrlBuilder::new(self.inner.clone(), url.into())
+    }
+
+    /// Runs `action` and waits for the navigation which it triggers to reach `load`.
+    /// Returns the main resource response, which is `None` for same-document navigations such as anchor links.
+    /// The navigation timeout of the page applies.
+    ///
+    /// ```js
+    /// const [response] = await Promise.all([
+    ///  frame.waitForNavigation(), // The promise resolves after navigation has finished
+    ///  frame.click('a.delayed-navigation'), // Clicking the link will indirectly cause a navigation
+    /// ]);
+    /// ```
+    pub async fn expect_navigation<F, Fut, T>(&self, action: F) -> ArcResult<Option<Response>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ArcResult<T>>
+    {
+        let inner = upgrade(&self.inner)?;
+        let rx = inner.subscribe_event();
+        let wait = inner.wait_for_navigation(rx, None, None);
+        let (r, _) = futures::future::try_join(wait, action()).await?;
+        Ok(r.map(Response::new))
+    }
 }
 
 #[derive(Debug)]
@@ -690,6 +769,31 @@ impl<'a, 'b> GotoBuilder<'a, 'b> {
     }
 }
 
+pub struct WaitForUrlBuilder {
+    inner: Weak<Impl>,
+    url: UrlMatcher,
+    args: WaitForUrlArgs
+}
+
+impl WaitForUrlBuilder {
+    pub(crate) fn new(inner: Weak<Impl>, url: UrlMatcher) -> Self {
+        let args = WaitForUrlArgs::default();
+        Self { inner, url, args }
+    }
+
+    pub async fn wait_for_url(self) -> ArcResult<()> {
+        let Self { inner, url, args } = self;
+        upgrade(&inner)?.wait_for_url(&url, args).await
+    }
+
+    setter! {
+        /// Maximum time in milliseconds, defaults to the default navigation timeout of the page. `0` disables it.
+        timeout: Option<f64>,
+        /// Defaults to `load`
+        wait_until: Option<DocumentLoadState>
+    }
+}
+
 macro_rules! clicker {
     ($t: ident, $f: ident) => {
         pub struct $t<'a> {
@@ -728,6 +832,9 @@ macro_rules! clicker {
                 /// A point to use relative to the top-left corner of element padding box. If not specified, uses some visible point of the
                 /// element.
                 position: Option<Position>,
+                /// When true, the call requires selector to resolve to a single element. If given selector resolves to more than one
+                /// element, the call throws an exception.
+                strict: Option<bool>,
                 timeout: Option<f64>,
                 /// When set, this method only performs the [actionability](https://playwright.dev/docs/actionability/) checks and skips the action. Defaults to
                 /// `false`. Useful to wait until the element is ready for the action without performing it.
@@ -760,6 +867,9 @@ impl<'a> WaitForSelectorBuilder<'a> {
     setter! {
         /// Defaults to `'visible'`.
         state: Option<FrameState>,
+        /// When true, the call requires selector to resolve to a single element. If given selector resolves to more than one
+        /// element, the call throws an exception.
+        strict: Option<bool>,
         timeout: Option<f64>
     }
 }
@@ -790,6 +900,9 @@ macro_rules! type_builder {
                 /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating to
                 /// inaccessible pages. Defaults to `false`.
                 no_wait_after: Option<bool>,
+                /// When true, the call requires selector to resolve to a single element. If given selector resolves to more than one
+                /// element, the call throws an exception.
+                strict: Option<bool>,
                 timeout: Option<f64>
             }
         }
@@ -810,7 +923,9 @@ impl<'a> HoverBuilder<'a> {
         Self { inner, args }
     }
 
-    pub async fn goto(self) -> Result<(), Arc<Error>> {
+    pub async fn goto(self) -> Result<(), Arc<Error>> { self.hover().await }
+
+    pub async fn hover(self) -> Result<(), Arc<Error>> {
         let Self { inner, args } = self;
         upgrade(&inner)?.hover(args).await
     }
@@ -824,6 +939,9 @@ impl<'a> HoverBuilder<'a> {
         /// A point to use relative to the top-left corner of element padding box. If not specified, uses some visible point of the
         /// element.
         position: Option<Position>,
+        /// When true, the call requires selector to resolve to a single element. If given selector resolves to more than one
+        /// element, the call throws an exception.
+        strict: Option<bool>,
         timeout: Option<f64>,
         /// When set, this method only performs the [actionability](https://playwright.dev/docs/actionability/) checks and skips the action. Defaults to
         /// `false`. Useful to wait until the element is ready for the action without performing it.
@@ -883,6 +1001,9 @@ impl<'a> TapBuilder<'a> {
         /// A point to use relative to the top-left corner of element padding box. If not specified, uses some visible point of the
         /// element.
         position: Option<Position>,
+        /// When true, the call requires selector to resolve to a single element. If given selector resolves to more than one
+        /// element, the call throws an exception.
+        strict: Option<bool>,
         timeout: Option<f64>,
         /// When set, this method only performs the [actionability](https://playwright.dev/docs/actionability/) checks and skips the action. Defaults to
         /// `false`. Useful to wait until the element is ready for the action without performing it.
@@ -912,6 +1033,9 @@ impl<'a, 'b> FillBuilder<'a, 'b> {
         /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating to
         /// inaccessible pages. Defaults to `false`.
         no_wait_after: Option<bool>,
+        /// When true, the call requires selector to resolve to a single element. If given selector resolves to more than one
+        /// element, the call throws an exception.
+        strict: Option<bool>,
         timeout: Option<f64>
     }
 }
@@ -944,6 +1068,9 @@ macro_rules! check_builder {
                 /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating to
                 /// inaccessible pages. Defaults to `false`.
                 no_wait_after: Option<bool>,
+                /// When true, the call requires selector to resolve to a single element. If given selector resolves to more than one
+                /// element, the call throws an exception.
+                strict: Option<bool>,
                 timeout: Option<f64>,
                 /// When set, this method only performs the [actionability](https://playwright.dev/docs/actionability/) checks and skips the action. Defaults to
                 /// `false`. Useful to wait until the element is ready for the action without performing it.
@@ -1071,6 +1198,9 @@ impl<'a> SelectOptionBuilder<'a> {
         /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating to
         /// inaccessible pages. Defaults to `false`.
         no_wait_after: Option<bool>,
+        /// When true, the call requires selector to resolve to a single element. If given selector resolves to more than one
+        /// element, the call throws an exception.
+        strict: Option<bool>,
         timeout: Option<f64>
     }
 
@@ -1112,6 +1242,9 @@ impl<'a> SetInputFilesBuilder<'a> {
         /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating to
         /// inaccessible pages. Defaults to `false`.
         no_wait_after: Option<bool>,
+        /// When true, the call requires selector to resolve to a single element. If given selector resolves to more than one
+        /// element, the call throws an exception.
+        strict: Option<bool>,
         timeout: Option<f64>
     }
 
@@ -15,6 +15,7 @@ use std::fmt;
 ///
 /// JsHandle instances can be used as an argument in [`method: Page.evalOnSelector`], [`method: Page.evaluate`] and
 /// [`method: Page.evaluateHandle`] methods.
+#[derive(Debug, Clone)]
 pub struct JsHandle {
     inner: Weak<Impl>
 }
@@ -0,0 +1,422 @@
+pub use crate::api::frame::{
+    CheckBuilder, ClickBuilder, DblClickBuilder, FillBuilder, HoverBuilder, PressBuilder,
+    SelectOptionBuilder, SetInputFilesBuilder, TapBuilder, TypeBuilder, UncheckBuilder,
+    WaitForSelectorBuilder
+};
+use crate::{
+    api::{ElementHandle, Mask},
+    imp::{
+        core::*,
+        element_handle::ScreenshotArgs,
+        frame::{Frame as FrameImpl, FrameState, WaitForSelectorArgs},
+        prelude::*,
+        screenshot::MaskTarget,
+        utils::{
+            File, FloatRect, ScreenshotAnimations, ScreenshotCaret, ScreenshotScale, ScreenshotType
+        }
+    },
+    Error
+};
+
+macro_rules! is_checked {
+    ($f: ident) => {
+        pub async fn $f(&self, timeout: Option<f64>) -> ArcResult<bool> {
+            upgrade(&self.frame)?
+                .$f(&self.selector, timeout, Some(true))
+                .await
+        }
+    };
+}
+
+/// Locators are the central piece of Playwright's auto-waiting and retry-ability. In a nutshell, locators represent a way
+/// to find element(s) on the page at any moment. Locator can be created with the [`Page::locator`] method.
+///
+/// The difference between the Locator and [`ElementHandle`] is that the latter points to a particular element, while Locator
+/// captures the logic of how to retrieve that element.
+///
+/// Locators are strict. This means that all operations on locators that imply some target DOM element will throw if more
+/// than one element matches given selector.
+///
+/// ```js
+/// const locator = page.locator('text=Submit');
+/// await locator.click();
+///// Throws if there are several buttons in DOM:
+/// await page.locator('button').click();
+///// Works because we explicitly tell locator to pick the first element:
+/// await page.locator('button').first().click();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Locator {
+    frame: Weak<FrameImpl>,
+    selector: String
+}
+
+impl From<&Locator> for Mask {
+    fn from(l: &Locator) -> Self {
+        Mask(MaskTarget::Locator {
+            frame: l.frame.clone(),
+            selector: l.selector.clone()
+        })
+    }
+}
+
+impl PartialEq for Locator {
+    fn eq(&self, other: &Self) -> bool {
+        self.frame.ptr_eq(&other.frame) && self.selector == other.selector
+    }
+}
+
+impl Locator {
+    pub(crate) fn new(frame: Weak<FrameImpl>, selector: String) -> Self { Self { frame, selector } }
+
+    pub(crate) fn frame(&self) -> Weak<FrameImpl> { self.frame.clone() }
+
+    /// The selector this locator resolves on every call.
+    pub fn selector(&self) -> &str { &self.selector }
+
+    /// The method finds an element matching the specified selector in the locator's subtree.
+    pub fn locator(&self, selector: &str) -> Locator {
+        Locator::new(
+            self.frame.clone(),
+            format!("{} >> {}", self.selector, selector)
+        )
+    }
+
+    /// When working with iframes, you can create a frame locator that will enter the iframe and allow selecting elements in
+    /// that iframe.
+    pub fn frame_locator(&self, selector: &str) -> FrameLocator {
+        FrameLocator::new(
+            self.frame.clone(),
+            format!("{} >> {}", self.selector, selector)
+        )
+    }
+
+    /// Returns locator to the first matching element.
+    pub fn first(&self) -> Locator { self.nth(0) }
+
+    /// Returns locator to the last matching element.
+    pub fn last(&self) -> Locator { self.nth(-1) }
+
+    /// Returns locator to the n-th matching element. It's zero based, `nth(0)` selects the first element.
+    pub fn nth(&self, index: i32) -> Locator {
+        Locator::new(
+            self.frame.clone(),
+            format!("{} >> nth={}", self.selector, index)
+        )
+    }
+
+    /// This method narrows existing locator according to the options, for example filters by text.
+    ///
+    /// - `has_text`: Matches elements containing specified text somewhere inside, possibly in a child or a descendant element.
+    /// The match is case-insensitive and searches for a substring.
+    /// - `has`: Matches elements containing an element that matches an inner locator. Inner locator is queried against the
+    /// outer one. It must belong to the same frame.
+    pub fn filter(&self, has_text: Option<&str>, has: Option<&Locator>) -> Result<Locator, Error> {
+        let mut selector = self.selector.clone();
+        if let Some(text) = has_text {
+            let escaped = serde_json::to_string(text)?;
+            selector = format!("{} >> :scope:has-text({})", selector, escaped);
+        }
+        if let Some(has) = has {
+            if !self.frame.ptr_eq(&has.frame) {
+                return Err(Error::LocatorFrameMismatch);
+            }
+            let escaped = serde_json::to_string(&has.selector)?;
+            selector = format!("{} >> has={}", selector, escaped);
+        }
+        Ok(Locator::new(self.frame.clone(), selector))
+    }
+
+    /// Returns the number of elements matching given selector.
+    pub async fn count(&self) -> ArcResult<usize> {
+        upgrade(&self.frame)?.query_count(&self.selector).await
+    }
+
+    /// Returns an array of `node.innerText` values for all matching nodes.
+    pub async fn all_inner_texts(&self) -> ArcResult<Vec<String>> {
+        self.evaluate_all::<(), _>("ee => ee.map(e => e.innerText)", None)
+            .await
+    }
+
+    /// Returns an array of `node.textContent` values for all matching nodes.
+    pub async fn all_text_contents(&self) -> ArcResult<Vec<String>> {
+        self.evaluate_all::<(), _>("ee => ee.map(e => e.textContent || '')", None)
+            .await
+    }
+
+    /// Resolves given locator to the first matching DOM element. If no elements matching the query are visible, waits for
+    /// them up to a given timeout. If multiple elements match the selector, throws.
+    pub async fn element_handle(&self, timeout: Option<f64>) -> ArcResult<ElementHandle> {
+        let mut args = WaitForSelectorArgs::new(&self.selector);
+        args.strict = Some(true);
+        args.state = Some(FrameState::Attached);
+        args.timeout = timeout;
+        let e = upgrade(&self.frame)?
+            .wait_for_selector(args)
+            .await?
+            .ok_or(Error::ObjectNotFound)?;
+        Ok(ElementHandle::new(e))
+    }
+
+    /// Resolves given locator to all matching DOM elements.
+    pub async fn element_handles(&self) -> ArcResult<Vec<ElementHandle>> {
+        let es = upgrade(&self.frame)?
+            .query_selector_all(&self.selector)
+            .await?;
+        Ok(es.into_iter().map(ElementHandle::new).collect())
+    }
+
+    /// Returns the return value of `expression`. The matching element is passed as a first argument to `expression`.
+    pub async fn evaluate<T, U>(&self, expression: &str, arg: Option<T>) -> ArcResult<U>
+    where
+        T: Serialize,
+        U: DeserializeOwned
+    {
+        upgrade(&self.frame)?
+            .evaluate_on_selector(&self.selector, expression, arg, Some(true))
+            .await
+    }
+
+    /// Returns the return value of `expression`. An array of all matching elements is passed as a first argument to
+    /// `expression`.
+    pub async fn evaluate_all<T, U>(&self, expression: &str, arg: Option<T>) -> ArcResult<U>
+    where
+        T: Serialize,
+        U: DeserializeOwned
+    {
+        upgrade(&self.frame)?
+            .evaluate_on_selector_all(&self.selector, expression, arg)
+            .await
+    }
+
+    /// Clicks the element. See [`Frame::click_builder`](crate::api::Frame::click_builder) for the steps.
+    pub fn click_builder(&self) -> ClickBuilder<'_> {
+        ClickBuilder::new(self.frame.clone(), &self.selector).strict(true)
+    }
+
+    /// Double clicks the element. See [`Frame::dblclick_builder`](crate::api::Frame::dblclick_builder) for the steps.
+    pub fn dblclick_builder(&self) -> DblClickBuilder<'_> {
+        DblClickBuilder::new(self.frame.clone(), &self.selector).strict(true)
+    }
+
+    /// Taps the element. See [`Frame::tap_builder`](crate::api::Frame::tap_builder) for the steps.
+    pub fn tap_builder(&self) -> TapBuilder<'_> {
+        TapBuilder::new(self.frame.clone(), &self.selector).strict(true)
+    }
+
+    /// Waits for actionability checks, focuses the element, fills it and triggers an `input` event after filling.
+    pub fn fill_builder<'b>(&self, value: &'b str) -> FillBuilder<'_, 'b> {
+        FillBuilder::new(self.frame.clone(), &self.selector, value).strict(true)
+    }
+
+    /// Focuses the element, and then sends a `keydown`, `keypress`/`input`, and `keyup` event for each character in the text.
+    pub fn type_builder<'b>(&self, text: &'b str) -> TypeBuilder<'_, 'b> {
+        TypeBuilder::new(self.frame.clone(), &self.selector, text).strict(true)
+    }
+
+    /// Focuses the element, and then uses [`Keyboard::down`](crate::api::Keyboard::down) and
+    /// [`Keyboard::up`](crate::api::Keyboard::up).
+    pub fn press_builder<'b>(&self, key: &'b str) -> PressBuilder<'_, 'b> {
+        PressBuilder::new(self.frame.clone(), &self.selector, key).strict(true)
+    }
+
+    /// Hovers over the element. See [`Frame::hover_builder`](crate::api::Frame::hover_builder) for the steps.
+    pub fn hover_builder(&self) -> HoverBuilder<'_> {
+        HoverBuilder::new(self.frame.clone(), &self.selector).strict(true)
+    }
+
+    /// Ensures that the element is a checkbox or a radio input and checks it.
+    pub fn check_builder(&self) -> CheckBuilder<'_> {
+        CheckBuilder::new(self.frame.clone(), &self.selector).strict(true)
+    }
+
+    /// Ensures that the element is a checkbox or a radio input and unchecks it.
+    pub fn uncheck_builder(&self) -> UncheckBuilder<'_> {
+        UncheckBuilder::new(self.frame.clone(), &self.selector).strict(true)
+    }
+
+    /// Selects options in the `<select>` element.
+    pub fn select_option_builder(&self) -> SelectOptionBuilder<'_> {
+        SelectOptionBuilder::new(self.frame.clone(), &self.selector).strict(true)
+    }
+
+    /// Sets the value of the file input.
+    pub fn set_input_files_builder(&self, file: File) -> SetInputFilesBuilder<'_> {
+        SetInputFilesBuilder::new(self.frame.clone(), &self.selector, file).strict(true)
+    }
+
+    /// Returns when element specified by locator satisfies the `state` option. Defaults to `'visible'`.
+    pub fn wait_for_builder(&self) -> WaitForSelectorBuilder<'_> {
+        WaitForSelectorBuilder::new(self.frame.clone(), &self.selector).strict(true)
+    }
+
+    /// Returns the buffer with the captured screenshot of the element.
+    ///
+    /// This method waits for the actionability checks, then scrolls element into view before taking a screenshot.
+    pub fn screenshot_builder(&self) -> ScreenshotBuilder<'_> {
+        ScreenshotBuilder::new(self.frame.clone(), &self.selector)
+    }
+
+    /// Calls [focus](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/focus) on the element.
+    pub async fn focus(&self, timeout: Option<f64>) -> ArcResult<()> {
+        upgrade(&self.frame)?
+            .focus(&self.selector, timeout, Some(true))
+            .await
+    }
+
+    /// Returns the `node.textContent`.
+    pub async fn text_content(&self, timeout: Option<f64>) -> ArcResult<Option<String>> {
+        upgrade(&self.frame)?
+            .text_content(&self.selector, timeout, Some(true))
+            .await
+    }
+
+    /// Returns the `element.innerText`.
+    pub async fn inner_text(&self, timeout: Option<f64>) -> ArcResult<String> {
+        upgrade(&self.frame)?
+            .inner_text(&self.selector, timeout, Some(true))
+            .await
+    }
+
+    /// Returns the `element.innerHTML`.
+    pub async fn inner_html(&self, timeout: Option<f64>) -> ArcResult<String> {
+        upgrade(&self.frame)?
+            .inner_html(&self.selector, timeout, Some(true))
+            .await
+    }
+
+    /// Returns element attribute value.
+    pub async fn get_attribute(
+        &self,
+        name: &str,
+        timeout: Option<f64>
+    ) -> ArcResult<Option<String>> {
+        upgrade(&self.frame)?
+            .get_attribute(&self.selector, name, timeout, Some(true))
+            .await
+    }
+
+    /// Dispatches the `type` event on the element regardless of its visibility state.
+    pub async fn dispatch_event<T>(&self, r#type: &str, event_init: Option<T>) -> ArcResult<()>
+    where
+        T: Serialize
+    {
+        upgrade(&self.frame)?
+            .dispatch_event(&self.selector, r#type, event_init, Some(true))
+            .await
+    }
+
+    is_checked! {is_checked}
+    is_checked! {is_disabled}
+    is_checked! {is_editable}
+    is_checked! {is_enabled}
+    is_checked! {is_hidden}
+    is_checked! {is_visible}
+}
+
+/// FrameLocator represents a view to the `iframe` on the page. It captures the logic sufficient to retrieve the `iframe`
+/// and locate elements in that iframe. FrameLocator can be created with either [`Page::frame_locator`] or
+/// [`Locator::frame_locator`] method.
+///
+/// ```js
+/// const locator = page.frameLocator('#my-frame').locator('text=Submit');
+/// await locator.click();
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameLocator {
+    frame: Weak<FrameImpl>,
+    selector: String
+}
+
+impl FrameLocator {
+    pub(crate) fn new(frame: Weak<FrameImpl>, selector: String) -> Self { Self { frame, selector } }
+
+    /// The method finds an element matching the specified selector in the FrameLocator's subtree.
+    pub fn locator(&self, selector: &str) -> Locator {
+        Locator::new(
+            self.frame.clone(),
+            format!("{} >> control=enter-frame >> {}", self.selector, selector)
+        )
+    }
+
+    /// When working with iframes, you can create a frame locator that will enter the iframe and allow selecting elements in
+    /// that iframe.
+    pub fn frame_locator(&self, selector: &str) -> FrameLocator {
+        FrameLocator::new(
+            self.frame.clone(),
+            format!("{} >> control=enter-frame >> {}", self.selector, selector)
+        )
+    }
+
+    /// Returns locator to the first matching frame.
+    pub fn first(&self) -> FrameLocator { self.nth(0) }
+
+    /// Returns locator to the last matching frame.
+    pub fn last(&self) -> FrameLocator { self.nth(-1) }
+
+    /// Returns locator to the n-th matching frame. It's zero based, `nth(0)` selects the first frame.
+    pub fn nth(&self, index: i32) -> FrameLocator {
+        FrameLocator::new(
+            self.frame.clone(),
+            format!("{} >> nth={}", self.selector, index)
+        )
+    }
+}
+
+pub struct ScreenshotBuilder<'a> {
+    frame: Weak<FrameImpl>,
+    selector: &'a str,
+    args: ScreenshotArgs<'a>
+}
+
+impl<'a> ScreenshotBuilder<'a> {
+    pub(crate) fn new(frame: Weak<FrameImpl>, selector: &'a str) -> Self {
+        let args = ScreenshotArgs::default();
+        Self {
+            frame,
+            selector,
+            args
+        }
+    }
+
+    pub async fn screenshot(self) -> ArcResult<Vec<u8>> {
+        let Self {
+            frame,
+            selector,
+            args
+        } = self;
+        let mut wait = WaitForSelectorArgs::new(selector);
+        wait.strict = Some(true);
+        wait.state = Some(FrameState::Attached);
+        wait.timeout = args.timeout;
+        let e = upgrade(&frame)?
+            .wait_for_selector(wait)
+            .await?
+            .ok_or(Error::ObjectNotFound)?;
+        upgrade(&e)?.screenshot(args).await
+    }
+
+    /// Specify screenshot type, defaults to `png`.
+    pub fn r#type(mut self, x: ScreenshotType) -> Self {
+        self.args.r#type = Some(x);
+        self
+    }
+
+    setter! {
+        /// An area of the element to capture, relative to its top-left corner. Parts outside of the element are cut off.
+        clip: Option<FloatRect>,
+        /// The file path to save the image to. The screenshot type will be inferred from file extension. If `path` is a relative
+        /// path, then it is resolved relative to the current working directory. If no path is provided, the image won't be saved to
+        /// the disk.
+        path: Option<&'a Path>,
+        quality: Option<i64>,
+        timeout: Option<f64>
+    }
+
+    screenshot_options!();
+
+    pub fn clear_type(mut self) -> Self {
+        self.args.r#type = None;
+        self
+    }
+}
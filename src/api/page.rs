@@ -1,21 +1,24 @@
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 pub use crate::{
     api::{
         frame::{
             AddScriptTagBuilder, CheckBuilder, ClickBuilder, DblClickBuilder, FillBuilder,
             GotoBuilder, HoverBuilder, PressBuilder, SelectOptionBuilder, SetContentBuilder,
             SetInputFilesBuilder, TapBuilder, TypeBuilder, UncheckBuilder, WaitForFunctionBuilder,
-            WaitForSelectorBuilder
+            WaitForSelectorBuilder, WaitForUrlBuilder
         },
-        Download, JsHandle, Request
+        Download, JsHandle, Mask, Request
     },
-    imp::page::{EventType, Media}
+    imp::page::{EventType, JsError, Media}
 };
 use crate::protocol::generated::LifecycleEvent;
 use crate::{
     api::{
-        input_device::*, Accessibility, BrowserContext, ConsoleMessage, ElementHandle, FileChooser,
-        Frame, Keyboard, Response, TouchScreen, Video, WebSocket, Worker
+        input_device::*,
+        route::{HarTarget, RouteFromHarBuilder},
+        Accessibility, BindingSource, BrowserContext, ConsoleMessage, Dialog, ElementHandle,
+        FileChooser, Frame, FrameLocator, Keyboard, Locator, Response, Route, TouchScreen, Video,
+        WebSocket, Worker
     },
     imp::{
         core::*,
@@ -23,7 +26,8 @@ use crate::{
         page::{EmulateMediaArgs, Evt, Page as Impl, PdfArgs, ReloadArgs, ScreenshotArgs},
         prelude::*,
         utils::{
-            ColorScheme, DocumentLoadState, File, FloatRect, Length, PdfMargins, ScreenshotType,
+            ColorScheme, DocumentLoadState, File, FloatRect, Length, PaperFormat, PdfMargins,
+            ScreenshotAnimations, ScreenshotCaret, ScreenshotScale, ScreenshotType, UrlMatcher,
             Viewport
         }
     },
@@ -254,7 +258,7 @@ impl Page {
     ///
     /// > NOTE: `headerTemplate` and `footerTemplate` markup have the following limitations: > 1. Script tags inside templates
     /// are not evaluated. > 2. Page styles are not visible inside templates.
-    pub fn pdf_builder(&self) -> PdfBuilder<'_, '_, '_, '_, '_, '_, '_, '_, '_, '_> {
+    pub fn pdf_builder(&self) -> PdfBuilder<'_, '_, '_, '_, '_, '_, '_, '_, '_> {
         PdfBuilder::new(self.inner.clone())
     }
 
@@ -326,19 +330,267 @@ impl Page {
         upgrade(&self.inner)?.set_extra_http_headers(headers).await
     }
 
+    /// Stream of the dialogs which the page opens. Each of them **must** be either accepted or dismissed.
+    /// While no such stream is alive, dialogs are dismissed automatically, except that `beforeunload` is accepted.
+    pub fn subscribe_dialog(&self) -> Result<impl futures::stream::Stream<Item = Dialog>, Error> {
+        use futures::{future::ready, stream::StreamExt};
+        use tokio_stream::wrappers::BroadcastStream;
+        let stream = BroadcastStream::new(upgrade(&self.inner)?.subscribe_dialog());
+        Ok(stream.filter_map(|d| ready(d.ok().map(Dialog::new))))
+    }
+
     pub async fn expect_event(&self, evt: EventType) -> Result<Event, Error> {
         let stream = upgrade(&self.inner)?.subscribe_event();
         let timeout = upgrade(&self.inner)?.default_timeout();
         expect_event(stream, evt, timeout).await.map(Event::from)
     }
 
+    /// Waits for the first event of `evt` for which `predicate` returns true.
+    /// `timeout` is in milliseconds, defaults to the default timeout and `0` disables it.
+    ///
+    /// ```js
+    /// const [frame, _] = await Promise.all([
+    ///  page.waitForEvent('framenavigated', frame => frame.name() === 'inner'),
+    ///  page.click('button')
+    /// ]);
+    /// ```
+    pub async fn wait_for_event<F>(
+        &self,
+        evt: EventType,
+        mut predicate: F,
+        timeout: Option<f64>
+    ) -> Result<Event, Error>
+    where
+        F: FnMut(&Event) -> bool
+    {
+        let (stream, default_timeout) = {
+            let inner = upgrade(&self.inner)?;
+            (inner.subscribe_event(), inner.default_timeout())
+        };
+        let timeout = timeout.map(|t| t as u32).unwrap_or(default_timeout);
+        let e =
+            wait_for_event(stream, evt, |e| predicate(&Event::from(e.clone())), timeout).await?;
+        Ok(Event::from(e))
+    }
+
+    /// Runs `action` and waits for a request matching `url` which it issues.
+    /// The event is subscribed before `action` starts, so it cannot be missed.
+    ///
+    /// ```js
+    /// const [request] = await Promise.all([
+    ///  page.waitForRequest('**/api/*'),
+    ///  page.click('button')
+    /// ]);
+    /// ```
+    pub async fn expect_request<M, F, Fut, T>(&self, url: M, action: F) -> ArcResult<Request>
+    where
+        M: Into<UrlMatcher>,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ArcResult<T>>
+    {
+        let url = url.into();
+        let predicate = move |e: &Evt| match e {
+            Evt::Request(r) => r
+                .upgrade()
+                .map(|r| url.is_match(r.url()))
+                .unwrap_or_default(),
+            _ => false
+        };
+        match self
+            .wait_during(EventType::Request, predicate, action)
+            .await?
+        {
+            Event::Request(x) => Ok(x),
+            _ => unreachable!()
+        }
+    }
+
+    /// Runs `action` and waits for a response matching `url`.
+    /// The event is subscribed before `action` starts, so it cannot be missed.
+    pub async fn expect_response<M, F, Fut, T>(&self, url: M, action: F) -> ArcResult<Response>
+    where
+        M: Into<UrlMatcher>,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ArcResult<T>>
+    {
+        let url = url.into();
+        let predicate = move |e: &Evt| match e {
+            Evt::Response(r) => r
+                .upgrade()
+                .map(|r| url.is_match(r.url()))
+                .unwrap_or_default(),
+            _ => false
+        };
+        match self
+            .wait_during(EventType::Response, predicate, action)
+            .await?
+        {
+            Event::Response(x) => Ok(x),
+            _ => unreachable!()
+        }
+    }
+
+    /// Runs `action` and waits for a popup which it opens.
+    pub async fn expect_popup<F, Fut, T>(&self, action: F) -> ArcResult<Page>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ArcResult<T>>
+    {
+        match self.wait_during(EventType::Popup, |_| true, action).await? {
+            Event::Popup(x) => Ok(x),
+            _ => unreachable!()
+        }
+    }
+
+    /// Runs `action` and waits for a download which it starts.
+    pub async fn expect_download<F, Fut, T>(&self, action: F) -> ArcResult<Download>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ArcResult<T>>
+    {
+        match self
+            .wait_during(EventType::Download, |_| true, action)
+            .await?
+        {
+            Event::Download(x) => Ok(x),
+            _ => unreachable!()
+        }
+    }
+
+    /// Runs `action` and waits for a console message which it logs.
+    pub async fn expect_console_message<F, Fut, T>(&self, action: F) -> ArcResult<ConsoleMessage>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ArcResult<T>>
+    {
+        match self
+            .wait_during(EventType::Console, |_| true, action)
+            .await?
+        {
+            Event::Console(x) => Ok(x),
+            _ => unreachable!()
+        }
+    }
+
+    /// Subscribes, then runs `action` and the wait concurrently. Fails as soon as either fails.
+    async fn wait_during<P, F, Fut, T>(
+        &self,
+        evt: EventType,
+        predicate: P,
+        action: F
+    ) -> ArcResult<Event>
+    where
+        P: FnMut(&Evt) -> bool,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ArcResult<T>>
+    {
+        let (stream, timeout) = {
+            let inner = upgrade(&self.inner)?;
+            (inner.subscribe_event(), inner.default_timeout())
+        };
+        let wait = async move {
+            wait_for_event(stream, evt, predicate, timeout)
+                .await
+                .map_err(Arc::new)
+        };
+        let (e, _) = futures::future::try_join(wait, action()).await?;
+        Ok(Event::from(e))
+    }
+
     subscribe_event! {}
 
+    /// Routing provides the capability to modify network requests that are made by a page.
+    ///
+    /// Once routing is enabled, every request matching the url pattern will stall unless it's continued, fulfilled or
+    /// aborted. When several routes match the request, the last registered one takes precedence. Requests that match no
+    /// page route fall through to the routes of [`BrowserContext::route`], and are continued if nothing matches there
+    /// either.
+    ///
+    /// An example of a naive handler that aborts all image requests:
+    ///
+    /// ```js
+    /// const page = await browser.newPage();
+    /// await page.route('**/*.{png,jpg,jpeg}', route => route.abort());
+    /// await page.goto('https://example.com');
+    /// await browser.close();
+    /// ```
+    ///
+    /// > NOTE: Enabling routing disables http cache.
+    /// # Args
+    /// ## url
+    /// A glob pattern, regex pattern or predicate receiving URL to match while routing.
+    /// ## handler
+    /// handler function to route the request.
+    pub async fn route<M, F, Fut>(&self, url: M, handler: F) -> ArcResult<()>
+    where
+        M: Into<UrlMatcher>,
+        F: Fn(Route) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ArcResult<()>> + Send + 'static
+    {
+        let handler = crate::api::route::route_handler(url.into(), handler);
+        upgrade(&self.inner)?.route(handler).await
+    }
+
+    /// Serves requests of the page from a HAR file. See [`BrowserContext::route_from_har_builder`].
+    /// With [`HarNotFound::Fallback`](crate::api::HarNotFound::Fallback) requests which are not in the HAR go to
+    /// the routes of the browser context.
+    pub fn route_from_har_builder<'a>(&self, har: &'a Path) -> RouteFromHarBuilder<'a> {
+        RouteFromHarBuilder::new(HarTarget::Page(self.inner.clone()), har)
+    }
+
+    /// Removes all routes created with [`Page::route`] for the equal `url` matcher.
+    pub async fn unroute<M>(&self, url: M) -> ArcResult<()>
+    where
+        M: Into<UrlMatcher>
+    {
+        upgrade(&self.inner)?.unroute(&url.into()).await
+    }
+
+    /// The method adds a function called `name` on the `window` object of every frame in this page. When called, the
+    /// function executes `f` and returns a [Promise] which resolves to the return value of `f`.
+    ///
+    /// The first argument of `f` contains information about the caller: [`BindingSource`]. The second one is the
+    /// arguments of the JavaScript call deserialized into `A`, e.g. a tuple.
+    /// If `f` returns an error, the promise is rejected with its message.
+    ///
+    /// See [`BrowserContext::expose_binding`] for the context-wide version.
+    ///
+    /// > NOTE: Functions installed via [`Page::expose_binding`] survive navigations.
+    pub async fn expose_binding<F, Fut, A, R, E>(&self, name: &str, f: F) -> ArcResult<()>
+    where
+        F: Fn(BindingSource, A) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R, E>> + Send + 'static,
+        A: DeserializeOwned,
+        R: Serialize,
+        E: Display
+    {
+        let f = crate::api::binding_call::binding_fn(f);
+        upgrade(&self.inner)?.expose_binding(name, f).await
+    }
+
+    /// The method adds a function called `name` on the `window` object of every frame in the page. When called, the
+    /// function executes `f` and returns a [Promise] which resolves to the return value of `f`.
+    ///
+    /// ```js
+    /// await page.exposeFunction('add', (a, b) => a + b);
+    /// await page.evaluate(() => window.add(1, 2));
+    /// ```
+    ///
+    /// See [`BrowserContext::expose_function`] for context-wide exposed function.
+    ///
+    /// > NOTE: Functions installed via [`Page::expose_function`] survive navigations.
+    pub async fn expose_function<F, Fut, A, R, E>(&self, name: &str, f: F) -> ArcResult<()>
+    where
+        F: Fn(A) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R, E>> + Send + 'static,
+        A: DeserializeOwned,
+        R: Serialize,
+        E: Display
+    {
+        self.expose_binding(name, move |_, a| f(a)).await
+    }
+
     // coverage
-    // expose_binding
-    // expose_function
-    // route
-    // unroute
     // once_dialog
 
     pub async fn wait_for_timeout(&self, timeout: f64) {
@@ -365,13 +617,15 @@ pub enum Event {
     /// await page.evaluate(() => console.log('hello', 5, {foo: 'bar'}));
     /// ```
     Console(ConsoleMessage),
-    /// Emitted when a JavaScript dialog appears, such as `alert`, `prompt`, `confirm` or `beforeunload`. Listener **must**
-    /// either [`method: Dialog.accept`] or [`method: Dialog.dismiss`] the dialog - otherwise the page will
+    /// Emitted when a JavaScript dialog appears, such as `alert`, `prompt`, `confirm` or `beforeunload`. Handlers
+    /// subscribed with [`Page::subscribe_dialog`] **must** either [`method: Dialog.accept`] or [`method: Dialog.dismiss`]
+    /// the dialog - otherwise the page will
     /// [freeze](https://developer.mozilla.org/en-US/docs/Web/JavaScript/EventLoop#never_blocking) waiting for the dialog, and
     /// actions like click will never finish.
     ///
-    /// > NOTE: When no [`event: Page.dialog`] listeners are present, all dialogs are automatically dismissed.
-    Dialog,
+    /// > NOTE: When no [`Page::subscribe_dialog`] handlers are present, all dialogs are automatically dismissed, and this
+    /// event is only a notification.
+    Dialog(Dialog),
     DomContentLoaded,
     /// Emitted when attachment download started. User can access basic file operations on downloaded content via the passed
     /// `Download` instance.
@@ -393,7 +647,14 @@ pub enum Event {
     FrameDetached(Frame),
     FrameNavigated(Frame),
     Load,
-    PageError,
+    /// Emitted when an uncaught exception happens within the page.
+    ///
+    /// ```js
+    /// page.on('pageerror', exception => {
+    ///  console.log(`Uncaught exception: "${exception}"`);
+    /// });
+    /// ```
+    PageError(JsError),
     /// Emitted when the page opens a new tab or window. This event is emitted in addition to the
     /// [`event: BrowserContext.page`], but only for popups relevant to this page.
     ///
@@ -437,7 +698,7 @@ impl Debug for Event {
             Event::Close => "Close",
             Event::Crash => "Crash",
             Event::Console(_) => "Console",
-            Event::Dialog => "Dialog",
+            Event::Dialog(_) => "Dialog(_)",
             Event::DomContentLoaded => "DomContentLoaded",
             Event::Download(_) => "Download(_)",
             // Event::FileChooser(_) => "FileChooser(_)",
@@ -445,7 +706,7 @@ impl Debug for Event {
             Event::FrameDetached(_) => "FrameDetached(_)",
             Event::FrameNavigated(_) => "FrameNavigated(_)",
             Event::Load => "Load",
-            Event::PageError => "PageError",
+            Event::PageError(_) => "PageError(_)",
             Event::Popup(_) => "Popup(_)",
             Event::Request(_) => "Request(_)",
             Event::RequestFailed(_) => "RequestFailed(_)",
@@ -465,11 +726,11 @@ impl From<Evt> for Event {
             Evt::Close => Event::Close,
             Evt::Crash => Event::Crash,
             Evt::Console(x) => Event::Console(ConsoleMessage::new(x)),
-            Evt::Dialog => Event::Dialog,
+            Evt::Dialog(x) => Event::Dialog(Dialog::new(x)),
             Evt::Download(x) => Event::Download(Download::new(x)),
             // Evt::FileChooser(x) => Event::FileChooser(x),
             Evt::DomContentLoaded => Event::DomContentLoaded,
-            Evt::PageError => Event::PageError,
+            Evt::PageError(x) => Event::PageError(x),
             Evt::Request(x) => Event::Request(Request::new(x)),
             Evt::Response(x) => Event::Response(Response::new(x)),
             Evt::RequestFailed(x) => Event::RequestFailed(Request::new(x)),
@@ -494,11 +755,11 @@ impl IsEvent for Event {
             Self::Close => EventType::Close,
             Self::Crash => EventType::Crash,
             Self::Console(_) => EventType::Console,
-            Self::Dialog => EventType::Dialog,
+            Self::Dialog(_) => EventType::Dialog,
             Self::Download(_) => EventType::Download,
             // Self::FileChooser(_) => EventType::FileChooser,
             Self::DomContentLoaded => EventType::DomContentLoaded,
-            Self::PageError => EventType::PageError,
+            Self::PageError(_) => EventType::PageError,
             Self::Request(_) => EventType::Request,
             Self::Response(_) => EventType::Response,
             Self::RequestFailed(_) => EventType::RequestFailed,
@@ -526,6 +787,24 @@ macro_rules! is_checked {
 
 /// Shorthand of main_frame
 impl Page {
+    /// The method returns an element locator that can be used to perform actions on the page. Locator is resolved to the
+    /// element immediately before performing an action, so a series of actions on the same locator can in fact be performed on
+    /// different DOM elements. That would happen if the DOM structure between those actions has changed.
+    ///
+    /// Shortcut for main frame's [`Frame::locator`].
+    pub fn locator(&self, selector: &str) -> Locator { self.main_frame().locator(selector) }
+
+    /// When working with iframes, you can create a frame locator that will enter the iframe and allow selecting elements in
+    /// that iframe.
+    ///
+    /// ```js
+    /// const locator = page.frameLocator('#my-iframe').locator('text=Submit');
+    /// await locator.click();
+    /// ```
+    pub fn frame_locator(&self, selector: &str) -> FrameLocator {
+        self.main_frame().frame_locator(selector)
+    }
+
     pub async fn query_selector(&self, selector: &str) -> ArcResult<Option<ElementHandle>> {
         self.main_frame().query_selector(selector).await
     }
@@ -674,7 +953,22 @@ impl Page {
         GotoBuilder::new(self.main_frame_weak(), url)
     }
 
-    // wait_for_load_state
+    /// Shortcut for main frame's [`Frame::wait_for_load_state`]
+    pub async fn wait_for_load_state(
+        &self,
+        state: Option<DocumentLoadState>,
+        timeout: Option<f64>
+    ) -> ArcResult<()> {
+        self.main_frame().wait_for_load_state(state, timeout).await
+    }
+
+    /// Shortcut for main frame's [`Frame::wait_for_url_builder`]
+    pub fn wait_for_url_builder<M>(&self, url: M) -> WaitForUrlBuilder
+    where
+        M: Into<UrlMatcher>
+    {
+        self.main_frame().wait_for_url_builder(url)
+    }
 
     pub async fn title(&self) -> ArcResult<String> { self.main_frame().title().await }
 
@@ -760,7 +1054,15 @@ impl Page {
     pub fn wait_for_function_builder<'a>(&self, expression: &'a str) -> WaitForFunctionBuilder<'a> {
         self.main_frame().wait_for_function_builder(expression)
     }
-    // expect_navigation
+
+    /// Shortcut for main frame's [`Frame::expect_navigation`]
+    pub async fn expect_navigation<F, Fut, T>(&self, action: F) -> ArcResult<Option<Response>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ArcResult<T>>
+    {
+        self.main_frame().expect_navigation(action).await
+    }
 }
 
 macro_rules! navigation {
@@ -798,21 +1100,33 @@ navigation!(ReloadBuilder, reload);
 navigation!(GoBackBuilder, go_back);
 navigation!(GoForwardBuilder, go_forward);
 
-pub struct PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+pub struct PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
     inner: Weak<Impl>,
-    args: PdfArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j>
+    args: PdfArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>
 }
 
-impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
     pub(crate) fn new(inner: Weak<Impl>) -> Self {
         let args = PdfArgs::default();
         Self { inner, args }
     }
 
-    pub async fn pdf(self) -> Result<(), Arc<Error>> {
+    /// Returns the PDF. Fails with [`Error::InvalidPageRanges`] before generating if `page_ranges` is malformed.
+    pub async fn pdf(self) -> ArcResult<Vec<u8>> {
         let Self { inner, args } = self;
-        let _ = upgrade(&inner)?.pdf(args).await?;
-        Ok(())
+        upgrade(&inner)?.pdf(args).await
+    }
+
+    /// Writes the PDF into `writer` as it is decoded, so that the decoded document is never held in memory.
+    /// This is not streaming: the driver replies with the whole PDF as base64, which is kept in memory until the call
+    /// returns, so very large documents still need about 4/3 of their size.
+    /// Tokio writers can be adapted with `tokio_util::compat`.
+    pub async fn pdf_to_writer<W>(self, writer: &mut W) -> ArcResult<()>
+    where
+        W: futures::io::AsyncWrite + Unpin
+    {
+        let Self { inner, args } = self;
+        upgrade(&inner)?.pdf_to_writer(args, writer).await
     }
 
     setter! {
@@ -836,17 +1150,17 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f,
         landscape: Option<bool>,
         /// Paper ranges to print, e.g., '1-5, 8, 11-13'. Defaults to the empty string, which means print all pages.
         page_ranges: Option<&'c str>,
-        /// Paper format. If set, takes priority over `width` or `height` options. Defaults to 'Letter'.
-        format: Option<&'d str>,
+        /// Paper format. If set, takes priority over `width` or `height` options. Defaults to [`PaperFormat::Letter`].
+        format: Option<PaperFormat>,
         /// Paper width, accepts values labeled with units.
-        width: Option<Length<'e>>,
+        width: Option<Length<'d>>,
         /// Paper height, accepts values labeled with units.
-        height: Option<Length<'f>>,
+        height: Option<Length<'e>>,
         /// Give any CSS `@page` size declared in the page priority over what is declared in `width` and `height` or `format`
         /// options. Defaults to `false`, which will scale the content to fit the paper size.
         prefer_css_page_size: Option<bool>,
         /// Paper margins, defaults to none.
-        margin: Option<PdfMargins<'g, 'h, 'i, 'j>>,
+        margin: Option<PdfMargins<'f, 'g, 'h, 'i>>,
         /// The file path to save the PDF to. If `path` is a relative path, then it is resolved relative to the current working
         /// directory. If no path is provided, the PDF won't be saved to the disk.
         path: Option<PathBuf>
@@ -880,9 +1194,6 @@ impl ScreenshotBuilder {
         /// When true, takes a screenshot of the full scrollable page, instead of the currently visible viewport. Defaults to
         /// `false`.
         full_page: Option<bool>,
-        /// Hides default white background and allows capturing screenshots with transparency. Not applicable to `jpeg` images.
-        /// Defaults to `false`.
-        omit_background: Option<bool>,
         quality: Option<i32>,
         /// Maximum time in milliseconds, defaults to 30 seconds, pass `0` to disable timeout. The default value can be changed by
         /// using the [`method: BrowserContext.setDefaultTimeout`] or [`method: Page.setDefaultTimeout`] methods.
@@ -893,6 +1204,8 @@ impl ScreenshotBuilder {
         path: Option<PathBuf>
     }
 
+    screenshot_options!();
+
     pub fn clear_type(mut self) -> Self {
         self.args.r#type = None;
         self
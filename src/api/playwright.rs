@@ -1,6 +1,9 @@
 pub use crate::imp::playwright::DeviceDescriptor;
 use crate::{
-    api::{browser_type::BrowserType, selectors::Selectors},
+    api::{
+        browser_installer::BrowserInstaller, browser_type::BrowserType, selectors::Selectors,
+        ApiRequest
+    },
     imp::{core::*, playwright::Playwright as Impl, prelude::*},
     Error
 };
@@ -60,6 +63,9 @@ impl Playwright {
 
     pub fn install_webkit(&self) -> io::Result<()> { run(&self.driver, &["install", "webkit"]) }
 
+    /// Lists, installs and uninstalls the browsers of this driver with structured results
+    pub fn installer(&self) -> BrowserInstaller { BrowserInstaller::new(self.driver.clone()) }
+
     /// Launcher
     pub fn chromium(&self) -> BrowserType {
         let inner = weak_and_then(&self.inner, |rc| rc.chromium());
@@ -80,6 +86,20 @@ impl Playwright {
 
     pub fn driver(&mut self) -> &mut Driver { &mut self.driver }
 
+    /// Closes all browsers, shuts the driver down and waits for it to exit. The driver and its descendants are killed if it
+    /// does not exit in time. Dropping [`Playwright`] without calling this kills them immediately.
+    pub async fn close(self) -> ArcResult<()> { self._conn.close().await }
+
+    /// Sets a client-side deadline on every request to the driver. A request without response within the deadline fails
+    /// with [`Error::CallTimeout`]. Browsers from [`BrowserType::connect_builder`] inherit the deadline at the time of
+    /// connecting. Defaults to `None`, which waits forever for the driver and relies on the timeouts of each method.
+    pub fn set_call_timeout(&self, timeout: Option<Duration>) {
+        self._conn.set_call_timeout(timeout);
+    }
+
+    /// Exposes API that can be used for the Web API testing.
+    pub fn request(&self) -> ApiRequest { ApiRequest::new(self.inner.clone()) }
+
     pub fn selectors(&self) -> Selectors {
         let inner = weak_and_then(&self.inner, |rc| rc.selectors());
         Selectors::new(inner)
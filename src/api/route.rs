@@ -1,9 +1,13 @@
 use crate::{
     api::{Header, Request},
     imp::{
+        browser_context::BrowserContext as ContextImpl,
         core::*,
+        har_router::RouteFromHarArgs,
+        page::Page as PageImpl,
         prelude::*,
-        route::{ContinueArgs, FulfillArgs, Route as Impl}
+        route::{ContinueArgs, FulfillArgs, Route as Impl, RouteHandler, RouteHandlerFn},
+        utils::{HarNotFound, UrlMatcher}
     }
 };
 
@@ -24,7 +28,7 @@ impl PartialEq for Route {
 }
 
 impl Route {
-    fn new(inner: Weak<Impl>) -> Self { Self { inner } }
+    pub(crate) fn new(inner: Weak<Impl>) -> Self { Self { inner } }
 
     /// A request to be routed.
     pub fn request(&self) -> Request {
@@ -93,6 +97,72 @@ impl Route {
     }
 }
 
+pub(crate) fn route_handler<F, Fut>(url: UrlMatcher, handler: F) -> RouteHandler
+where
+    F: Fn(Route) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ArcResult<()>> + Send + 'static
+{
+    let f: RouteHandlerFn = Arc::new(move |inner| {
+        let fut = handler(Route::new(inner));
+        Box::pin(async move {
+            if let Err(e) = fut.await {
+                log::error!("Route handler failed: {}", e);
+            }
+        })
+    });
+    RouteHandler::new(url, f)
+}
+
+/// [`BrowserContext::route_from_har_builder`](crate::api::BrowserContext::route_from_har_builder) and
+/// [`Page::route_from_har_builder`](crate::api::Page::route_from_har_builder)
+pub struct RouteFromHarBuilder<'a> {
+    target: HarTarget,
+    har: &'a Path,
+    args: RouteFromHarArgs
+}
+
+pub(crate) enum HarTarget {
+    Context(Weak<ContextImpl>),
+    Page(Weak<PageImpl>)
+}
+
+impl<'a> RouteFromHarBuilder<'a> {
+    pub(crate) fn new(target: HarTarget, har: &'a Path) -> Self {
+        let args = RouteFromHarArgs::default();
+        Self { target, har, args }
+    }
+
+    pub async fn route_from_har(self) -> ArcResult<()> {
+        let Self { target, har, args } = self;
+        match target {
+            HarTarget::Context(x) => upgrade(&x)?.route_from_har(har, args).await,
+            HarTarget::Page(x) => upgrade(&x)?.route_from_har(har, args).await
+        }
+    }
+
+    /// Only requests with URL matching this are served from the HAR. Defaults to all requests.
+    pub fn url<M>(mut self, x: M) -> Self
+    where
+        M: Into<UrlMatcher>
+    {
+        self.args.url = Some(x.into());
+        self
+    }
+
+    pub fn clear_url(mut self) -> Self {
+        self.args.url = None;
+        self
+    }
+
+    setter! {
+        /// What to do with requests which are not in the HAR, defaults to [`HarNotFound::Abort`]
+        not_found: Option<HarNotFound>,
+        /// Instead of serving from the HAR, record the actual network traffic into it. The file is written when the
+        /// browser context closes.
+        update: Option<bool>
+    }
+}
+
 pub struct FulfillBuilder<'a, 'b> {
     inner: Weak<Impl>,
     args: FulfillArgs<'a, 'b>
@@ -0,0 +1,102 @@
+use crate::imp::{
+    core::*,
+    prelude::*,
+    tracing::{StartArgs, Tracing as Impl}
+};
+
+/// API for collecting and saving Playwright traces. Playwright traces can be opened in
+/// [Trace Viewer](https://playwright.dev/docs/trace-viewer) after Playwright script runs.
+///
+/// Start recording a trace before performing actions. At the end, stop tracing and save it to a file.
+///
+/// ```js
+/// const browser = await chromium.launch();
+/// const context = await browser.newContext();
+/// await context.tracing.start({ screenshots: true, snapshots: true });
+/// const page = await context.newPage();
+/// await page.goto('https://playwright.dev');
+/// await context.tracing.stop({ path: 'trace.zip' });
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tracing {
+    inner: Weak<Impl>
+}
+
+impl Tracing {
+    pub(crate) fn new(inner: Weak<Impl>) -> Self { Self { inner } }
+
+    /// Start tracing.
+    pub fn start_builder(&self) -> StartBuilder<'_, '_> { StartBuilder::new(self.inner.clone()) }
+
+    /// Start a new trace chunk. If you'd like to record multiple traces on the same [`BrowserContext`](crate::api::BrowserContext),
+    /// use [`Tracing::start_builder`] once, and then create multiple trace chunks with [`Tracing::start_chunk`] and
+    /// [`Tracing::stop_chunk`].
+    ///
+    /// ```js
+    /// await context.tracing.start({ screenshots: true, snapshots: true });
+    /// const page = await context.newPage();
+    /// await page.goto('https://playwright.dev');
+    ///
+    /// await context.tracing.startChunk();
+    /// await page.locator('text=Get Started').click();
+    /// // Everything between startChunk and stopChunk will be recorded in the trace.
+    /// await context.tracing.stopChunk({ path: 'trace1.zip' });
+    /// ```
+    ///
+    /// [`Tracing::start_builder`] already opens a chunk. Starting another one discards what the open chunk has recorded,
+    /// unless it is saved with [`Tracing::stop_chunk`] first.
+    pub async fn start_chunk(&self, title: Option<&str>) -> ArcResult<()> {
+        upgrade(&self.inner)?.start_chunk(title).await
+    }
+
+    /// Stop the trace chunk. See [`Tracing::start_chunk`] for more details about multiple trace chunks.
+    /// Exports the trace into the file with the given path if given.
+    /// After this, [`Tracing::stop`] can save a trace only if another chunk is started.
+    pub async fn stop_chunk(&self, path: Option<&Path>) -> ArcResult<()> {
+        upgrade(&self.inner)?.stop_chunk(path).await
+    }
+
+    /// Stop tracing. Exports the trace into the file with the given path if given.
+    ///
+    /// The trace is the current chunk, so saving it fails with "Must start tracing before stopping" after
+    /// [`Tracing::stop_chunk`] unless another chunk is started with [`Tracing::start_chunk`].
+    pub async fn stop(&self, path: Option<&Path>) -> ArcResult<()> {
+        upgrade(&self.inner)?.stop(path).await
+    }
+}
+
+/// [`Tracing::start_builder`]
+pub struct StartBuilder<'a, 'b> {
+    inner: Weak<Impl>,
+    args: StartArgs<'a, 'b>
+}
+
+impl<'a, 'b> StartBuilder<'a, 'b> {
+    fn new(inner: Weak<Impl>) -> Self {
+        Self {
+            inner,
+            args: StartArgs::default()
+        }
+    }
+
+    pub async fn start(self) -> ArcResult<()> {
+        let Self { inner, args } = self;
+        upgrade(&inner)?.start(args).await
+    }
+
+    setter! {
+        /// If specified, the trace is going to be saved into the file with the given name inside the `tracesDir` folder
+        /// specified in [`method: BrowserType.launch`].
+        name: Option<&'a str>,
+        /// Whether to capture screenshots during tracing. Screenshots are used to build a timeline preview.
+        screenshots: Option<bool>,
+        /// If this option is true tracing will
+        /// - capture DOM snapshot on every action
+        /// - record network activity
+        snapshots: Option<bool>,
+        /// Whether to include source files for trace actions.
+        sources: Option<bool>,
+        /// Trace name to be shown in the Trace Viewer.
+        title: Option<&'b str>
+    }
+}
@@ -0,0 +1,93 @@
+//! Blocking API which mirrors [`crate::api`] for programs without an async runtime.
+//!
+//! Every handle owns an internal runtime, which drives the async API until the call completes. Do not call these methods
+//! from async code; the internal runtime panics if it is blocked on inside another runtime.
+//!
+//! ```no_run
+//! use playwright::blocking::Playwright;
+//!
+//! let playwright = Playwright::initialize().unwrap();
+//! playwright.install_chromium().unwrap();
+//! let browser = playwright.chromium().launcher().headless(true).launch().unwrap();
+//! let page = browser.context_builder().build().unwrap().new_page().unwrap();
+//! page.goto_builder("https://example.com/").goto().unwrap();
+//! let title = page.title().unwrap();
+//! ```
+//!
+//! Methods which take async callbacks, such as routes and exposed functions, have no counterpart here.
+
+macro_rules! forward_setter {
+    (
+        $(
+            $field:ident : $t:ty
+        ),+
+    ) => {
+        $(
+            paste::paste! {
+                #[allow(clippy::wrong_self_convention)]
+                pub fn [<$field>](mut self, x: $t) -> Self {
+                    self.inner = self.inner.$field(x);
+                    self
+                }
+            }
+        )*
+        $(
+            paste::paste! {
+                pub fn [<clear_$field>](mut self) -> Self {
+                    self.inner = self.inner.[<clear_$field>]();
+                    self
+                }
+            }
+        )*
+    };
+}
+
+mod browser;
+mod browser_context;
+mod browser_type;
+mod locator;
+mod page;
+mod playwright;
+mod response;
+
+pub use self::playwright::Playwright;
+pub use browser::{Browser, ContextBuilder};
+pub use browser_context::{BrowserContext, RouteFromHarBuilder};
+pub use browser_type::{BrowserType, Launcher};
+pub use locator::Locator;
+pub use page::{
+    ClickBuilder, FillBuilder, GotoBuilder, Page, ScreenshotBuilder, SetContentBuilder,
+    WaitForSelectorBuilder, WaitForUrlBuilder
+};
+pub use response::Response;
+
+use crate::imp::prelude::*;
+
+/// Runs futures of the async API to completion on the calling thread.
+/// Tasks spawned by the async API, such as event dispatchers, run on a background worker.
+#[derive(Debug)]
+pub(crate) struct Runtime {
+    #[cfg(any(feature = "rt-tokio", feature = "rt-actix"))]
+    rt: tokio::runtime::Runtime
+}
+
+impl Runtime {
+    #[cfg(any(feature = "rt-tokio", feature = "rt-actix"))]
+    fn new() -> std::io::Result<Arc<Self>> {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("playwright-blocking")
+            .enable_all()
+            .build()?;
+        Ok(Arc::new(Self { rt }))
+    }
+
+    #[cfg(feature = "rt-async-std")]
+    fn new() -> std::io::Result<Arc<Self>> { Ok(Arc::new(Self {})) }
+
+    #[cfg(any(feature = "rt-tokio", feature = "rt-actix"))]
+    pub(crate) fn block_on<F: Future>(&self, f: F) -> F::Output { self.rt.block_on(f) }
+
+    #[cfg(feature = "rt-async-std")]
+    pub(crate) fn block_on<F: Future>(&self, f: F) -> F::Output { async_std::task::block_on(f) }
+}
@@ -0,0 +1,102 @@
+use super::{BrowserContext, Runtime};
+use crate::{
+    api::{
+        self,
+        browser::{RecordHar, RecordVideo},
+        playwright::DeviceDescriptor,
+        ColorScheme, Geolocation, HttpCredentials, ProxySettings, StorageState, Viewport
+    },
+    imp::{core::*, prelude::*},
+    Error
+};
+
+/// Blocking [`api::Browser`]
+#[derive(Debug)]
+pub struct Browser {
+    inner: api::Browser,
+    rt: Arc<Runtime>
+}
+
+impl PartialEq for Browser {
+    fn eq(&self, other: &Self) -> bool { self.inner == other.inner }
+}
+
+impl Browser {
+    pub(crate) fn new(inner: api::Browser, rt: &Arc<Runtime>) -> Self {
+        Self {
+            inner,
+            rt: rt.clone()
+        }
+    }
+
+    /// Returns an array of all open browser contexts.
+    pub fn contexts(&self) -> Result<Vec<BrowserContext>, Error> {
+        Ok(self
+            .inner
+            .contexts()?
+            .into_iter()
+            .map(|c| BrowserContext::new(c, &self.rt))
+            .collect())
+    }
+
+    /// Returns the browser version.
+    pub fn version(&self) -> Result<String, Error> { self.inner.version() }
+
+    pub fn exists(&self) -> bool { self.inner.exists() }
+
+    /// See [`api::Browser::context_builder`]
+    pub fn context_builder(&self) -> ContextBuilder<'_, '_, '_, '_, '_, '_, '_> {
+        ContextBuilder {
+            inner: self.inner.context_builder(),
+            rt: self.rt.clone()
+        }
+    }
+
+    /// See [`api::Browser::close`]
+    pub fn close(&self) -> ArcResult<()> { self.rt.block_on(self.inner.close()) }
+}
+
+/// [`Browser::context_builder`]
+pub struct ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+    inner: api::browser::ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k>,
+    rt: Arc<Runtime>
+}
+
+impl<'e, 'f, 'g, 'h, 'i, 'j, 'k> ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
+    pub fn build(self) -> ArcResult<BrowserContext> {
+        let Self { inner, rt } = self;
+        let context = rt.block_on(inner.build())?;
+        Ok(BrowserContext::new(context, &rt))
+    }
+
+    pub fn set_device(mut self, device: &'e DeviceDescriptor) -> Self {
+        self.inner = self.inner.set_device(device);
+        self
+    }
+
+    forward_setter! {
+        accept_downloads: bool,
+        bypass_csp: bool,
+        color_scheme: ColorScheme,
+        device_scale_factor: f64,
+        extra_http_headers: HashMap<String, String>,
+        geolocation: Geolocation,
+        has_touch: bool,
+        http_credentials: &'i HttpCredentials,
+        ignore_https_errors: bool,
+        is_mobile: bool,
+        js_enabled: bool,
+        locale: &'f str,
+        no_viewport: bool,
+        offline: bool,
+        permissions: &'h [String],
+        proxy: ProxySettings,
+        record_har: RecordHar<'k>,
+        record_video: RecordVideo<'j>,
+        screen: Viewport,
+        storage_state: StorageState,
+        timezone_id: &'g str,
+        user_agent: &'e str,
+        viewport: Option<Viewport>
+    }
+}
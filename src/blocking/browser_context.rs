@@ -0,0 +1,126 @@
+use super::{Browser, Page, Runtime};
+use crate::{
+    api::{self, Cookie, HarNotFound, StorageState, UrlMatcher},
+    imp::{core::*, prelude::*},
+    Error
+};
+
+/// Blocking [`api::BrowserContext`]
+#[derive(Debug)]
+pub struct BrowserContext {
+    inner: api::BrowserContext,
+    rt: Arc<Runtime>
+}
+
+impl PartialEq for BrowserContext {
+    fn eq(&self, other: &Self) -> bool { self.inner == other.inner }
+}
+
+impl BrowserContext {
+    pub(crate) fn new(inner: api::BrowserContext, rt: &Arc<Runtime>) -> Self {
+        Self {
+            inner,
+            rt: rt.clone()
+        }
+    }
+
+    /// Returns all open pages in the context.
+    pub fn pages(&self) -> Result<Vec<Page>, Error> {
+        Ok(self
+            .inner
+            .pages()?
+            .into_iter()
+            .map(|p| Page::new(p, &self.rt))
+            .collect())
+    }
+
+    /// Returns the browser instance of the context. If it was launched as a persistent context None gets returned.
+    pub fn browser(&self) -> Result<Option<Browser>, Error> {
+        Ok(self.inner.browser()?.map(|b| Browser::new(b, &self.rt)))
+    }
+
+    /// Creates a new page in the browser context.
+    pub fn new_page(&self) -> ArcResult<Page> {
+        let page = self.rt.block_on(self.inner.new_page())?;
+        Ok(Page::new(page, &self.rt))
+    }
+
+    pub fn set_default_navigation_timeout(&self, timeout: u32) -> ArcResult<()> {
+        self.rt
+            .block_on(self.inner.set_default_navigation_timeout(timeout))
+    }
+
+    pub fn set_default_timeout(&self, timeout: u32) -> ArcResult<()> {
+        self.rt.block_on(self.inner.set_default_timeout(timeout))
+    }
+
+    /// If no URLs are specified, this method returns all cookies. If URLs are specified, only cookies that affect those URLs
+    /// are returned.
+    pub fn cookies(&self, urls: &[String]) -> ArcResult<Vec<Cookie>> {
+        self.rt.block_on(self.inner.cookies(urls))
+    }
+
+    /// Adds cookies into this browser context.
+    pub fn add_cookies(&self, cookies: &[Cookie]) -> ArcResult<()> {
+        self.rt.block_on(self.inner.add_cookies(cookies))
+    }
+
+    /// Clears context cookies.
+    pub fn clear_cookies(&self) -> ArcResult<()> { self.rt.block_on(self.inner.clear_cookies()) }
+
+    pub fn set_offline(&self, offline: bool) -> ArcResult<()> {
+        self.rt.block_on(self.inner.set_offline(offline))
+    }
+
+    /// Adds a script which would be evaluated in every page created in the context and every navigated frame.
+    pub fn add_init_script(&self, script: &str) -> ArcResult<()> {
+        self.rt.block_on(self.inner.add_init_script(script))
+    }
+
+    /// Returns storage state for this browser context, contains current cookies and local storage snapshot.
+    pub fn storage_state(&self) -> ArcResult<StorageState> {
+        self.rt.block_on(self.inner.storage_state())
+    }
+
+    /// See [`api::BrowserContext::route_from_har_builder`]
+    pub fn route_from_har_builder<'a>(&self, har: &'a Path) -> RouteFromHarBuilder<'a> {
+        RouteFromHarBuilder {
+            inner: self.inner.route_from_har_builder(har),
+            rt: self.rt.clone()
+        }
+    }
+
+    /// Closes the browser context. All the pages that belong to the browser context will be closed.
+    pub fn close(&self) -> ArcResult<()> { self.rt.block_on(self.inner.close()) }
+}
+
+/// [`BrowserContext::route_from_har_builder`]
+pub struct RouteFromHarBuilder<'a> {
+    inner: api::route::RouteFromHarBuilder<'a>,
+    rt: Arc<Runtime>
+}
+
+impl<'a> RouteFromHarBuilder<'a> {
+    pub fn route_from_har(self) -> ArcResult<()> {
+        let Self { inner, rt } = self;
+        rt.block_on(inner.route_from_har())
+    }
+
+    pub fn url<M>(mut self, x: M) -> Self
+    where
+        M: Into<UrlMatcher>
+    {
+        self.inner = self.inner.url(x);
+        self
+    }
+
+    pub fn clear_url(mut self) -> Self {
+        self.inner = self.inner.clear_url();
+        self
+    }
+
+    forward_setter! {
+        not_found: HarNotFound,
+        update: bool
+    }
+}
@@ -0,0 +1,69 @@
+use super::{Browser, Runtime};
+use crate::{
+    api::{self, BrowserChannel, ProxySettings},
+    imp::{core::*, prelude::*},
+    Error
+};
+
+/// Blocking [`api::BrowserType`]
+#[derive(Debug, Clone)]
+pub struct BrowserType {
+    inner: api::BrowserType,
+    rt: Arc<Runtime>
+}
+
+impl BrowserType {
+    pub(crate) fn new(inner: api::BrowserType, rt: &Arc<Runtime>) -> Self {
+        Self {
+            inner,
+            rt: rt.clone()
+        }
+    }
+
+    /// Returns browser name. For example: `'chromium'`, `'webkit'` or `'firefox'`.
+    pub fn name(&self) -> Result<String, Error> { self.inner.name() }
+
+    /// A path where Playwright expects to find a bundled browser executable.
+    pub fn executable(&self) -> Result<PathBuf, Error> { self.inner.executable() }
+
+    /// See [`api::BrowserType::launcher`]
+    pub fn launcher(&self) -> Launcher<'_, '_, '_> {
+        Launcher {
+            inner: self.inner.launcher(),
+            rt: self.rt.clone()
+        }
+    }
+}
+
+/// [`BrowserType::launcher`]
+pub struct Launcher<'a, 'b, 'c> {
+    inner: api::browser_type::Launcher<'a, 'b, 'c>,
+    rt: Arc<Runtime>
+}
+
+impl<'a, 'b, 'c> Launcher<'a, 'b, 'c> {
+    pub fn launch(self) -> ArcResult<Browser> {
+        let Self { inner, rt } = self;
+        let browser = rt.block_on(inner.launch())?;
+        Ok(Browser::new(browser, &rt))
+    }
+
+    forward_setter! {
+        executable: &'a Path,
+        args: &'b [String],
+        ignore_all_default_args: bool,
+        handle_sigint: bool,
+        handle_sigterm: bool,
+        handle_sighup: bool,
+        timeout: f64,
+        devtools: bool,
+        proxy: ProxySettings,
+        downloads: &'c Path,
+        slowmo: f64,
+        env: Map<String, Value>,
+        headless: bool,
+        chromium_sandbox: bool,
+        firefox_user_prefs: Map<String, Value>,
+        channel: BrowserChannel
+    }
+}
@@ -0,0 +1,126 @@
+use super::{ClickBuilder, FillBuilder, Runtime, WaitForSelectorBuilder};
+use crate::{
+    api,
+    imp::{core::*, prelude::*},
+    Error
+};
+
+macro_rules! is_checked {
+    ($f: ident) => {
+        pub fn $f(&self, timeout: Option<f64>) -> ArcResult<bool> {
+            self.rt.block_on(self.inner.$f(timeout))
+        }
+    };
+}
+
+/// Blocking [`api::Locator`]
+#[derive(Debug, Clone)]
+pub struct Locator {
+    inner: api::Locator,
+    rt: Arc<Runtime>
+}
+
+impl PartialEq for Locator {
+    fn eq(&self, other: &Self) -> bool { self.inner == other.inner }
+}
+
+impl Locator {
+    pub(crate) fn new(inner: api::Locator, rt: &Arc<Runtime>) -> Self {
+        Self {
+            inner,
+            rt: rt.clone()
+        }
+    }
+
+    /// The selector this locator resolves on every call.
+    pub fn selector(&self) -> &str { self.inner.selector() }
+
+    /// The method finds an element matching the specified selector in the `Locator`'s subtree.
+    pub fn locator(&self, selector: &str) -> Locator {
+        Self::new(self.inner.locator(selector), &self.rt)
+    }
+
+    /// Returns locator to the first matching element.
+    pub fn first(&self) -> Locator { Self::new(self.inner.first(), &self.rt) }
+
+    /// Returns locator to the last matching element.
+    pub fn last(&self) -> Locator { Self::new(self.inner.last(), &self.rt) }
+
+    /// Returns locator to the n-th matching element.
+    pub fn nth(&self, index: i32) -> Locator { Self::new(self.inner.nth(index), &self.rt) }
+
+    /// See [`api::Locator::filter`]
+    pub fn filter(&self, has_text: Option<&str>, has: Option<&Locator>) -> Result<Locator, Error> {
+        let inner = self.inner.filter(has_text, has.map(|l| &l.inner))?;
+        Ok(Self::new(inner, &self.rt))
+    }
+
+    /// Returns the number of elements matching given selector.
+    pub fn count(&self) -> ArcResult<usize> { self.rt.block_on(self.inner.count()) }
+
+    /// Returns an array of `node.innerText` values for all matching nodes.
+    pub fn all_inner_texts(&self) -> ArcResult<Vec<String>> {
+        self.rt.block_on(self.inner.all_inner_texts())
+    }
+
+    /// Returns an array of `node.textContent` values for all matching nodes.
+    pub fn all_text_contents(&self) -> ArcResult<Vec<String>> {
+        self.rt.block_on(self.inner.all_text_contents())
+    }
+
+    /// Returns the return value of `expression`. The matching element is passed as a first argument to `expression`.
+    pub fn evaluate<T, U>(&self, expression: &str, arg: Option<T>) -> ArcResult<U>
+    where
+        T: Serialize,
+        U: DeserializeOwned
+    {
+        self.rt.block_on(self.inner.evaluate(expression, arg))
+    }
+
+    /// See [`api::Locator::click_builder`]
+    pub fn click_builder(&self) -> ClickBuilder<'_> {
+        ClickBuilder::new(self.inner.click_builder(), &self.rt)
+    }
+
+    /// See [`api::Locator::fill_builder`]
+    pub fn fill_builder<'b>(&self, value: &'b str) -> FillBuilder<'_, 'b> {
+        FillBuilder::new(self.inner.fill_builder(value), &self.rt)
+    }
+
+    /// See [`api::Locator::wait_for_builder`]
+    pub fn wait_for_builder(&self) -> WaitForSelectorBuilder<'_> {
+        WaitForSelectorBuilder::new(self.inner.wait_for_builder(), &self.rt)
+    }
+
+    /// Calls [focus](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/focus) on the element.
+    pub fn focus(&self, timeout: Option<f64>) -> ArcResult<()> {
+        self.rt.block_on(self.inner.focus(timeout))
+    }
+
+    /// Returns the `node.textContent`.
+    pub fn text_content(&self, timeout: Option<f64>) -> ArcResult<Option<String>> {
+        self.rt.block_on(self.inner.text_content(timeout))
+    }
+
+    /// Returns the `element.innerText`.
+    pub fn inner_text(&self, timeout: Option<f64>) -> ArcResult<String> {
+        self.rt.block_on(self.inner.inner_text(timeout))
+    }
+
+    /// Returns the `element.innerHTML`.
+    pub fn inner_html(&self, timeout: Option<f64>) -> ArcResult<String> {
+        self.rt.block_on(self.inner.inner_html(timeout))
+    }
+
+    /// Returns element attribute value.
+    pub fn get_attribute(&self, name: &str, timeout: Option<f64>) -> ArcResult<Option<String>> {
+        self.rt.block_on(self.inner.get_attribute(name, timeout))
+    }
+
+    is_checked! {is_checked}
+    is_checked! {is_disabled}
+    is_checked! {is_editable}
+    is_checked! {is_enabled}
+    is_checked! {is_hidden}
+    is_checked! {is_visible}
+}
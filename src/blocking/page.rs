@@ -0,0 +1,380 @@
+use super::{BrowserContext, Locator, Response, Runtime};
+use crate::{
+    api::{
+        self, frame::FrameState, DocumentLoadState, ElementHandle, FloatRect, KeyboardModifier,
+        Mask, MouseButton, Position, ScreenshotAnimations, ScreenshotCaret, ScreenshotScale,
+        ScreenshotType, UrlMatcher, Viewport
+    },
+    imp::{core::*, prelude::*},
+    Error
+};
+
+macro_rules! is_checked {
+    ($f: ident) => {
+        pub fn $f(&self, selector: &str, timeout: Option<f64>) -> ArcResult<bool> {
+            self.rt.block_on(self.inner.$f(selector, timeout))
+        }
+    };
+}
+
+/// Blocking [`api::Page`]
+#[derive(Debug, Clone)]
+pub struct Page {
+    inner: api::Page,
+    rt: Arc<Runtime>
+}
+
+impl PartialEq for Page {
+    fn eq(&self, other: &Self) -> bool { self.inner == other.inner }
+}
+
+impl Page {
+    pub(crate) fn new(inner: api::Page, rt: &Arc<Runtime>) -> Self {
+        Self {
+            inner,
+            rt: rt.clone()
+        }
+    }
+
+    /// Get the browser context that the page belongs to.
+    pub fn context(&self) -> BrowserContext { BrowserContext::new(self.inner.context(), &self.rt) }
+
+    pub fn url(&self) -> Result<String, Error> { self.inner.url() }
+
+    /// Returns the page's title.
+    pub fn title(&self) -> ArcResult<String> { self.rt.block_on(self.inner.title()) }
+
+    /// Gets the full HTML contents of the page, including the doctype.
+    pub fn content(&self) -> ArcResult<String> { self.rt.block_on(self.inner.content()) }
+
+    /// See [`api::Page::locator`]
+    pub fn locator(&self, selector: &str) -> Locator {
+        Locator::new(self.inner.locator(selector), &self.rt)
+    }
+
+    /// See [`api::Page::goto_builder`]
+    pub fn goto_builder<'a>(&self, url: &'a str) -> GotoBuilder<'a, '_> {
+        GotoBuilder {
+            inner: self.inner.goto_builder(url),
+            rt: self.rt.clone()
+        }
+    }
+
+    /// See [`api::Page::set_content_builder`]
+    pub fn set_content_builder<'a>(&self, html: &'a str) -> SetContentBuilder<'a> {
+        SetContentBuilder {
+            inner: self.inner.set_content_builder(html),
+            rt: self.rt.clone()
+        }
+    }
+
+    /// See [`api::Page::click_builder`]
+    pub fn click_builder<'a>(&self, selector: &'a str) -> ClickBuilder<'a> {
+        ClickBuilder::new(self.inner.click_builder(selector), &self.rt)
+    }
+
+    /// See [`api::Page::fill_builder`]
+    pub fn fill_builder<'a, 'b>(&self, selector: &'a str, value: &'b str) -> FillBuilder<'a, 'b> {
+        FillBuilder::new(self.inner.fill_builder(selector, value), &self.rt)
+    }
+
+    /// See [`api::Page::wait_for_selector_builder`]
+    pub fn wait_for_selector_builder<'a>(&self, selector: &'a str) -> WaitForSelectorBuilder<'a> {
+        WaitForSelectorBuilder::new(self.inner.wait_for_selector_builder(selector), &self.rt)
+    }
+
+    /// See [`api::Page::wait_for_load_state`]
+    pub fn wait_for_load_state(
+        &self,
+        state: Option<DocumentLoadState>,
+        timeout: Option<f64>
+    ) -> ArcResult<()> {
+        self.rt
+            .block_on(self.inner.wait_for_load_state(state, timeout))
+    }
+
+    /// See [`api::Page::wait_for_url_builder`]
+    pub fn wait_for_url_builder<M>(&self, url: M) -> WaitForUrlBuilder
+    where
+        M: Into<UrlMatcher>
+    {
+        WaitForUrlBuilder {
+            inner: self.inner.wait_for_url_builder(url),
+            rt: self.rt.clone()
+        }
+    }
+
+    /// See [`api::Page::screenshot_builder`]
+    pub fn screenshot_builder(&self) -> ScreenshotBuilder {
+        ScreenshotBuilder {
+            inner: self.inner.screenshot_builder(),
+            rt: self.rt.clone()
+        }
+    }
+
+    pub fn eval<U>(&self, expression: &str) -> ArcResult<U>
+    where
+        U: DeserializeOwned
+    {
+        self.rt.block_on(self.inner.eval(expression))
+    }
+
+    pub fn evaluate<T, U>(&self, expression: &str, arg: T) -> ArcResult<U>
+    where
+        T: Serialize,
+        U: DeserializeOwned
+    {
+        self.rt.block_on(self.inner.evaluate(expression, arg))
+    }
+
+    pub fn focus(&self, selector: &str, timeout: Option<f64>) -> ArcResult<()> {
+        self.rt.block_on(self.inner.focus(selector, timeout))
+    }
+
+    pub fn text_content(&self, selector: &str, timeout: Option<f64>) -> ArcResult<Option<String>> {
+        self.rt.block_on(self.inner.text_content(selector, timeout))
+    }
+
+    pub fn inner_text(&self, selector: &str, timeout: Option<f64>) -> ArcResult<String> {
+        self.rt.block_on(self.inner.inner_text(selector, timeout))
+    }
+
+    pub fn inner_html(&self, selector: &str, timeout: Option<f64>) -> ArcResult<String> {
+        self.rt.block_on(self.inner.inner_html(selector, timeout))
+    }
+
+    pub fn get_attribute(
+        &self,
+        selector: &str,
+        name: &str,
+        timeout: Option<f64>
+    ) -> ArcResult<Option<String>> {
+        self.rt
+            .block_on(self.inner.get_attribute(selector, name, timeout))
+    }
+
+    is_checked! {is_checked}
+    is_checked! {is_disabled}
+    is_checked! {is_editable}
+    is_checked! {is_enabled}
+    is_checked! {is_hidden}
+    is_checked! {is_visible}
+
+    pub fn set_default_navigation_timeout(&self, timeout: u32) -> ArcResult<()> {
+        self.rt
+            .block_on(self.inner.set_default_navigation_timeout(timeout))
+    }
+
+    pub fn set_default_timeout(&self, timeout: u32) -> ArcResult<()> {
+        self.rt.block_on(self.inner.set_default_timeout(timeout))
+    }
+
+    pub fn viewport_size(&self) -> Result<Option<Viewport>, Error> { self.inner.viewport_size() }
+
+    pub fn set_viewport_size(&self, viewport_size: Viewport) -> ArcResult<()> {
+        self.rt
+            .block_on(self.inner.set_viewport_size(viewport_size))
+    }
+
+    /// Waits for the given `timeout` in milliseconds.
+    pub fn wait_for_timeout(&self, timeout: f64) {
+        self.rt.block_on(self.inner.wait_for_timeout(timeout))
+    }
+
+    /// See [`api::Page::close`]
+    pub fn close(&self, run_before_unload: Option<bool>) -> ArcResult<()> {
+        self.rt.block_on(self.inner.close(run_before_unload))
+    }
+}
+
+/// [`Page::goto_builder`]
+pub struct GotoBuilder<'a, 'b> {
+    inner: api::page::GotoBuilder<'a, 'b>,
+    rt: Arc<Runtime>
+}
+
+impl<'a, 'b> GotoBuilder<'a, 'b> {
+    pub fn goto(self) -> ArcResult<Option<Response>> {
+        let Self { inner, rt } = self;
+        let response = rt.block_on(inner.goto())?;
+        Ok(response.map(|r| Response::new(r, &rt)))
+    }
+
+    forward_setter! {
+        referer: &'b str,
+        timeout: f64,
+        wait_until: DocumentLoadState
+    }
+}
+
+/// [`Page::set_content_builder`]
+pub struct SetContentBuilder<'a> {
+    inner: api::page::SetContentBuilder<'a>,
+    rt: Arc<Runtime>
+}
+
+impl<'a> SetContentBuilder<'a> {
+    pub fn set_content(self) -> ArcResult<()> {
+        let Self { inner, rt } = self;
+        rt.block_on(inner.set_content())
+    }
+
+    forward_setter! {
+        timeout: f64,
+        wait_until: DocumentLoadState
+    }
+}
+
+/// [`Page::click_builder`] and [`Locator::click_builder`]
+pub struct ClickBuilder<'a> {
+    inner: api::page::ClickBuilder<'a>,
+    rt: Arc<Runtime>
+}
+
+impl<'a> ClickBuilder<'a> {
+    pub(crate) fn new(inner: api::page::ClickBuilder<'a>, rt: &Arc<Runtime>) -> Self {
+        Self {
+            inner,
+            rt: rt.clone()
+        }
+    }
+
+    pub fn click(self) -> ArcResult<()> {
+        let Self { inner, rt } = self;
+        rt.block_on(inner.click())
+    }
+
+    forward_setter! {
+        button: MouseButton,
+        click_count: i32,
+        delay: f64,
+        force: bool,
+        modifiers: Vec<KeyboardModifier>,
+        no_wait_after: bool,
+        position: Position,
+        strict: bool,
+        timeout: f64,
+        trial: bool
+    }
+}
+
+/// [`Page::fill_builder`] and [`Locator::fill_builder`]
+pub struct FillBuilder<'a, 'b> {
+    inner: api::page::FillBuilder<'a, 'b>,
+    rt: Arc<Runtime>
+}
+
+impl<'a, 'b> FillBuilder<'a, 'b> {
+    pub(crate) fn new(inner: api::page::FillBuilder<'a, 'b>, rt: &Arc<Runtime>) -> Self {
+        Self {
+            inner,
+            rt: rt.clone()
+        }
+    }
+
+    pub fn fill(self) -> ArcResult<()> {
+        let Self { inner, rt } = self;
+        rt.block_on(inner.fill())
+    }
+
+    forward_setter! {
+        no_wait_after: bool,
+        strict: bool,
+        timeout: f64
+    }
+}
+
+/// [`Page::wait_for_selector_builder`] and [`Locator::wait_for_builder`]
+pub struct WaitForSelectorBuilder<'a> {
+    inner: api::page::WaitForSelectorBuilder<'a>,
+    rt: Arc<Runtime>
+}
+
+impl<'a> WaitForSelectorBuilder<'a> {
+    pub(crate) fn new(inner: api::page::WaitForSelectorBuilder<'a>, rt: &Arc<Runtime>) -> Self {
+        Self {
+            inner,
+            rt: rt.clone()
+        }
+    }
+
+    /// Element handles are returned as is. Their methods need an async runtime.
+    pub fn wait_for_selector(self) -> ArcResult<Option<ElementHandle>> {
+        let Self { inner, rt } = self;
+        rt.block_on(inner.wait_for_selector())
+    }
+
+    forward_setter! {
+        state: FrameState,
+        strict: bool,
+        timeout: f64
+    }
+}
+
+/// [`Page::wait_for_url_builder`]
+pub struct WaitForUrlBuilder {
+    inner: api::page::WaitForUrlBuilder,
+    rt: Arc<Runtime>
+}
+
+impl WaitForUrlBuilder {
+    pub fn wait_for_url(self) -> ArcResult<()> {
+        let Self { inner, rt } = self;
+        rt.block_on(inner.wait_for_url())
+    }
+
+    forward_setter! {
+        timeout: f64,
+        wait_until: DocumentLoadState
+    }
+}
+
+/// [`Page::screenshot_builder`]
+pub struct ScreenshotBuilder {
+    inner: api::page::ScreenshotBuilder,
+    rt: Arc<Runtime>
+}
+
+impl ScreenshotBuilder {
+    pub fn screenshot(self) -> ArcResult<Vec<u8>> {
+        let Self { inner, rt } = self;
+        rt.block_on(inner.screenshot())
+    }
+
+    pub fn r#type(mut self, x: ScreenshotType) -> Self {
+        self.inner = self.inner.r#type(x);
+        self
+    }
+
+    pub fn clear_type(mut self) -> Self {
+        self.inner = self.inner.clear_type();
+        self
+    }
+
+    forward_setter! {
+        clip: FloatRect,
+        full_page: bool,
+        omit_background: bool,
+        quality: i32,
+        timeout: f64,
+        path: PathBuf,
+        animations: ScreenshotAnimations,
+        caret: ScreenshotCaret,
+        scale: ScreenshotScale,
+        mask_color: &str
+    }
+
+    pub fn mask<I, M>(mut self, x: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+        M: Into<Mask>
+    {
+        self.inner = self.inner.mask(x);
+        self
+    }
+
+    pub fn clear_mask(mut self) -> Self {
+        self.inner = self.inner.clear_mask();
+        self
+    }
+}
@@ -0,0 +1,68 @@
+use super::{BrowserType, Runtime};
+use crate::{
+    api::{self, playwright::DeviceDescriptor, BrowserInstaller},
+    imp::{core::*, prelude::*},
+    Error
+};
+use std::io;
+
+/// Blocking [`api::Playwright`]
+pub struct Playwright {
+    inner: api::Playwright,
+    rt: Arc<Runtime>
+}
+
+impl Playwright {
+    /// Installs playwright driver to "$CACHE_DIR/.ms-playwright/playwright-rust/driver"
+    pub fn initialize() -> Result<Playwright, Error> {
+        let rt = Runtime::new()?;
+        let inner = rt.block_on(api::Playwright::initialize())?;
+        Ok(Self { inner, rt })
+    }
+
+    /// Constructs from installed playwright driver
+    pub fn with_driver(driver: Driver) -> Result<Playwright, Error> {
+        let rt = Runtime::new()?;
+        let inner = rt.block_on(api::Playwright::with_driver(driver))?;
+        Ok(Self { inner, rt })
+    }
+
+    /// Runs $ playwright install
+    pub fn prepare(&self) -> io::Result<()> { self.inner.prepare() }
+
+    /// Runs $ playwright install chromium
+    pub fn install_chromium(&self) -> io::Result<()> { self.inner.install_chromium() }
+
+    pub fn install_firefox(&self) -> io::Result<()> { self.inner.install_firefox() }
+
+    pub fn install_webkit(&self) -> io::Result<()> { self.inner.install_webkit() }
+
+    /// Lists, installs and uninstalls the browsers of this driver with structured results
+    pub fn installer(&self) -> BrowserInstaller { self.inner.installer() }
+
+    /// Launcher
+    pub fn chromium(&self) -> BrowserType { BrowserType::new(self.inner.chromium(), &self.rt) }
+
+    /// Launcher
+    pub fn firefox(&self) -> BrowserType { BrowserType::new(self.inner.firefox(), &self.rt) }
+
+    /// Launcher
+    pub fn webkit(&self) -> BrowserType { BrowserType::new(self.inner.webkit(), &self.rt) }
+
+    pub fn driver(&mut self) -> &mut Driver { self.inner.driver() }
+
+    /// See [`api::Playwright::close`]
+    pub fn close(self) -> ArcResult<()> {
+        let Self { inner, rt } = self;
+        rt.block_on(inner.close())
+    }
+
+    /// See [`api::Playwright::set_call_timeout`]
+    pub fn set_call_timeout(&self, timeout: Option<Duration>) {
+        self.inner.set_call_timeout(timeout);
+    }
+
+    pub fn devices(&self) -> Vec<DeviceDescriptor> { self.inner.devices() }
+
+    pub fn device(&self, name: &str) -> Option<DeviceDescriptor> { self.inner.device(name) }
+}
@@ -0,0 +1,42 @@
+use super::Runtime;
+use crate::{
+    api::{self, Header},
+    imp::{core::*, prelude::*},
+    Error
+};
+
+/// Blocking [`api::Response`]
+#[derive(Debug, Clone)]
+pub struct Response {
+    inner: api::Response,
+    rt: Arc<Runtime>
+}
+
+impl Response {
+    pub(crate) fn new(inner: api::Response, rt: &Arc<Runtime>) -> Self {
+        Self {
+            inner,
+            rt: rt.clone()
+        }
+    }
+
+    pub fn url(&self) -> Result<String, Error> { self.inner.url() }
+
+    /// Contains the status code of the response (e.g., 200 for a success).
+    pub fn status(&self) -> Result<i32, Error> { self.inner.status() }
+
+    /// Contains the status text of the response (e.g. usually an "OK" for a success).
+    pub fn status_text(&self) -> Result<String, Error> { self.inner.status_text() }
+
+    /// Contains a boolean stating whether the response was successful (status in the range 200-299) or not.
+    pub fn ok(&self) -> Result<bool, Error> { self.inner.ok() }
+
+    /// Returns the buffer with response body.
+    pub fn body(&self) -> ArcResult<Vec<u8>> { self.rt.block_on(self.inner.body()) }
+
+    /// Returns the text representation of response body.
+    pub fn text(&self) -> ArcResult<String> { self.rt.block_on(self.inner.text()) }
+
+    /// Returns all headers of the response.
+    pub fn headers(&self) -> ArcResult<Vec<Header>> { self.rt.block_on(self.inner.headers()) }
+}
@@ -1,19 +1,52 @@
+use sha2::{Digest, Sha256};
 use std::{
     env, fmt, fs,
     fs::File,
+    io,
     path::{Path, PathBuf, MAIN_SEPARATOR}
 };
 
 const DRIVER_VERSION: &str = "1.25.0-alpha-jul-26-2022";
 
+/// Path to a local driver zip which is embedded instead of downloading
+const DRIVER_ZIP: &str = "PLAYWRIGHT_DRIVER_ZIP";
+/// Path to an extracted driver which is used at runtime. Nothing is embedded.
+const DRIVER_DIR: &str = "PLAYWRIGHT_DRIVER_DIR";
+
 fn main() {
     let out_dir: PathBuf = env::var_os("OUT_DIR").unwrap().into();
     let dest = out_dir.join("driver.zip");
     let platform = Platform::default();
     fs::write(out_dir.join("platform"), platform.to_string()).unwrap();
-    download(&url(platform), &dest);
+    let no_embed = env::var_os("CARGO_FEATURE_NO_EMBED_DRIVER").is_some();
+    if no_embed || env::var_os(DRIVER_DIR).is_some() {
+        // The driver is resolved by Driver::from_env at runtime
+        File::create(&dest).unwrap();
+    } else if let Some(zip) = env::var_os(DRIVER_ZIP) {
+        fs::copy(&zip, &dest).unwrap_or_else(|e| panic!("{}={:?}: {}", DRIVER_ZIP, zip, e));
+        check_size(&dest);
+    } else {
+        download(&url(platform), &dest);
+    }
+    fs::write(out_dir.join("driver.sha256"), sha256(&dest)).unwrap();
     println!("cargo:rerun-if-changed=src/build.rs");
+    println!("cargo:rerun-if-env-changed={}", DRIVER_ZIP);
+    println!("cargo:rerun-if-env-changed={}", DRIVER_DIR);
+    if let Some(zip) = env::var_os(DRIVER_ZIP) {
+        println!("cargo:rerun-if-changed={}", Path::new(&zip).display());
+    }
     println!("cargo:rustc-env=SEP={}", MAIN_SEPARATOR);
+    println!("cargo:rustc-env=DRIVER_VERSION={}", DRIVER_VERSION);
+}
+
+fn sha256(p: &Path) -> String {
+    let mut hasher = Sha256::new();
+    io::copy(&mut File::open(p).unwrap(), &mut hasher).unwrap();
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
 }
 
 #[cfg(all(not(feature = "only-for-docs-rs"), not(unix)))]
@@ -0,0 +1,666 @@
+//! Typed [HAR 1.2](http://www.softwareishard.com/blog/har-12-spec/) model for reading traffic recorded with `record_har`.
+//!
+//! ```no_run
+//! use playwright::har::Har;
+//!
+//! let har = Har::from_path("network.har").unwrap();
+//! assert_eq!(har.entries().url("**/analytics/**").count(), 0);
+//! let summary = har.summary();
+//! assert_eq!(summary.failed, 0);
+//! ```
+//!
+//! Fields prefixed with `_` are extensions written by playwright or browsers.
+
+use crate::{
+    imp::{har_router::is_zip, prelude::*, utils::UrlMatcher},
+    Error
+};
+use std::{collections::BTreeMap, fs, io, ops::Range, path::Component, str::FromStr};
+use zip::ZipArchive;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Har {
+    pub log: Log
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Log {
+    pub version: String,
+    pub creator: Creator,
+    pub browser: Option<Creator>,
+    #[serde(default)]
+    pub pages: Vec<Page>,
+    pub entries: Vec<Entry>,
+    pub comment: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Creator {
+    pub name: String,
+    pub version: String,
+    pub comment: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Page {
+    /// ISO 8601
+    pub started_date_time: String,
+    pub id: String,
+    pub title: String,
+    pub page_timings: PageTimings,
+    pub comment: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PageTimings {
+    /// Milliseconds since the page load started, or `-1`
+    pub on_content_load: Option<f64>,
+    /// Milliseconds since the page load started, or `-1`
+    pub on_load: Option<f64>,
+    pub comment: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Entry {
+    /// [`Page::id`] of the page which issued the request
+    pub pageref: Option<String>,
+    /// ISO 8601
+    pub started_date_time: String,
+    /// Total elapsed milliseconds of the request
+    pub time: f64,
+    pub request: Request,
+    pub response: Response,
+    #[serde(default)]
+    pub cache: Cache,
+    pub timings: Timings,
+    #[serde(rename = "serverIPAddress")]
+    pub server_ip_address: Option<String>,
+    pub connection: Option<String>,
+    pub comment: Option<String>,
+    /// Written by Chromium DevTools, and not by playwright
+    #[serde(rename = "_resourceType")]
+    pub resource_type: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub http_version: String,
+    #[serde(default)]
+    pub cookies: Vec<Cookie>,
+    #[serde(default)]
+    pub headers: Vec<Header>,
+    #[serde(default)]
+    pub query_string: Vec<QueryParam>,
+    pub post_data: Option<PostData>,
+    /// `-1` if unknown
+    pub headers_size: i64,
+    /// `-1` if unknown
+    pub body_size: i64,
+    pub comment: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    /// `0` or `-1` if the request failed without a response
+    pub status: i32,
+    pub status_text: String,
+    pub http_version: String,
+    #[serde(default)]
+    pub cookies: Vec<Cookie>,
+    #[serde(default)]
+    pub headers: Vec<Header>,
+    pub content: Content,
+    #[serde(rename = "redirectURL")]
+    pub redirect_url: String,
+    /// `-1` if unknown
+    pub headers_size: i64,
+    /// `-1` if unknown
+    pub body_size: i64,
+    pub comment: Option<String>,
+    /// Bytes received over the network including headers
+    #[serde(rename = "_transferSize")]
+    pub transfer_size: Option<i64>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Cookie {
+    pub name: String,
+    pub value: String,
+    pub path: Option<String>,
+    pub domain: Option<String>,
+    /// ISO 8601
+    pub expires: Option<String>,
+    pub http_only: Option<bool>,
+    pub secure: Option<bool>,
+    pub same_site: Option<String>,
+    pub comment: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct Header {
+    pub name: String,
+    pub value: String,
+    pub comment: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct QueryParam {
+    pub name: String,
+    pub value: String,
+    pub comment: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PostData {
+    pub mime_type: String,
+    #[serde(default)]
+    pub params: Vec<Param>,
+    pub text: Option<String>,
+    pub comment: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Param {
+    pub name: String,
+    pub value: Option<String>,
+    pub file_name: Option<String>,
+    pub content_type: Option<String>,
+    pub comment: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Content {
+    /// Length of the decoded body in bytes
+    pub size: i64,
+    pub compression: Option<i64>,
+    pub mime_type: String,
+    pub text: Option<String>,
+    /// `"base64"` if `text` is encoded
+    pub encoding: Option<String>,
+    pub comment: Option<String>,
+    /// Name of the file which holds the body when recorded with `content: Attach`
+    #[serde(rename = "_file")]
+    pub file: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Cache {
+    pub before_request: Option<CacheEntry>,
+    pub after_request: Option<CacheEntry>,
+    pub comment: Option<String>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheEntry {
+    pub expires: Option<String>,
+    pub last_access: String,
+    #[serde(rename = "eTag")]
+    pub e_tag: String,
+    pub hit_count: i64,
+    pub comment: Option<String>
+}
+
+/// Milliseconds of each phase. `-1` if the phase does not apply.
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Timings {
+    pub blocked: Option<f64>,
+    pub dns: Option<f64>,
+    pub connect: Option<f64>,
+    pub send: f64,
+    pub wait: f64,
+    pub receive: f64,
+    pub ssl: Option<f64>,
+    pub comment: Option<String>
+}
+
+impl Har {
+    pub fn from_reader<R: io::Read>(r: R) -> Result<Self, Error> {
+        Ok(serde_json::from_reader(io::BufReader::new(r))?)
+    }
+
+    /// Reads a `.har` file, or a `.zip` file recorded by playwright.
+    /// Bodies which were saved as separate files are not read. Read them on demand with [`Content::body_from`], or all
+    /// at once with [`Har::from_path_with_bodies`].
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        if is_zip(path) {
+            return Self::from_zip(&mut ZipArchive::new(fs::File::open(path)?)?);
+        }
+        Self::from_reader(fs::File::open(path)?)
+    }
+
+    /// [`Har::from_path`] which also reads the bodies saved as separate files into [`Content::text`] as base64.
+    /// The files are next to the `.har` file, or in the `.zip` file.
+    pub fn from_path_with_bodies<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        if is_zip(path) {
+            let mut zip = ZipArchive::new(fs::File::open(path)?)?;
+            let mut har = Self::from_zip(&mut zip)?;
+            har.attach(|name| {
+                let mut buf = Vec::new();
+                io::Read::read_to_end(&mut zip.by_name(name)?, &mut buf)?;
+                Ok(buf)
+            })?;
+            return Ok(har);
+        }
+        let mut har = Self::from_reader(fs::File::open(path)?)?;
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        har.attach(|name| Ok(fs::read(dir.join(name))?))?;
+        Ok(har)
+    }
+
+    fn from_zip(zip: &mut ZipArchive<fs::File>) -> Result<Self, Error> {
+        let name = zip
+            .file_names()
+            .find(|n| n.ends_with(".har"))
+            .map(str::to_owned)
+            .ok_or(zip::result::ZipError::FileNotFound)?;
+        Self::from_reader(zip.by_name(&name)?)
+    }
+
+    fn attach<F>(&mut self, mut read: F) -> Result<(), Error>
+    where
+        F: FnMut(&str) -> Result<Vec<u8>, Error>
+    {
+        for content in self.log.entries.iter_mut().map(|e| &mut e.response.content) {
+            match (&content.file, &content.text) {
+                (Some(file), None) => {
+                    check_file_name(file)?;
+                    content.text = Some(base64::encode(read(file)?));
+                    content.encoding = Some("base64".into());
+                }
+                _ => continue
+            }
+        }
+        Ok(())
+    }
+
+    /// All entries, which can be narrowed down with the methods of [`Entries`]
+    pub fn entries(&self) -> Entries<'_> {
+        Entries {
+            iter: self.log.entries.iter(),
+            url: None,
+            method: None,
+            status: None,
+            resource_type: None
+        }
+    }
+
+    pub fn summary(&self) -> Summary { self.entries().summary() }
+}
+
+impl FromStr for Har {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> { serde_json::from_str(s) }
+}
+
+impl Entry {
+    /// [`Entry::resource_type`] if recorded, otherwise guessed from the mime type of the response.
+    /// One of `document`, `stylesheet`, `image`, `media`, `font`, `script`, `fetch` and `other`.
+    pub fn resource_type(&self) -> &str {
+        if let Some(t) = &self.resource_type {
+            return t;
+        }
+        let mime = self.response.content.mime_type.as_str();
+        let mime = mime.split(';').next().unwrap_or_default().trim();
+        match mime {
+            "text/html" | "application/xhtml+xml" => "document",
+            "text/css" => "stylesheet",
+            "application/javascript" | "text/javascript" | "application/x-javascript" => "script",
+            "application/json" => "fetch",
+            _ if mime.starts_with("image/") => "image",
+            _ if mime.starts_with("audio/") || mime.starts_with("video/") => "media",
+            _ if mime.starts_with("font/") => "font",
+            _ => "other"
+        }
+    }
+
+    /// Whether the request got no response or an error status
+    pub fn is_failed(&self) -> bool { self.response.status <= 0 || self.response.status >= 400 }
+}
+
+impl Request {
+    /// Value of the first header named `name`, ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> { find_header(&self.headers, name) }
+}
+
+impl Response {
+    /// Value of the first header named `name`, ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> { find_header(&self.headers, name) }
+}
+
+fn find_header<'a>(headers: &'a [Header], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|h| h.name.eq_ignore_ascii_case(name))
+        .map(|h| h.value.as_str())
+}
+
+impl Content {
+    /// Decoded body. `None` if the body was omitted.
+    pub fn body(&self) -> Result<Option<Vec<u8>>, Error> {
+        let text = match &self.text {
+            Some(x) => x,
+            None => return Ok(None)
+        };
+        match self.encoding.as_deref() {
+            Some("base64") => Ok(Some(base64::decode(text)?)),
+            _ => Ok(Some(text.as_bytes().to_vec()))
+        }
+    }
+
+    /// [`Content::body`], which reads the body from [`Content::file`] in `dir` if it was saved as a separate file.
+    /// `dir` is the directory of the `.har` file, or the one into which its `.zip` file was extracted.
+    pub fn body_from<P: AsRef<Path>>(&self, dir: P) -> Result<Option<Vec<u8>>, Error> {
+        match (&self.file, &self.text) {
+            (Some(file), None) => {
+                check_file_name(file)?;
+                Ok(Some(fs::read(dir.as_ref().join(file))?))
+            }
+            _ => self.body()
+        }
+    }
+}
+
+/// Body files are next to the HAR, so that a crafted `_file` such as `../secret` or `/etc/passwd` cannot read others.
+fn check_file_name(name: &str) -> Result<(), Error> {
+    let mut components = Path::new(name).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) => Ok(()),
+        _ => Err(Error::HarOpen(format!("Invalid body file name {:?}", name)))
+    }
+}
+
+/// Iterator over [`Entry`] which match all of the given conditions
+#[derive(Debug, Clone)]
+pub struct Entries<'a> {
+    iter: std::slice::Iter<'a, Entry>,
+    url: Option<UrlMatcher>,
+    method: Option<String>,
+    status: Option<Range<i32>>,
+    resource_type: Option<String>
+}
+
+impl<'a> Entries<'a> {
+    /// Entries whose request URL matches the glob, regex or predicate
+    pub fn url<M: Into<UrlMatcher>>(mut self, x: M) -> Self {
+        self.url = Some(x.into());
+        self
+    }
+
+    /// Entries with the method, ignoring case
+    pub fn method(mut self, x: &str) -> Self {
+        self.method = Some(x.to_owned());
+        self
+    }
+
+    pub fn status(self, x: i32) -> Self { self.status_range(x..x + 1) }
+
+    /// Entries whose response status is in the range, e.g. `400..600`
+    pub fn status_range(mut self, x: Range<i32>) -> Self {
+        self.status = Some(x);
+        self
+    }
+
+    /// Entries whose [`Entry::resource_type`] is `x`
+    pub fn resource_type(mut self, x: &str) -> Self {
+        self.resource_type = Some(x.to_owned());
+        self
+    }
+
+    pub fn summary(self) -> Summary {
+        let mut s = Summary::default();
+        for e in self {
+            s.entries += 1;
+            if e.is_failed() {
+                s.failed += 1;
+            }
+            s.time += e.time;
+            s.body_size += e.response.body_size.max(0);
+            s.transfer_size += e.response.transfer_size.unwrap_or_default().max(0);
+            *s.statuses.entry(e.response.status).or_default() += 1;
+            *s.methods
+                .entry(e.request.method.to_uppercase())
+                .or_default() += 1;
+            *s.resource_types
+                .entry(e.resource_type().to_owned())
+                .or_default() += 1;
+            *s.hosts.entry(host(&e.request.url).to_owned()).or_default() += 1;
+        }
+        s
+    }
+
+    fn is_match(&self, e: &Entry) -> bool {
+        self.url
+            .as_ref()
+            .map_or(true, |m| m.is_match(&e.request.url))
+            && self
+                .method
+                .as_ref()
+                .map_or(true, |m| m.eq_ignore_ascii_case(&e.request.method))
+            && self
+                .status
+                .as_ref()
+                .map_or(true, |r| r.contains(&e.response.status))
+            && self
+                .resource_type
+                .as_ref()
+                .map_or(true, |t| t == e.resource_type())
+    }
+}
+
+impl<'a> Iterator for Entries<'a> {
+    type Item = &'a Entry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(e) = self.iter.next() {
+            if self.is_match(e) {
+                return Some(e);
+            }
+        }
+        None
+    }
+}
+
+/// Statistics of entries
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Summary {
+    pub entries: usize,
+    /// Entries which got no response or an error status
+    pub failed: usize,
+    /// Sum of [`Entry::time`] in milliseconds
+    pub time: f64,
+    /// Sum of known response body sizes
+    pub body_size: i64,
+    /// Sum of known `_transferSize`s of responses
+    pub transfer_size: i64,
+    pub statuses: BTreeMap<i32, usize>,
+    /// Upper case methods
+    pub methods: BTreeMap<String, usize>,
+    pub resource_types: BTreeMap<String, usize>,
+    /// `host:port` of request URLs
+    pub hosts: BTreeMap<String, usize>
+}
+
+fn host(url: &str) -> &str {
+    let rest = url.split_once("://").map(|(_, r)| r).unwrap_or(url);
+    let end = rest.find(&['/', '?', '#'][..]).unwrap_or(rest.len());
+    let authority = &rest[..end];
+    authority
+        .rsplit_once('@')
+        .map(|(_, h)| h)
+        .unwrap_or(authority)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HAR: &str = r#"{
+        "log": {
+            "version": "1.2",
+            "creator": { "name": "Playwright", "version": "1.27.0" },
+            "browser": { "name": "chromium", "version": "107.0.5304.18" },
+            "pages": [{
+                "startedDateTime": "2022-10-18T00:00:00.000Z",
+                "id": "page@1",
+                "title": "Example",
+                "pageTimings": { "onContentLoad": 12.5, "onLoad": 20 }
+            }],
+            "entries": [{
+                "pageref": "page@1",
+                "startedDateTime": "2022-10-18T00:00:00.000Z",
+                "time": 10.5,
+                "request": {
+                    "method": "GET",
+                    "url": "https://example.com/",
+                    "httpVersion": "HTTP/1.1",
+                    "cookies": [],
+                    "headers": [{ "name": "Accept", "value": "text/html" }],
+                    "queryString": [],
+                    "headersSize": -1,
+                    "bodySize": 0
+                },
+                "response": {
+                    "status": 200,
+                    "statusText": "OK",
+                    "httpVersion": "HTTP/1.1",
+                    "cookies": [],
+                    "headers": [{ "name": "Content-Type", "value": "text/html; charset=utf-8" }],
+                    "content": { "size": 6, "mimeType": "text/html; charset=utf-8", "text": "PGh0bWw+", "encoding": "base64" },
+                    "redirectURL": "",
+                    "headersSize": -1,
+                    "bodySize": 6,
+                    "_transferSize": 100
+                },
+                "cache": {},
+                "timings": { "send": 0, "wait": 10, "receive": 0.5, "dns": -1 },
+                "serverIPAddress": "93.184.216.34"
+            }, {
+                "startedDateTime": "2022-10-18T00:00:01.000Z",
+                "time": 3,
+                "request": {
+                    "method": "post",
+                    "url": "https://user@analytics.example.com:8443/collect?id=1",
+                    "httpVersion": "HTTP/1.1",
+                    "cookies": [],
+                    "headers": [],
+                    "queryString": [{ "name": "id", "value": "1" }],
+                    "postData": { "mimeType": "application/json", "text": "{}" },
+                    "headersSize": -1,
+                    "bodySize": 2
+                },
+                "response": {
+                    "status": -1,
+                    "statusText": "",
+                    "httpVersion": "",
+                    "cookies": [],
+                    "headers": [],
+                    "content": { "size": -1, "mimeType": "x-unknown" },
+                    "redirectURL": "",
+                    "headersSize": -1,
+                    "bodySize": -1
+                },
+                "cache": {},
+                "timings": { "send": 0, "wait": 3, "receive": 0 }
+            }]
+        }
+    }"#;
+
+    #[test]
+    fn parse() {
+        let har: Har = HAR.parse().unwrap();
+        assert_eq!(har.log.pages[0].page_timings.on_load, Some(20.));
+        let e = &har.log.entries[0];
+        assert_eq!(e.server_ip_address.as_deref(), Some("93.184.216.34"));
+        assert_eq!(e.request.header("accept"), Some("text/html"));
+        assert_eq!(e.response.content.body().unwrap().unwrap(), b"<html>");
+        assert_eq!(e.resource_type(), "document");
+        let e = &har.log.entries[1];
+        assert_eq!(
+            e.request.post_data.as_ref().unwrap().text.as_deref(),
+            Some("{}")
+        );
+        assert_eq!(e.response.content.body().unwrap(), None);
+        assert!(e.is_failed());
+        let v = to_value(&har).unwrap();
+        assert_eq!(v["log"]["entries"][0]["_transferSize"], Value::Null);
+        assert_eq!(v["log"]["entries"][0]["response"]["_transferSize"], 100);
+        assert_eq!(serde_json::from_value::<Har>(v).unwrap(), har);
+    }
+
+    #[test]
+    fn body_from() {
+        let dir = std::env::temp_dir().join("test-playwright-rust-har");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("body.html"), "<html>").unwrap();
+        let mut har: Har = HAR.parse().unwrap();
+        let content = &mut har.log.entries[0].response.content;
+        content.text = None;
+        content.encoding = None;
+        content.file = Some("body.html".into());
+        assert_eq!(content.body().unwrap(), None);
+        assert_eq!(content.body_from(&dir).unwrap().unwrap(), b"<html>");
+        for name in ["../body.html", "/etc/passwd", "a/body.html", ".", ""] {
+            content.file = Some(name.into());
+            assert!(content.body_from(&dir).is_err(), "{}", name);
+        }
+        fs::write(dir.join("escape.har"), to_value(&har).unwrap().to_string()).unwrap();
+        assert!(Har::from_path(dir.join("escape.har")).is_ok());
+        assert!(Har::from_path_with_bodies(dir.join("escape.har")).is_err());
+    }
+
+    #[test]
+    fn filter() {
+        let har: Har = HAR.parse().unwrap();
+        assert_eq!(har.entries().count(), 2);
+        assert_eq!(har.entries().url("**/collect*").count(), 1);
+        assert_eq!(har.entries().url("**/analytics/**").count(), 0);
+        assert_eq!(har.entries().method("POST").count(), 1);
+        assert_eq!(har.entries().status(200).method("post").count(), 0);
+        assert_eq!(har.entries().status_range(200..300).count(), 1);
+        assert_eq!(har.entries().resource_type("document").count(), 1);
+        let s = har.summary();
+        assert_eq!(s.entries, 2);
+        assert_eq!(s.failed, 1);
+        assert_eq!(s.time, 13.5);
+        assert_eq!(s.body_size, 6);
+        assert_eq!(s.transfer_size, 100);
+        assert_eq!(s.methods["POST"], 1);
+        assert_eq!(s.hosts["analytics.example.com:8443"], 1);
+        assert_eq!(s.hosts["example.com"], 1);
+        assert_eq!(s.resource_types["other"], 1);
+    }
+}
@@ -83,12 +83,14 @@ pub(crate) mod core {
     mod driver;
     mod event_emitter;
     mod message;
+    mod process;
     mod remote_object;
     mod transport;
     pub use connection::*;
     pub use driver::*;
     pub use event_emitter::*;
     pub use message::*;
+    pub(crate) use process::*;
     pub(crate) use remote_object::*;
     pub use transport::*;
 }
@@ -98,22 +100,28 @@ pub(crate) mod playwright;
 pub(crate) mod selectors;
 pub(crate) mod utils;
 
+pub(crate) mod api_request_context;
 pub(crate) mod artifact;
 pub(crate) mod binding_call;
 pub(crate) mod browser;
 pub(crate) mod browser_context;
+pub(crate) mod browser_server;
 pub(crate) mod console_message;
 pub(crate) mod dialog;
 pub(crate) mod download;
 pub(crate) mod element_handle;
 pub(crate) mod file_hooser;
 pub(crate) mod frame;
+pub(crate) mod har_router;
 pub(crate) mod js_handle;
+pub(crate) mod local_utils;
 pub(crate) mod page;
 pub(crate) mod request;
 pub(crate) mod response;
 pub(crate) mod route;
+pub(crate) mod screenshot;
 pub(crate) mod stream;
+pub(crate) mod tracing;
 pub(crate) mod video;
 pub(crate) mod websocket;
 pub(crate) mod worker;
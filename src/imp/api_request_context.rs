@@ -0,0 +1,138 @@
+use crate::imp::{
+    core::*,
+    prelude::*,
+    utils::{File, Header, HttpCredentials, ProxySettings, StorageState}
+};
+
+#[derive(Debug)]
+pub(crate) struct ApiRequestContext {
+    channel: ChannelOwner
+}
+
+impl ApiRequestContext {
+    pub(crate) fn try_new(channel: ChannelOwner) -> Result<Self, Error> { Ok(Self { channel }) }
+
+    pub(crate) async fn fetch(&self, args: FetchArgs<'_>) -> ArcResult<FetchedResponse> {
+        let v = send_message!(self, "fetch", args);
+        let first = first(&v).ok_or(Error::InvalidParams)?;
+        let mut res: FetchedResponse =
+            serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
+        for h in res.headers.iter_mut() {
+            h.name.make_ascii_lowercase();
+        }
+        Ok(res)
+    }
+
+    pub(crate) async fn fetch_response_body(&self, fetch_uid: &str) -> ArcResult<Vec<u8>> {
+        let v = send_message!(self, "fetchResponseBody", FetchUid { fetch_uid });
+        let s = maybe_only_str(&v)?.ok_or(Error::ResponseDisposed)?;
+        let bytes = base64::decode(s).map_err(Error::InvalidBase64)?;
+        Ok(bytes)
+    }
+
+    pub(crate) async fn fetch_log(&self, fetch_uid: &str) -> ArcResult<Vec<String>> {
+        let v = send_message!(self, "fetchLog", FetchUid { fetch_uid });
+        let first = first(&v).ok_or(Error::InvalidParams)?;
+        let log = serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
+        Ok(log)
+    }
+
+    pub(crate) async fn dispose_api_response(&self, fetch_uid: &str) -> ArcResult<()> {
+        let _ = send_message!(self, "disposeAPIResponse", FetchUid { fetch_uid });
+        Ok(())
+    }
+
+    pub(crate) async fn storage_state(&self) -> ArcResult<StorageState> {
+        let v = send_message!(self, "storageState", Map::new());
+        let s = serde_json::from_value((*v).clone()).map_err(Error::Serde)?;
+        Ok(s)
+    }
+
+    pub(crate) async fn dispose(&self) -> ArcResult<()> {
+        let _ = send_message!(self, "dispose", Map::new());
+        Ok(())
+    }
+}
+
+impl RemoteObject for ApiRequestContext {
+    fn channel(&self) -> &ChannelOwner { &self.channel }
+    fn channel_mut(&mut self) -> &mut ChannelOwner { &mut self.channel }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FetchUid<'a> {
+    fetch_uid: &'a str
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NewRequestArgs<'a, 'b> {
+    #[serde(rename = "baseURL")]
+    pub(crate) base_url: Option<&'a str>,
+    pub(crate) user_agent: Option<&'b str>,
+    #[serde(rename = "ignoreHTTPSErrors")]
+    pub(crate) ignore_https_errors: Option<bool>,
+    #[serde(rename = "extraHTTPHeaders")]
+    pub(crate) extra_http_headers: Option<Vec<Header>>,
+    pub(crate) http_credentials: Option<HttpCredentials>,
+    pub(crate) proxy: Option<ProxySettings>,
+    pub(crate) timeout: Option<f64>,
+    pub(crate) storage_state: Option<StorageState>
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FetchArgs<'a> {
+    url: &'a str,
+    pub(crate) method: Option<&'a str>,
+    pub(crate) params: Option<Vec<Header>>,
+    pub(crate) headers: Option<Vec<Header>>,
+    /// base64
+    pub(crate) post_data: Option<String>,
+    pub(crate) json_data: Option<Value>,
+    pub(crate) form_data: Option<Vec<Header>>,
+    pub(crate) multipart_data: Option<Vec<FormField>>,
+    pub(crate) timeout: Option<f64>,
+    pub(crate) fail_on_status_code: Option<bool>,
+    #[serde(rename = "ignoreHTTPSErrors")]
+    pub(crate) ignore_https_errors: Option<bool>
+}
+
+impl<'a> FetchArgs<'a> {
+    pub(crate) fn new(url: &'a str, method: Option<&'a str>) -> Self {
+        Self {
+            url,
+            method,
+            params: None,
+            headers: None,
+            post_data: None,
+            json_data: None,
+            form_data: None,
+            multipart_data: None,
+            timeout: None,
+            fail_on_status_code: None,
+            ignore_https_errors: None
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+pub(crate) struct FormField {
+    pub(crate) name: String,
+    pub(crate) value: Option<String>,
+    pub(crate) file: Option<File>
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FetchedResponse {
+    pub(crate) fetch_uid: String,
+    pub(crate) url: String,
+    pub(crate) status: i32,
+    pub(crate) status_text: String,
+    pub(crate) headers: Vec<Header>
+}
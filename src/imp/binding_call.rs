@@ -1,15 +1,135 @@
-use crate::imp::core::*;
+use crate::imp::{core::*, frame::Frame, prelude::*};
+use tokio::sync::mpsc;
 
 #[derive(Debug)]
 pub(crate) struct BindingCall {
-    channel: ChannelOwner
+    channel: ChannelOwner,
+    name: String,
+    frame: Weak<Frame>,
+    args: Vec<Value>
 }
 
+/// Receives the frame and the serialized arguments array,
+/// and resolves to a serialized argument or an error message.
+pub(crate) type BindingFn = Arc<
+    dyn Fn(Weak<Frame>, Value) -> Pin<Box<dyn Future<Output = Result<Value, String>> + Send>>
+        + Send
+        + Sync
+>;
+
 impl BindingCall {
-    pub(crate) fn new(channel: ChannelOwner) -> Self { Self { channel } }
+    pub(crate) fn try_new(ctx: &Context, channel: ChannelOwner) -> Result<Self, Error> {
+        let Initializer {
+            frame: OnlyGuid { guid },
+            name,
+            args
+        } = serde_json::from_value(channel.initializer.clone())?;
+        let frame = get_object!(ctx, &guid, Frame)?;
+        Ok(Self {
+            channel,
+            name,
+            frame,
+            args
+        })
+    }
+
+    pub(crate) fn name(&self) -> &str { &self.name }
+
+    pub(crate) async fn call(&self, f: BindingFn) -> ArcResult<()> {
+        let args = Value::Array(self.args.clone());
+        match f(self.frame.clone(), args).await {
+            Ok(result) => self.resolve(result).await,
+            Err(message) => self.reject(&message).await
+        }
+    }
+
+    async fn resolve(&self, result: Value) -> ArcResult<()> {
+        let mut args = Map::new();
+        args.insert("result".into(), result);
+        let _ = send_message!(self, "resolve", args);
+        Ok(())
+    }
+
+    async fn reject(&self, message: &str) -> ArcResult<()> {
+        #[derive(Serialize)]
+        struct Args<'a> {
+            error: SerializedError<'a>
+        }
+        #[derive(Serialize)]
+        struct SerializedError<'a> {
+            error: ErrorBody<'a>
+        }
+        #[derive(Serialize)]
+        struct ErrorBody<'a> {
+            message: &'a str,
+            name: &'a str,
+            stack: &'a str
+        }
+        let args = Args {
+            error: SerializedError {
+                error: ErrorBody {
+                    message,
+                    name: "Error",
+                    stack: ""
+                }
+            }
+        };
+        let _ = send_message!(self, "reject", args);
+        Ok(())
+    }
 }
 
 impl RemoteObject for BindingCall {
     fn channel(&self) -> &ChannelOwner { &self.channel }
     fn channel_mut(&mut self) -> &mut ChannelOwner { &mut self.channel }
 }
+
+/// Functions registered by Page.expose_binding or BrowserContext.expose_binding.
+#[derive(Default)]
+pub(crate) struct Bindings {
+    functions: HashMap<String, BindingFn>,
+    tx: Option<mpsc::UnboundedSender<Weak<BindingCall>>>
+}
+
+impl Bindings {
+    pub(crate) fn contains(&self, name: &str) -> bool { self.functions.contains_key(name) }
+
+    pub(crate) fn insert(&mut self, name: String, f: BindingFn) { self.functions.insert(name, f); }
+
+    pub(crate) fn get(&self, name: &str) -> Option<BindingFn> { self.functions.get(name).cloned() }
+
+    /// Opens the channel of binding calls.
+    /// The receiver is returned only once and its owner is responsible for dispatching.
+    pub(crate) fn subscribe(&mut self) -> Option<mpsc::UnboundedReceiver<Weak<BindingCall>>> {
+        if self.tx.is_some() {
+            return None;
+        }
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.tx = Some(tx);
+        Some(rx)
+    }
+
+    pub(crate) fn emit(&self, call: Weak<BindingCall>) -> bool {
+        match &self.tx {
+            Some(tx) => tx.send(call).is_ok(),
+            None => false
+        }
+    }
+}
+
+impl std::fmt::Debug for Bindings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Bindings")
+            .field("functions", &self.functions.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Initializer {
+    frame: OnlyGuid,
+    name: String,
+    #[serde(default)]
+    args: Vec<Value>
+}
@@ -66,9 +66,16 @@ impl Browser {
         &self,
         args: NewContextArgs<'_, '_, '_, '_, '_, '_, '_>
     ) -> Result<Weak<BrowserContext>, Arc<Error>> {
+        let har = args
+            .record_har
+            .as_ref()
+            .map(|h| (h.path.to_owned(), h.content()));
         let res = send_message!(self, "newContext", args);
         let guid = only_guid(&res)?;
         let c = get_object!(self.context()?.lock().unwrap(), guid, BrowserContext)?;
+        if let Some((path, content)) = har {
+            upgrade(&c)?.add_har_recorder(None, path, content);
+        }
         self.register_new_context(c.clone())?;
         Ok(c)
     }
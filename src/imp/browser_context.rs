@@ -1,14 +1,28 @@
 use crate::imp::{
+    api_request_context::ApiRequestContext,
+    artifact::Artifact,
+    binding_call::{BindingCall, BindingFn, Bindings},
     browser::Browser,
+    browser_type::RecordHar,
     core::*,
+    har_router::{is_zip, HarRouter, RouteFromHarArgs},
+    local_utils::LocalUtils,
     page::Page,
     prelude::*,
-    utils::{Cookie, Geolocation, Header, StorageState}
+    route::{ContinueArgs, Route, RouteHandler, Routes},
+    tracing::Tracing,
+    utils::{
+        Cookie, Geolocation, HarContentPolicy, HarMode, HarNotFound, Header, StorageState,
+        UrlMatcher
+    }
 };
+use tokio::sync::mpsc;
 
 #[derive(Debug)]
 pub(crate) struct BrowserContext {
     channel: ChannelOwner,
+    request: Weak<ApiRequestContext>,
+    tracing: Weak<Tracing>,
     var: Mutex<Variable>,
     tx: Mutex<Option<broadcast::Sender<Evt>>>
 }
@@ -18,14 +32,32 @@ pub(crate) struct Variable {
     browser: Option<Weak<Browser>>,
     pages: Vec<Weak<Page>>,
     timeout: Option<u32>,
-    navigation_timeout: Option<u32>
+    navigation_timeout: Option<u32>,
+    routes: Routes,
+    bindings: Bindings,
+    /// HARs which are written on close
+    har_recorders: Vec<HarRecorder>,
+    /// HARs which are open until the browser context closes
+    har_routers: Vec<Arc<HarRouter>>
+}
+
+#[derive(Debug)]
+struct HarRecorder {
+    har_id: Option<String>,
+    path: PathBuf,
+    content: HarContentPolicy
 }
 
 impl BrowserContext {
     const DEFAULT_TIMEOUT: u32 = 30000;
 
-    pub(crate) fn try_new(channel: ChannelOwner) -> Result<Self, Error> {
-        let Initializer {} = serde_json::from_value(channel.initializer.clone())?;
+    pub(crate) fn try_new(ctx: &Context, channel: ChannelOwner) -> Result<Self, Error> {
+        let Initializer {
+            request_context,
+            tracing
+        } = serde_json::from_value(channel.initializer.clone())?;
+        let request = get_object!(ctx, &request_context.guid, ApiRequestContext)?;
+        let tracing = get_object!(ctx, &tracing.guid, Tracing)?;
         let browser = match &channel.parent {
             Some(RemoteWeak::Browser(b)) => Some(b.clone()),
             _ => None
@@ -36,6 +68,8 @@ impl BrowserContext {
         });
         Ok(Self {
             channel,
+            request,
+            tracing,
             var,
             tx: Mutex::default()
         })
@@ -49,6 +83,10 @@ impl BrowserContext {
     }
 
     pub(crate) async fn close(&self) -> Result<(), Arc<Error>> {
+        let recorders = std::mem::take(&mut self.var.lock().unwrap().har_recorders);
+        for recorder in &recorders {
+            self.export_har(recorder).await?;
+        }
         let _ = send_message!(self, "close", Map::new());
         Ok(())
     }
@@ -155,10 +193,36 @@ impl BrowserContext {
         Ok(())
     }
 
-    // async def expose_binding(
-    // async def expose_function(self, name: str, callback: Callable) -> None:
-    // async def route(self, url: URLMatch, handler: RouteHandler) -> None:
-    // async def unroute(
+    pub(crate) async fn expose_binding(&self, name: &str, f: BindingFn) -> ArcResult<()> {
+        let in_page = self
+            .pages()
+            .iter()
+            .filter_map(|p| p.upgrade())
+            .any(|p| p.has_binding(name));
+        if in_page || self.has_binding(name) {
+            return Err(Error::FunctionAlreadyRegistered(name.into()).into());
+        }
+        let rx = {
+            let bindings = &mut self.var.lock().unwrap().bindings;
+            bindings.insert(name.into(), f);
+            bindings.subscribe()
+        };
+        if let Some(rx) = rx {
+            let this = get_object!(self.context()?.lock().unwrap(), self.guid(), BrowserContext)?;
+            spawn(Self::dispatch_binding_calls(this, rx));
+        }
+        let mut args = Map::new();
+        args.insert("name".into(), name.into());
+        let _ = send_message!(self, "exposeBinding", args);
+        Ok(())
+    }
+
+    async fn set_network_interception_enabled(&self, enabled: bool) -> ArcResult<()> {
+        let mut args = Map::new();
+        args.insert("enabled".into(), enabled.into());
+        let _ = send_message!(self, "setNetworkInterceptionEnabled", args);
+        Ok(())
+    }
 
     // async fn pause(&self) -> ArcResult<()> {
     //    let _ = send_message!(self, "pause", Map::new());
@@ -168,6 +232,10 @@ impl BrowserContext {
 
 // mutable
 impl BrowserContext {
+    pub(crate) fn request(&self) -> Weak<ApiRequestContext> { self.request.clone() }
+
+    pub(crate) fn tracing(&self) -> Weak<Tracing> { self.tracing.clone() }
+
     pub(crate) fn browser(&self) -> Option<Weak<Browser>> {
         self.var.lock().unwrap().browser.clone()
     }
@@ -218,6 +286,9 @@ impl BrowserContext {
     }
 
     fn on_close(&self, ctx: &Context) -> Result<(), Error> {
+        for router in std::mem::take(&mut self.var.lock().unwrap().har_routers) {
+            router.close(ctx);
+        }
         let browser = match self.browser().and_then(|b| b.upgrade()) {
             None => return Ok(()),
             Some(b) => b
@@ -228,8 +299,216 @@ impl BrowserContext {
         Ok(())
     }
 
-    fn on_route(&self, _ctx: &Context, _parmas: Map<String, Value>) -> Result<(), Error> {
-        // TODO: noimplemented
+    pub(crate) async fn route(&self, handler: RouteHandler) -> ArcResult<()> {
+        let (rx, first) = {
+            let routes = &mut self.var.lock().unwrap().routes;
+            let first = routes.is_empty();
+            routes.push(handler);
+            (routes.subscribe(), first)
+        };
+        if let Some(rx) = rx {
+            let this = get_object!(self.context()?.lock().unwrap(), self.guid(), BrowserContext)?;
+            spawn(Self::dispatch_routes(this, rx));
+        }
+        if first {
+            self.set_network_interception_enabled(true).await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn unroute(&self, matcher: &UrlMatcher) -> ArcResult<()> {
+        let emptied = {
+            let routes = &mut self.var.lock().unwrap().routes;
+            let had = !routes.is_empty();
+            routes.remove(matcher);
+            had && routes.is_empty()
+        };
+        if emptied {
+            self.set_network_interception_enabled(false).await?;
+        }
+        Ok(())
+    }
+
+    async fn dispatch_routes(this: Weak<Self>, mut rx: mpsc::UnboundedReceiver<Weak<Route>>) {
+        while let Some(route) = rx.recv().await {
+            let this = match this.upgrade() {
+                Some(x) => x,
+                None => break
+            };
+            spawn(async move { this.handle_route(route).await });
+        }
+    }
+
+    pub(crate) async fn route_from_har(&self, har: &Path, args: RouteFromHarArgs) -> ArcResult<()> {
+        if args.update.unwrap_or_default() {
+            return self.record_into_har(har, None, &args.url()).await;
+        }
+        let not_found = args.not_found.unwrap_or(HarNotFound::Abort);
+        let router = HarRouter::open(self.local_utils()?, har, not_found).await?;
+        self.var.lock().unwrap().har_routers.push(router.clone());
+        let handler = router.handler(args.url(), |route| async move {
+            if let Some(route) = route.upgrade() {
+                if let Err(e) = route.r#continue(ContinueArgs::default()).await {
+                    log::warn!("Failed to continue route: {}", e);
+                }
+            }
+        });
+        self.route(handler).await
+    }
+
+    /// Starts recording requests matching `url` to be written into `har` on close
+    pub(crate) async fn record_into_har(
+        &self,
+        har: &Path,
+        page: Option<&S<Guid>>,
+        url: &UrlMatcher
+    ) -> ArcResult<()> {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        struct Args<'a> {
+            page: Option<OnlyGuid>,
+            options: RecordHar<'a>
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Res {
+            har_id: String
+        }
+        let options = RecordHar {
+            mode: Some(HarMode::Minimal),
+            url_filter: Some(url.clone()),
+            ..RecordHar::new(har)
+        };
+        let content = options.content();
+        let args = Args {
+            page: page.map(|guid| OnlyGuid {
+                guid: guid.to_owned()
+            }),
+            options
+        };
+        let v = send_message!(self, "harStart", args);
+        let Res { har_id } = serde_json::from_value((*v).clone()).map_err(Error::Serde)?;
+        self.add_har_recorder(Some(har_id), har.to_owned(), content);
+        Ok(())
+    }
+
+    /// Registers a HAR to be written on close. `har_id` is `None` for the one given on creation of the context.
+    pub(crate) fn add_har_recorder(
+        &self,
+        har_id: Option<String>,
+        path: PathBuf,
+        content: HarContentPolicy
+    ) {
+        self.var.lock().unwrap().har_recorders.push(HarRecorder {
+            har_id,
+            path,
+            content
+        });
+    }
+
+    async fn export_har(&self, recorder: &HarRecorder) -> ArcResult<()> {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Args<'a> {
+            har_id: Option<&'a str>
+        }
+        let HarRecorder {
+            har_id,
+            path,
+            content
+        } = recorder;
+        let args = Args {
+            har_id: har_id.as_deref()
+        };
+        let v = send_message!(self, "harExport", args);
+        let guid = only_guid(&v)?;
+        let artifact = get_object!(self.context()?.lock().unwrap(), guid, Artifact)?;
+        let artifact = upgrade(&artifact)?;
+        // The driver zips the HAR when the bodies are attached
+        let is_compressed = *content == HarContentPolicy::Attach || is_zip(path);
+        if is_compressed && !is_zip(path) {
+            let mut zip = path.as_os_str().to_owned();
+            zip.push(".tmp");
+            let zip = PathBuf::from(zip);
+            artifact.save_as(&zip).await?;
+            upgrade(&self.local_utils()?)?.har_unzip(&zip, path).await?;
+        } else {
+            artifact.save_as(path).await?;
+        }
+        artifact.delete().await
+    }
+
+    pub(crate) fn local_utils(&self) -> Result<Weak<LocalUtils>, Error> {
+        self.context()?
+            .lock()
+            .unwrap()
+            .local_utils()
+            .ok_or(Error::ObjectNotFound)
+    }
+
+    /// Called directly by pages whose own handlers didn't match
+    pub(crate) async fn handle_route(&self, route: Weak<Route>) {
+        let url = route
+            .upgrade()
+            .and_then(|r| r.request().upgrade())
+            .map(|r| r.url().to_owned());
+        let handler = url.and_then(|u| self.var.lock().unwrap().routes.find(&u));
+        if let Some(handler) = handler {
+            handler.handle(route).await;
+        } else if let Some(route) = route.upgrade() {
+            if let Err(e) = route.r#continue(ContinueArgs::default()).await {
+                log::warn!("Failed to continue route: {}", e);
+            }
+        }
+    }
+
+    pub(crate) fn has_binding(&self, name: &str) -> bool {
+        self.var.lock().unwrap().bindings.contains(name)
+    }
+
+    async fn dispatch_binding_calls(
+        this: Weak<Self>,
+        mut rx: mpsc::UnboundedReceiver<Weak<BindingCall>>
+    ) {
+        while let Some(call) = rx.recv().await {
+            let this = match this.upgrade() {
+                Some(x) => x,
+                None => break
+            };
+            spawn(async move { this.handle_binding_call(call).await });
+        }
+    }
+
+    /// Called directly by pages which don't have the binding
+    pub(crate) async fn handle_binding_call(&self, call: Weak<BindingCall>) {
+        let call = match call.upgrade() {
+            Some(x) => x,
+            None => return
+        };
+        let f = self.var.lock().unwrap().bindings.get(call.name());
+        if let Some(f) = f {
+            if let Err(e) = call.call(f).await {
+                log::warn!("Failed to respond to binding call: {}", e);
+            }
+        }
+    }
+
+    fn on_binding(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        let binding = params.get("binding").ok_or(Error::InvalidParams)?;
+        let call = get_object!(ctx, guid_from_params(binding)?, BindingCall)?;
+        if !self.var.lock().unwrap().bindings.emit(call) {
+            log::warn!("Binding call received without bindings");
+        }
+        Ok(())
+    }
+
+    fn on_route(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        let route = params.get("route").ok_or(Error::InvalidParams)?;
+        let route = get_object!(ctx, guid_from_params(route)?, Route)?;
+        if !self.var.lock().unwrap().routes.emit(route) {
+            log::warn!("Route event received without handlers");
+        }
         Ok(())
     }
 }
@@ -269,7 +548,7 @@ impl RemoteObject for BrowserContext {
                 self.emit_event(Evt::Page(p));
             }
             "close" => self.on_close(ctx)?,
-            "bindingCall" => {}
+            "bindingCall" => self.on_binding(ctx, params)?,
             "route" => self.on_route(ctx, params)?,
             _ => {}
         }
@@ -310,7 +589,10 @@ impl IsEvent for Evt {
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct Initializer {}
+struct Initializer {
+    request_context: OnlyGuid,
+    tracing: OnlyGuid
+}
 
 #[cfg(test)]
 mod tests {
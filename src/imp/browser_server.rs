@@ -0,0 +1,176 @@
+use crate::imp::{browser_type::LaunchArgs, core::*, prelude::*};
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+use std::{
+    env, fs,
+    io::{BufRead, BufReader},
+    process::{Child, ChildStdout, Command, Stdio},
+    sync::atomic::{AtomicUsize, Ordering}
+};
+
+/// A browser hosted by `playwright launch-server`
+#[derive(Debug)]
+pub(crate) struct BrowserServer {
+    process: Mutex<Option<Child>>,
+    pid: u32,
+    ws_endpoint: String,
+    /// Kept open so that the server never writes to a closed pipe
+    _stdout: BufReader<ChildStdout>
+}
+
+impl BrowserServer {
+    pub(crate) async fn launch(
+        driver: &Path,
+        browser_name: &str,
+        args: LaunchServerArgs<'_, '_, '_, '_>
+    ) -> ArcResult<Self> {
+        let config = ConfigFile::new(&args)?;
+        let mut command = Command::new(driver);
+        let child = command
+            .args(&["launch-server", "--browser", browser_name, "--config"])
+            .arg(&config.0)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit());
+        #[cfg(target_os = "windows")]
+        child.creation_flags(0x08000000);
+        let mut child = child.spawn().map_err(Error::Io)?;
+        let pid = child.id();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        let read = unblock(move || {
+            let mut stdout = stdout;
+            let mut line = String::new();
+            stdout.read_line(&mut line).map(|_| (line, stdout))
+        })
+        .await;
+        // The config is read by the server before it starts listening
+        drop(config);
+        let (line, stdout) = match read {
+            Ok(Ok(x)) => x,
+            Ok(Err(e)) => {
+                let _ = child.kill();
+                return Err(Error::Io(e).into());
+            }
+            Err(e) => {
+                let _ = child.kill();
+                return Err(e.into());
+            }
+        };
+        let ws_endpoint = line.trim();
+        if !ws_endpoint.starts_with("ws://") {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(Error::BrowserServerExited.into());
+        }
+        Ok(Self {
+            process: Mutex::new(Some(child)),
+            pid,
+            ws_endpoint: ws_endpoint.into(),
+            _stdout: stdout
+        })
+    }
+
+    pub(crate) fn ws_endpoint(&self) -> &str { &self.ws_endpoint }
+
+    pub(crate) fn process(&self) -> u32 { self.pid }
+
+    /// Asks the server to close the browser and waits for the server to exit.
+    pub(crate) async fn close(&self) -> ArcResult<()> {
+        let child = match self.process.lock().unwrap().take() {
+            Some(x) => x,
+            None => return Ok(())
+        };
+        terminate(child).await
+    }
+
+    /// Kills the server and waits for it to exit.
+    pub(crate) async fn kill(&self) -> ArcResult<()> {
+        let mut child = match self.process.lock().unwrap().take() {
+            Some(x) => x,
+            None => return Ok(())
+        };
+        child.kill().map_err(Error::Io)?;
+        unblock(move || child.wait()).await?.map_err(Error::Io)?;
+        Ok(())
+    }
+}
+
+impl Drop for BrowserServer {
+    fn drop(&mut self) {
+        if let Some(mut child) = self.process.get_mut().ok().and_then(Option::take) {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+#[cfg(unix)]
+async fn terminate(mut child: Child) -> ArcResult<()> {
+    // The server closes browsers gracefully on SIGTERM
+    let pid = child.id() as libc::pid_t;
+    if unsafe { libc::kill(pid, libc::SIGTERM) } != 0 {
+        return Err(Error::Io(std::io::Error::last_os_error()).into());
+    }
+    unblock(move || child.wait()).await?.map_err(Error::Io)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn terminate(mut child: Child) -> ArcResult<()> {
+    child.kill().map_err(Error::Io)?;
+    unblock(move || child.wait()).await?.map_err(Error::Io)?;
+    Ok(())
+}
+
+/// `launch-server` takes its options from a json file
+struct ConfigFile(PathBuf);
+
+impl ConfigFile {
+    fn new(args: &LaunchServerArgs<'_, '_, '_, '_>) -> Result<Self, Error> {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let name = format!(
+            "playwright-rust-launch-server-{}-{}.json",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = env::temp_dir().join(name);
+        fs::write(&path, serde_json::to_vec(args)?)?;
+        Ok(Self(path))
+    }
+}
+
+impl Drop for ConfigFile {
+    fn drop(&mut self) { let _ = fs::remove_file(&self.0); }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LaunchServerArgs<'a, 'b, 'c, 'd> {
+    #[serde(flatten)]
+    pub(crate) launch: LaunchArgs<'a, 'b, 'c>,
+    pub(crate) port: Option<u16>,
+    pub(crate) ws_path: Option<&'d str>
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn launch_server_args() {
+        let args = LaunchServerArgs {
+            launch: LaunchArgs {
+                headless: Some(true),
+                ..LaunchArgs::default()
+            },
+            port: Some(8080),
+            ws_path: Some("/ws")
+        };
+        let v = serde_json::to_value(&args).unwrap();
+        assert_eq!(
+            v,
+            serde_json::json!({"headless": true, "port": 8080, "wsPath": "/ws"})
+        );
+    }
+}
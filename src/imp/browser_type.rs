@@ -2,10 +2,14 @@ use crate::{
     imp::{
         browser::Browser,
         browser_context::BrowserContext,
+        browser_server::{BrowserServer, LaunchServerArgs},
         core::*,
+        har_router::is_zip,
+        playwright::Playwright,
         prelude::*,
         utils::{
-            BrowserChannel, ColorScheme, Geolocation, HttpCredentials, ProxySettings, Viewport
+            BrowserChannel, ColorScheme, Geolocation, HarContentPolicy, HarMode, HttpCredentials,
+            ProxySettings, UrlMatcher, Viewport
         }
     },
     protocol::generated::browser_type as protocol
@@ -49,9 +53,16 @@ impl BrowserType {
         &self,
         args: LaunchPersistentContextArgs<'_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>
     ) -> Result<Weak<BrowserContext>, Arc<Error>> {
+        let har = args
+            .record_har
+            .as_ref()
+            .map(|h| (h.path.to_owned(), h.content()));
         let res = send_message!(self, "launchPersistentContext", args);
         let guid = only_guid(&res)?;
         let b = get_object!(self.context()?.lock().unwrap(), guid, BrowserContext)?;
+        if let Some((path, content)) = har {
+            upgrade(&b)?.add_har_recorder(None, path, content);
+        }
         Ok(b)
     }
 
@@ -83,8 +94,49 @@ impl BrowserType {
         Ok(browser)
     }
 
-    pub(crate) async fn connect(&self, args: ConnectArgs<'_>) -> ArcResult<Weak<Browser>> {
-        todo!()
+    pub(crate) async fn launch_server(
+        &self,
+        args: LaunchServerArgs<'_, '_, '_, '_>
+    ) -> ArcResult<BrowserServer> {
+        let driver = self
+            .context()?
+            .lock()
+            .unwrap()
+            .driver()
+            .map(Path::to_owned)
+            .ok_or(Error::RemoteLaunchServer)?;
+        BrowserServer::launch(&driver, &self.name, args).await
+    }
+
+    /// Returns the connection which owns the browser.
+    pub(crate) async fn connect(
+        &self,
+        args: ConnectArgs<'_>
+    ) -> ArcResult<(Connection, Weak<Browser>)> {
+        let ConnectArgs {
+            ws_endpoint,
+            headers,
+            timeout,
+            slowmo
+        } = args;
+        let mut headers = headers.unwrap_or_default();
+        headers.insert("x-playwright-browser".into(), self.name.clone());
+        let timeout = match timeout {
+            Some(t) if t <= 0. => None,
+            t => Some(Duration::from_secs_f64(t.unwrap_or(30000.) / 1000.))
+        };
+        let slow_mo = slowmo
+            .filter(|x| *x > 0.)
+            .map(|x| Duration::from_secs_f64(x / 1000.));
+        let call_timeout = self.context()?.lock().unwrap().call_timeout();
+        let conn = Connection::connect(ws_endpoint, headers, timeout, slow_mo).await?;
+        conn.set_call_timeout(call_timeout);
+        let playwright = Playwright::wait_initial_object(&conn).await?;
+        let browser = upgrade(&playwright)?
+            .pre_launched_browser()
+            .ok_or(Error::InitializationError)?;
+        upgrade(&browser)?.set_is_remote_true();
+        Ok((conn, browser))
     }
 }
 
@@ -195,12 +247,72 @@ pub struct RecordVideo<'a> {
     pub size: Option<Viewport>
 }
 
-#[skip_serializing_none]
-#[derive(Debug, Serialize, Clone)]
-#[serde(rename_all = "camelCase")]
+/// Records the traffic of a context into a HAR, which is written when the context is closed.
+/// A `.zip` path stores the HAR and, with [`HarContentPolicy::Attach`], each body as a separate entry of the zip.
+#[derive(Debug, Clone)]
 pub struct RecordHar<'a> {
     pub path: &'a Path,
-    pub omit_content: Option<bool>
+    /// Same as `content: Some(HarContentPolicy::Omit)`, which takes precedence
+    pub omit_content: Option<bool>,
+    /// Defaults to [`HarContentPolicy::Attach`] for `.zip` and [`HarContentPolicy::Embed`] otherwise
+    pub content: Option<HarContentPolicy>,
+    /// Defaults to [`HarMode::Full`]
+    pub mode: Option<HarMode>,
+    /// Only requests whose URL matches the glob or regex are recorded. A predicate is not supported and records all.
+    pub url_filter: Option<UrlMatcher>
+}
+
+impl<'a> RecordHar<'a> {
+    pub fn new(path: &'a Path) -> Self {
+        Self {
+            path,
+            omit_content: None,
+            content: None,
+            mode: None,
+            url_filter: None
+        }
+    }
+
+    pub(crate) fn content(&self) -> HarContentPolicy {
+        match (self.omit_content, self.content) {
+            (Some(true), _) => HarContentPolicy::Omit,
+            (_, Some(c)) => c,
+            _ if is_zip(self.path) => HarContentPolicy::Attach,
+            _ => HarContentPolicy::Embed
+        }
+    }
+}
+
+impl Serialize for RecordHar<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer
+    {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Options<'a> {
+            path: &'a Path,
+            content: HarContentPolicy,
+            mode: Option<HarMode>,
+            url_glob: Option<&'a str>,
+            url_regex_source: Option<&'a str>
+        }
+        Options {
+            path: self.path,
+            content: self.content(),
+            mode: self.mode,
+            url_glob: match &self.url_filter {
+                Some(UrlMatcher::Glob(g)) => Some(g.as_str()),
+                _ => None
+            },
+            url_regex_source: match &self.url_filter {
+                Some(UrlMatcher::Regex(r)) => Some(r.as_str()),
+                _ => None
+            }
+        }
+        .serialize(serializer)
+    }
 }
 
 impl<'a> LaunchPersistentContextArgs<'a, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_> {
@@ -249,13 +361,12 @@ impl<'a> LaunchPersistentContextArgs<'a, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>
     }
 }
 
-#[skip_serializing_none]
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
+/// Not sent to the driver but used to open a connection to the server.
+#[derive(Debug)]
 pub(crate) struct ConnectArgs<'a> {
     ws_endpoint: &'a str,
+    pub(crate) headers: Option<HashMap<String, String>>,
     pub(crate) timeout: Option<f64>,
-    #[serde(rename = "slowMo")]
     pub(crate) slowmo: Option<f64>
 }
 
@@ -263,6 +374,7 @@ impl<'a> ConnectArgs<'a> {
     pub(crate) fn new(ws_endpoint: &'a str) -> Self {
         Self {
             ws_endpoint,
+            headers: None,
             timeout: None,
             slowmo: None
         }
@@ -327,4 +439,32 @@ mod tests {
             }
         }
     });
+
+    #[test]
+    fn record_har() {
+        let har = RecordHar {
+            omit_content: Some(true),
+            content: Some(HarContentPolicy::Attach),
+            url_filter: Some("**/api/**".into()),
+            ..RecordHar::new(Path::new("a.har"))
+        };
+        assert_eq!(
+            to_value(&har).unwrap(),
+            serde_json::json!({ "path": "a.har", "content": "omit", "urlGlob": "**/api/**" })
+        );
+        let har = RecordHar {
+            mode: Some(HarMode::Minimal),
+            url_filter: Some(regex::Regex::new("api$").unwrap().into()),
+            ..RecordHar::new(Path::new("a.zip"))
+        };
+        assert_eq!(
+            to_value(&har).unwrap(),
+            serde_json::json!({
+                "path": "a.zip",
+                "content": "attach",
+                "mode": "minimal",
+                "urlRegexSource": "api$"
+            })
+        );
+    }
 }
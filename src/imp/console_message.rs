@@ -1,11 +1,12 @@
-use crate::imp::{core::*, js_handle::JsHandle, prelude::*, utils::SourceLocation};
+use crate::imp::{core::*, frame::Handle, prelude::*, utils::SourceLocation};
 
 #[derive(Debug)]
 pub(crate) struct ConsoleMessage {
     channel: ChannelOwner,
     location: SourceLocation,
     text: String,
-    message_type: String,
+    message_type: ConsoleType,
+    args: Vec<Handle>
 }
 
 impl ConsoleMessage {
@@ -14,24 +15,34 @@ impl ConsoleMessage {
         struct De {
             location: SourceLocation,
             text: String,
-            r#type: String,
+            r#type: ConsoleType,
+            #[serde(default)]
+            args: Vec<OnlyGuid>
         }
-        let De { location, text, r#type } = serde_json::from_value(channel.initializer.clone())?;
+        let De {
+            location,
+            text,
+            r#type,
+            args
+        } = serde_json::from_value(channel.initializer.clone())?;
+        let args = args
+            .iter()
+            .map(|OnlyGuid { guid }| {
+                get_object!(ctx, guid, ElementHandle)
+                    .map(Handle::Element)
+                    .or_else(|_| get_object!(ctx, guid, JsHandle).map(Handle::Js))
+            })
+            .collect::<Result<_, _>>()?;
         Ok(Self {
             channel,
             location,
             text,
-            message_type: r#type
+            message_type: r#type,
+            args
         })
     }
 
-    pub(crate) fn r#type(&self) -> &str {
-        self.channel()
-            .initializer
-            .get("type")
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
-    }
+    pub(crate) fn r#type(&self) -> ConsoleType { self.message_type }
 
     pub(crate) fn text(&self) -> &str {
         &self.text
@@ -39,12 +50,36 @@ impl ConsoleMessage {
 
     pub(crate) fn location(&self) -> &SourceLocation { &self.location }
 
-    pub fn message_type(&self) -> &str {
-        &self.message_type
-    }
+    pub(crate) fn args(&self) -> &[Handle] { &self.args }
 }
 
 impl RemoteObject for ConsoleMessage {
     fn channel(&self) -> &ChannelOwner { &self.channel }
     fn channel_mut(&mut self) -> &mut ChannelOwner { &mut self.channel }
 }
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ConsoleType {
+    Log,
+    Debug,
+    Info,
+    Error,
+    Warning,
+    Dir,
+    Dirxml,
+    Table,
+    Trace,
+    Clear,
+    StartGroup,
+    StartGroupCollapsed,
+    EndGroup,
+    Assert,
+    Profile,
+    ProfileEnd,
+    Count,
+    TimeEnd,
+    /// A type which this crate does not know yet
+    #[serde(other)]
+    Other
+}
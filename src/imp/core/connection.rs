@@ -1,14 +1,9 @@
 use crate::imp::{core::*, prelude::*};
 use std::{
     io,
-    process::{Child, Command, Stdio},
-    sync::{
-        atomic::{AtomicBool, Ordering},
-        TryLockError
-    }
+    process::{ChildStdout, ExitStatus},
+    sync::atomic::{AtomicBool, Ordering}
 };
-#[cfg(target_os = "windows")]
-use std::os::windows::process::CommandExt;
 
 #[derive(Debug)]
 pub(crate) struct Context {
@@ -16,14 +11,22 @@ pub(crate) struct Context {
     ctx: Wm<Context>,
     id: i32,
     callbacks: HashMap<i32, WaitPlaces<WaitMessageResult>>,
-    writer: Writer
+    writer: Box<dyn Writer>,
+    /// Delays every request to the remote server
+    slow_mo: Option<Duration>,
+    /// Deadline of each request
+    call_timeout: Option<Duration>,
+    /// The executable of `playwright run-driver`. Missing for remote connections.
+    driver: Option<PathBuf>,
+    /// Requests from event handlers, which only borrow the context.
+    /// They are sent after the event is handled and nobody waits for the results.
+    deferred: Mutex<Vec<RequestBody>>
 }
 
 #[derive(Debug)]
 pub(crate) struct Connection {
-    _child: Child,
+    process: Option<Arc<DriverProcess>>,
     ctx: Am<Context>,
-    reader: Am<Reader>,
     should_stop: Arc<AtomicBool>
 }
 
@@ -35,6 +38,12 @@ pub enum Error {
     InitializationError,
     #[error("Disconnected")]
     ReceiverClosed,
+    #[error("Driver exited unexpectedly with {status:?}\n{stderr_tail}")]
+    DriverExited {
+        status: ExitStatus,
+        /// The last lines of stderr
+        stderr_tail: String
+    },
     #[error("Invalid message")]
     InvalidParams,
     #[error("Object not found")]
@@ -71,63 +80,146 @@ pub enum Error {
     ResolvePath(PathBuf),
     #[error("Timed out")]
     Timeout,
+    #[error("Timed out waiting for the response of {method} on {guid:?}")]
+    CallTimeout { guid: String, method: String },
+    #[error("Function {0:?} has been already registered")]
+    FunctionAlreadyRegistered(String),
+    #[error("Inner \"has\" locator must belong to the same frame")]
+    LocatorFrameMismatch,
+    #[error("{0}")]
+    AssertionFailed(String),
+    #[error("Response has been disposed")]
+    ResponseDisposed,
+    #[error("Browser server exited before listening")]
+    BrowserServerExited,
+    #[error("Launching a server is not available when using BrowserType.connect()")]
+    RemoteLaunchServer,
+    #[error("Failed to open HAR: {0}")]
+    HarOpen(String),
+    #[error(transparent)]
+    Zip(#[from] zip::result::ZipError),
+    #[error("Invalid page ranges {0:?}")]
+    InvalidPageRanges(String),
     #[error(transparent)]
     Join(#[from] JoinError)
 }
 
 pub(crate) type ArcResult<T> = Result<T, Arc<Error>>;
 
+impl Error {
+    /// The error which the driver responded with, looking through [`Error::Arc`]
+    pub fn error_message(&self) -> Option<&ErrorMessage> {
+        match self {
+            Self::ErrorResponded(e) => Some(e),
+            Self::Arc(e) => e.error_message(),
+            _ => None
+        }
+    }
+
+    /// Kind of the error which the driver responded with
+    pub fn kind(&self) -> Option<ErrorKind> { self.error_message().map(ErrorMessage::kind) }
+
+    /// A `TimeoutError` of the driver, or [`Error::Timeout`] and [`Error::CallTimeout`] on the client side
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            Self::Timeout | Self::CallTimeout { .. } => true,
+            Self::Arc(e) => e.is_timeout(),
+            _ => matches!(self.kind(), Some(ErrorKind::Timeout { .. }))
+        }
+    }
+
+    pub fn is_target_closed(&self) -> bool { matches!(self.kind(), Some(ErrorKind::TargetClosed)) }
+
+    pub fn is_navigation_error(&self) -> bool {
+        matches!(self.kind(), Some(ErrorKind::Navigation { .. }))
+    }
+
+    pub fn is_strict_mode_violation(&self) -> bool {
+        matches!(self.kind(), Some(ErrorKind::StrictModeViolation { .. }))
+    }
+
+    pub fn is_selector_error(&self) -> bool { matches!(self.kind(), Some(ErrorKind::Selector)) }
+}
+
 impl Drop for Connection {
     fn drop(&mut self) {
         self.notify_closed(Error::ReceiverClosed);
         self.should_stop.store(true, Ordering::Relaxed);
+        if let Ok(mut ctx) = self.ctx.lock() {
+            ctx.writer.close();
+        }
+        // Nothing is left to kill after close()
+        if let Some(p) = &self.process {
+            p.kill();
+        }
     }
 }
 
 impl Connection {
-    fn try_new(exec: &Path) -> io::Result<Connection> {
-        let mut command = Command::new(exec);
-        let mut child = command
-            .args(&["run-driver"])
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::inherit());
-        #[cfg(target_os = "windows")]
-        child.creation_flags(0x08000000);    
-
-        let mut child = child.spawn()?;
-
-        // TODO: env "NODE_OPTIONS"
-        let stdin = child.stdin.take().unwrap();
-        let stdout = child.stdout.take().unwrap();
-        let reader = Reader::new(stdout);
-        let writer = Writer::new(stdin);
-        let ctx = Context::new(writer);
-        Ok(Self {
-            _child: child,
+    const EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn try_new(exec: &Path) -> io::Result<(Connection, PipeReader<ChildStdout>)> {
+        let (process, stdin, stdout) = DriverProcess::spawn(exec)?;
+        let reader = PipeReader::new(stdout);
+        let writer = PipeWriter::new(stdin);
+        let conn = Self::with_transport(
+            Some(Arc::new(process)),
+            Box::new(writer),
+            None,
+            Some(exec.to_owned())
+        );
+        Ok((conn, reader))
+    }
+
+    fn with_transport(
+        process: Option<Arc<DriverProcess>>,
+        writer: Box<dyn Writer>,
+        slow_mo: Option<Duration>,
+        driver: Option<PathBuf>
+    ) -> Self {
+        let ctx = Context::new(writer, slow_mo, driver);
+        Self {
+            process,
             ctx,
-            should_stop: Arc::new(false.into()),
-            reader: Arc::new(Mutex::new(reader))
-        })
+            should_stop: Arc::new(false.into())
+        }
     }
 
     pub(crate) fn run(exec: &Path) -> io::Result<Connection> {
-        let conn = Self::try_new(exec)?;
-        conn.start();
+        let (conn, reader) = Self::try_new(exec)?;
+        conn.start(Box::new(reader));
         Ok(conn)
     }
 
-    fn start(&self) {
+    /// Connects to `playwright run-server` or a server launched by `BrowserType.launchServer()`
+    pub(crate) async fn connect(
+        ws_endpoint: &str,
+        headers: HashMap<String, String>,
+        timeout: Option<Duration>,
+        slow_mo: Option<Duration>
+    ) -> Result<Connection, Error> {
+        let ws_endpoint = ws_endpoint.to_owned();
+        let (reader, writer) =
+            unblock(move || connect_web_socket(&ws_endpoint, &headers, timeout)).await??;
+        let conn = Self::with_transport(None, Box::new(writer), slow_mo, None);
+        conn.start(Box::new(reader));
+        Ok(conn)
+    }
+
+    /// Reads on a dedicated thread which sleeps until the next message arrives.
+    /// The thread exits when the connection is dropped and the writer closes the transport.
+    fn start(&self, mut reader: Box<dyn Reader>) {
         let c2 = Arc::downgrade(&self.ctx);
-        let r2 = Arc::downgrade(&self.reader);
         let s2 = Arc::downgrade(&self.should_stop);
+        let p2 = self.process.clone();
         std::thread::spawn(move || {
             let c = c2;
-            let r = r2;
             let s = s2;
+            let p = p2;
             log::trace!("success starting connection");
             let status = (|| -> Result<(), Error> {
                 loop {
+                    let response = reader.read()?;
                     {
                         let s = match s.upgrade() {
                             Some(x) => x,
@@ -138,21 +230,6 @@ impl Connection {
                             break;
                         }
                     }
-                    let response = {
-                        let r = match r.upgrade() {
-                            Some(x) => x,
-                            None => break
-                        };
-                        let mut reader = match r.try_lock() {
-                            Ok(x) => x,
-                            Err(TryLockError::WouldBlock) => continue,
-                            Err(e) => Err(e).unwrap()
-                        };
-                        match reader.try_read()? {
-                            Some(x) => x,
-                            None => continue
-                        }
-                    };
                     // dispatch
                     {
                         let c = match c.upgrade() {
@@ -168,6 +245,13 @@ impl Connection {
             })();
             if let Err(e) = status {
                 log::trace!("Failed with {:?}", e);
+                let stopping = s.upgrade().map_or(true, |s| s.load(Ordering::Relaxed));
+                let e = match (&e, p) {
+                    (Error::Transport(_), Some(p)) if !stopping => {
+                        p.exit_error(Duration::from_secs(1)).unwrap_or(e)
+                    }
+                    _ => e
+                };
                 if let Some(c) = c.upgrade() {
                     let mut ctx = c.lock().unwrap();
                     ctx.notify_closed(e);
@@ -180,6 +264,38 @@ impl Connection {
 
     pub(crate) fn context(&self) -> Wm<Context> { Arc::downgrade(&self.ctx) }
 
+    pub(crate) fn set_call_timeout(&self, timeout: Option<Duration>) {
+        self.ctx.lock().unwrap().set_call_timeout(timeout);
+    }
+
+    /// Closes browsers, lets the driver exit by closing its stdin and reaps it.
+    /// The process group is killed if the driver does not exit in time.
+    pub(crate) async fn close(&self) -> ArcResult<()> {
+        let browsers = self.ctx.lock().unwrap().browsers();
+        for b in browsers {
+            if let Some(b) = b.upgrade() {
+                if let Err(e) = b.close().await {
+                    log::debug!("Failed to close browser {:?}", e);
+                }
+            }
+        }
+        self.should_stop.store(true, Ordering::Relaxed);
+        self.ctx.lock().unwrap().writer.close();
+        if let Some(p) = self.process.clone() {
+            unblock(move || {
+                if p.wait_timeout(Self::EXIT_TIMEOUT).is_none() {
+                    p.kill();
+                }
+            })
+            .await?;
+        }
+        self.ctx
+            .lock()
+            .unwrap()
+            .notify_closed(Error::ReceiverClosed);
+        Ok(())
+    }
+
     fn notify_closed(&mut self, e: Error) {
         let ctx = &mut self.ctx.lock().unwrap();
         ctx.notify_closed(e);
@@ -187,7 +303,11 @@ impl Connection {
 }
 
 impl Context {
-    fn new(writer: Writer) -> Am<Context> {
+    fn new(
+        writer: Box<dyn Writer>,
+        slow_mo: Option<Duration>,
+        driver: Option<PathBuf>
+    ) -> Am<Context> {
         Arc::new_cyclic(|w| {
             let objects = {
                 let mut d = HashMap::new();
@@ -200,15 +320,19 @@ impl Context {
                 ctx: w.clone(),
                 id: 0,
                 callbacks: HashMap::new(),
-                writer
+                writer,
+                slow_mo,
+                call_timeout: None,
+                driver,
+                deferred: Mutex::default()
             })
         })
     }
 
     fn notify_closed(&mut self, e: Error) {
         let err = Arc::new(e);
-        for p in self.callbacks.iter().map(|(_, v)| v) {
-            Context::respond_wait(p, Err(err.clone()));
+        for (_, p) in std::mem::take(&mut self.callbacks) {
+            Context::respond_wait(&p, Err(err.clone()));
         }
         self.objects = HashMap::new();
     }
@@ -216,8 +340,10 @@ impl Context {
     fn dispatch(&mut self, msg: Res) -> Result<(), Error> {
         match msg {
             Res::Result(msg) => {
-                let p = self.callbacks.get(&msg.id).ok_or(Error::CallbackNotFound)?;
-                Self::respond_wait(p, Ok(msg.body.map(Arc::new).map_err(Arc::new)));
+                // Missing if the caller has stopped waiting
+                if let Some(p) = self.callbacks.remove(&msg.id) {
+                    Self::respond_wait(&p, Ok(msg.body.map(Arc::new).map_err(Arc::new)));
+                }
                 return Ok(());
             }
             Res::Initial(msg) => {
@@ -233,11 +359,30 @@ impl Context {
                 let target = self.objects.get(&msg.guid).ok_or(Error::ObjectNotFound)?;
                 let ResInitial { method, params, .. } = msg;
                 target.handle_event(self, method, params)?;
+                for r in std::mem::take(self.deferred.get_mut().unwrap()) {
+                    self.send_message(r)?;
+                }
             }
         }
         Ok(())
     }
 
+    pub(crate) fn slow_mo(&self) -> Option<Duration> { self.slow_mo }
+
+    pub(crate) fn call_timeout(&self) -> Option<Duration> { self.call_timeout }
+
+    pub(crate) fn set_call_timeout(&mut self, timeout: Option<Duration>) {
+        self.call_timeout = timeout;
+    }
+
+    pub(in crate::imp::core) fn remove_callback(&mut self, id: i32) { self.callbacks.remove(&id); }
+
+    pub(crate) fn driver(&self) -> Option<&Path> { self.driver.as_deref() }
+
+    pub(crate) fn send_message_later(&self, r: RequestBody) {
+        self.deferred.lock().unwrap().push(r);
+    }
+
     fn dispose(&mut self, i: &S<Guid>) {
         let a = match self.objects.get(i) {
             None => return,
@@ -308,13 +453,31 @@ impl Context {
         Ok(())
     }
 
+    fn browsers(&self) -> Vec<Weak<crate::imp::browser::Browser>> {
+        self.objects
+            .values()
+            .filter_map(|r| match r {
+                RemoteArc::Browser(b) => Some(Arc::downgrade(b)),
+                _ => None
+            })
+            .collect()
+    }
+
+    pub(in crate::imp) fn local_utils(&self) -> Option<Weak<crate::imp::local_utils::LocalUtils>> {
+        self.objects.values().find_map(|r| match r {
+            RemoteArc::LocalUtils(x) => Some(Arc::downgrade(x)),
+            _ => None
+        })
+    }
+
     pub(in crate::imp) fn find_object(&self, k: &S<Guid>) -> Option<RemoteWeak> {
         self.objects.get(k).map(|r| r.downgrade())
     }
 
     pub(in crate::imp) fn remove_object(&mut self, k: &S<Guid>) { self.objects.remove(k); }
 
-    pub(in crate::imp::core) fn send_message(&mut self, r: RequestBody) -> Result<(), Error> {
+    /// Returns the id of the request
+    pub(in crate::imp::core) fn send_message(&mut self, r: RequestBody) -> Result<i32, Error> {
         self.id += 1;
         let RequestBody {
             guid,
@@ -331,18 +494,206 @@ impl Context {
             metadata,
             id: self.id
         };
-        self.writer.send(&req)?;
-        Ok(())
+        if let Err(e) = self.writer.send(&req) {
+            self.callbacks.remove(&self.id);
+            return Err(e.into());
+        }
+        Ok(self.id)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::imp::core::*;
+    use super::*;
+    use crate::send_message;
+    use std::sync::mpsc;
+
+    /// Responds to every request unless `respond` is false
+    #[derive(Debug)]
+    struct FakeDriver {
+        tx: mpsc::Sender<Res>,
+        respond: bool
+    }
+
+    #[derive(Debug)]
+    struct FakeReader(mpsc::Receiver<Res>);
+
+    impl Writer for FakeDriver {
+        fn send(&mut self, req: &Req<'_, '_>) -> Result<(), TransportError> {
+            if self.respond {
+                let body = Ok(serde_json::json!({ "value": "" }));
+                let _ = self.tx.send(Res::Result(ResResult { id: req.id, body }));
+            }
+            Ok(())
+        }
+    }
+
+    impl Reader for FakeReader {
+        fn read(&mut self) -> Result<Res, TransportError> {
+            self.0
+                .recv()
+                .map_err(|_| io::Error::from(io::ErrorKind::UnexpectedEof).into())
+        }
+    }
+
+    fn fake(respond: bool) -> (Connection, Arc<RootObject>) {
+        let (tx, rx) = mpsc::channel();
+        let conn =
+            Connection::with_transport(None, Box::new(FakeDriver { tx, respond }), None, None);
+        conn.start(Box::new(FakeReader(rx)));
+        let root = root(&conn);
+        (conn, root)
+    }
+
+    fn root(conn: &Connection) -> Arc<RootObject> {
+        match conn
+            .ctx
+            .lock()
+            .unwrap()
+            .find_object(&S::validate("").unwrap())
+        {
+            Some(RemoteWeak::Root(x)) => x.upgrade().unwrap(),
+            _ => unreachable!()
+        }
+    }
+
+    async fn title(root: &RootObject) -> ArcResult<Arc<Value>> {
+        Ok(send_message!(root, "title", Map::new()))
+    }
+
+    fn callbacks(conn: &Connection) -> usize { conn.ctx.lock().unwrap().callbacks.len() }
+
+    crate::runtime_test!(callbacks_are_removed_on_response, {
+        let (conn, root) = fake(true);
+        for _ in 0..3000 {
+            title(&root).await.unwrap();
+        }
+        assert_eq!(callbacks(&conn), 0);
+    });
+
+    crate::runtime_test!(callbacks_are_removed_on_drop, {
+        let (conn, root) = fake(false);
+        let m = S::validate("title").unwrap().to_owned();
+        let wait = root
+            .channel()
+            .send_message(root.channel().create_request(m))
+            .await
+            .unwrap();
+        assert_eq!(callbacks(&conn), 1);
+        drop(wait);
+        assert_eq!(callbacks(&conn), 0);
+    });
+
+    crate::runtime_test!(callbacks_are_removed_on_disconnect, {
+        let (conn, root) = fake(false);
+        let m = S::validate("title").unwrap().to_owned();
+        let wait = root
+            .channel()
+            .send_message(root.channel().create_request(m))
+            .await
+            .unwrap();
+        conn.ctx
+            .lock()
+            .unwrap()
+            .notify_closed(Error::ReceiverClosed);
+        assert_eq!(callbacks(&conn), 0);
+        assert!(matches!(*wait.await.unwrap_err(), Error::ReceiverClosed));
+    });
+
+    crate::runtime_test!(call_timeout, {
+        let (conn, root) = fake(false);
+        conn.set_call_timeout(Some(Duration::from_millis(10)));
+        let err = title(&root).await.unwrap_err();
+        match &*err {
+            Error::CallTimeout { guid, method } => {
+                assert_eq!(guid, "");
+                assert_eq!(method, "title");
+            }
+            e => panic!("{:?}", e)
+        }
+        assert_eq!(callbacks(&conn), 0);
+    });
 
     crate::runtime_test!(start, {
         let driver = Driver::install().unwrap();
-        let conn = Connection::try_new(&driver.executable()).unwrap();
-        Connection::start(&conn);
+        let (conn, reader) = Connection::try_new(&driver.executable()).unwrap();
+        Connection::start(&conn, Box::new(reader));
+    });
+
+    /// Writes a shell script which pretends to be a driver
+    #[cfg(unix)]
+    fn script(name: &str, body: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let path = std::env::temp_dir().join(format!(
+            "playwright-rust-{}-{}.sh",
+            name,
+            std::process::id()
+        ));
+        std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[cfg(unix)]
+    crate::runtime_test!(driver_exited, {
+        let exec = script("exited", "head -c 1 >/dev/null; echo boom >&2; exit 3");
+        let conn = Connection::run(&exec).unwrap();
+        let err = title(&root(&conn)).await.unwrap_err();
+        match &*err {
+            Error::DriverExited {
+                status,
+                stderr_tail
+            } => {
+                assert_eq!(status.code(), Some(3));
+                assert_eq!(stderr_tail, "boom");
+            }
+            e => panic!("{:?}", e)
+        }
+    });
+
+    #[cfg(unix)]
+    crate::runtime_test!(close_reaps_driver, {
+        let exec = script("close", "cat >/dev/null");
+        let conn = Connection::run(&exec).unwrap();
+        let root = root(&conn);
+        conn.close().await.unwrap();
+        let process = conn.process.as_ref().unwrap();
+        assert!(process.wait_timeout(Duration::ZERO).is_none());
+        assert!(process.exit_error(Duration::ZERO).is_none());
+        assert!(title(&root).await.is_err());
+    });
+
+    #[cfg(target_os = "linux")]
+    crate::runtime_test!(drop_kills_process_group, {
+        let pid_file =
+            std::env::temp_dir().join(format!("playwright-rust-grandchild-{}", std::process::id()));
+        let exec = script(
+            "drop",
+            &format!("sleep 100 & echo $! > {}; wait", pid_file.display())
+        );
+        let conn = Connection::run(&exec).unwrap();
+        let mut pid = String::new();
+        for _ in 0..100 {
+            pid = std::fs::read_to_string(&pid_file).unwrap_or_default();
+            if pid.ends_with('\n') {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        drop(conn);
+        let stat = format!("/proc/{}/stat", pid.trim());
+        let mut alive = true;
+        for _ in 0..100 {
+            alive = match std::fs::read_to_string(&stat) {
+                Ok(s) => !s.contains(") Z "),
+                Err(_) => false
+            };
+            if !alive {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        let _ = std::fs::remove_file(&pid_file);
+        assert!(!alive);
     });
 }
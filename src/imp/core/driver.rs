@@ -1,5 +1,7 @@
 use crate::imp::prelude::*;
-use std::{env, fmt, fs, io, str::FromStr};
+use fs2::FileExt;
+use sha2::{Digest, Sha256};
+use std::{env, fmt, fs, io, path::Path, process, str::FromStr};
 use zip::{result::ZipError, ZipArchive};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -10,8 +12,22 @@ pub struct Driver {
 impl Driver {
     const ZIP: &'static [u8] = include_bytes!(concat!(env!("OUT_DIR"), env!("SEP"), "driver.zip"));
     const PLATFORM: &'static str = include_str!(concat!(env!("OUT_DIR"), env!("SEP"), "platform"));
+    const SHA256: &'static str =
+        include_str!(concat!(env!("OUT_DIR"), env!("SEP"), "driver.sha256"));
+    const VERSION: &'static str = env!("DRIVER_VERSION");
+    const LOCK: &'static str = ".lock";
 
+    /// Name of the environment variable of the directory of an extracted driver
+    pub const DIR_ENV: &'static str = "PLAYWRIGHT_DRIVER_DIR";
+
+    /// Extracts the embedded driver into [`Driver::default_dest`] if missing.
+    /// The directory is specific to the driver version and the checksum of the zip,
+    /// so a driver of another version is never reused.
+    /// Uses [`Driver::from_env`] instead if `PLAYWRIGHT_DRIVER_DIR` is set or the driver is not embedded.
     pub fn install() -> io::Result<Self> {
+        if env::var_os(Self::DIR_ENV).is_some() || !Self::is_embedded() {
+            return Self::from_env();
+        }
         let this = Self::new(Self::default_dest());
         if !this.path.is_dir() {
             this.prepare()?;
@@ -21,14 +37,68 @@ impl Driver {
 
     /// Without prepare
     pub fn new<P: Into<PathBuf>>(path: P) -> Self { Self { path: path.into() } }
-    ///
+
+    /// Uses the driver at `PLAYWRIGHT_DRIVER_DIR`, which is looked up at runtime and then at build time.
+    pub fn from_env() -> io::Result<Self> {
+        let dir = env::var_os(Self::DIR_ENV)
+            .map(PathBuf::from)
+            .or_else(|| option_env!("PLAYWRIGHT_DRIVER_DIR").map(PathBuf::from))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!(
+                        "{} is not set and the driver is not embedded",
+                        Self::DIR_ENV
+                    )
+                )
+            })?;
+        Self::from_dir(dir)
+    }
+
+    /// Uses an extracted driver after checking that the directory looks like a driver.
+    pub fn from_dir<P: Into<PathBuf>>(path: P) -> io::Result<Self> {
+        let this = Self::new(path);
+        let invalid = |p: PathBuf| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "{} is not a playwright driver: {:?} is missing",
+                    this.path.display(),
+                    p
+                )
+            )
+        };
+        if !this.path.is_dir() {
+            return Err(invalid(this.path.clone()));
+        }
+        for p in [this.executable(), this.path.join("package").join("cli.js")].iter() {
+            if !p.is_file() {
+                return Err(invalid(p.clone()));
+            }
+        }
+        Ok(this)
+    }
+
+    /// Whether the driver zip is bundled into the binary
+    pub fn is_embedded() -> bool { !Self::ZIP.is_empty() }
+
+    /// Verifies the checksum of the embedded zip and extracts it into the path.
+    /// The zip is extracted into a temporary directory and renamed while holding a file lock
+    /// in the parent directory, so concurrent processes never see a partial driver.
+    /// Does nothing if the path already exists.
     pub fn prepare(&self) -> Result<(), ZipError> {
-        fs::create_dir_all(&self.path)?;
-        let mut a = ZipArchive::new(io::Cursor::new(Self::ZIP))?;
-        a.extract(&self.path)
+        if !Self::is_embedded() {
+            return Err(ZipError::FileNotFound);
+        }
+        extract(Self::ZIP, Self::SHA256, &self.path)
     }
 
+    /// `ms-playwright/playwright-rust/driver/<version>-<checksum>` in the cache directory
     pub fn default_dest() -> PathBuf {
+        Self::base_dir().join(format!("{}-{}", Self::VERSION, &Self::SHA256[..12]))
+    }
+
+    fn base_dir() -> PathBuf {
         let base: PathBuf = dirs::cache_dir().unwrap_or_else(env::temp_dir);
         let dir: PathBuf = [
             base.as_os_str(),
@@ -41,6 +111,15 @@ impl Driver {
         dir
     }
 
+    /// Removes drivers extracted by other versions of this crate next to [`Driver::default_dest`].
+    /// Returns the removed paths.
+    pub fn prune_old_versions() -> io::Result<Vec<PathBuf>> {
+        prune(&Self::base_dir(), &Self::default_dest())
+    }
+
+    /// Directory of the extracted driver
+    pub fn path(&self) -> &Path { &self.path }
+
     pub fn platform(&self) -> Platform { Platform::from_str(Self::PLATFORM).unwrap() }
 
     pub fn executable(&self) -> PathBuf {
@@ -54,6 +133,81 @@ impl Driver {
     }
 }
 
+fn extract(zip: &[u8], sha256: &str, dest: &Path) -> Result<(), ZipError> {
+    if dest.is_dir() {
+        return Ok(());
+    }
+    let actual = hex_sha256(zip);
+    if actual != sha256 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "checksum mismatch of the driver zip: expected {}, got {}",
+                sha256, actual
+            )
+        )
+        .into());
+    }
+    let parent = dest.parent().unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(parent)?;
+    let _lock = lock(parent)?;
+    if dest.is_dir() {
+        return Ok(());
+    }
+    let name = dest.file_name().unwrap_or_default().to_string_lossy();
+    let tmp = parent.join(format!(".tmp-{}-{}", process::id(), name));
+    if tmp.exists() {
+        fs::remove_dir_all(&tmp)?;
+    }
+    fs::create_dir(&tmp)?;
+    let extracted = ZipArchive::new(io::Cursor::new(zip))
+        .and_then(|mut a| a.extract(&tmp))
+        .and_then(|()| Ok(fs::rename(&tmp, dest)?));
+    if extracted.is_err() {
+        let _ = fs::remove_dir_all(&tmp);
+    }
+    extracted
+}
+
+fn prune(base: &Path, current: &Path) -> io::Result<Vec<PathBuf>> {
+    if !base.is_dir() {
+        return Ok(Vec::new());
+    }
+    let _lock = lock(base)?;
+    let mut removed = Vec::new();
+    for entry in fs::read_dir(base)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path == current || entry.file_name() == Driver::LOCK {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            fs::remove_dir_all(&path)?;
+        } else {
+            fs::remove_file(&path)?;
+        }
+        removed.push(path);
+    }
+    Ok(removed)
+}
+
+/// Unlocked when dropped
+fn lock(dir: &Path) -> io::Result<fs::File> {
+    let f = fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .open(dir.join(Driver::LOCK))?;
+    f.lock_exclusive()?;
+    Ok(f)
+}
+
+fn hex_sha256(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Platform {
     Mac,
@@ -101,4 +255,80 @@ mod tests {
 
     #[test]
     fn install() { let _driver = Driver::install().unwrap(); }
+
+    #[test]
+    fn from_dir() {
+        let tmp = tempdir::TempDir::new("playwright-rust-driver").unwrap();
+        let dir = tmp.path();
+        assert!(Driver::from_dir(dir.join("missing")).is_err());
+        assert!(Driver::from_dir(dir).is_err());
+        fs::write(Driver::new(dir).executable(), "").unwrap();
+        assert!(Driver::from_dir(dir).is_err());
+        fs::create_dir(dir.join("package")).unwrap();
+        fs::write(dir.join("package").join("cli.js"), "").unwrap();
+        assert_eq!(Driver::from_dir(dir).unwrap(), Driver::new(dir));
+    }
+
+    fn small_zip() -> Vec<u8> {
+        use std::io::Write;
+        let mut w = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+        w.start_file("playwright.sh", Default::default()).unwrap();
+        w.write_all(b"#!/bin/sh\n").unwrap();
+        w.add_directory("package/", Default::default()).unwrap();
+        w.start_file("package/cli.js", Default::default()).unwrap();
+        w.write_all(b"").unwrap();
+        w.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn extract_checks_checksum() {
+        let tmp = tempdir::TempDir::new("playwright-rust-driver").unwrap();
+        let dest = tmp.path().join("v1");
+        let zip = small_zip();
+        let err = extract(&zip, &hex_sha256(b"other"), &dest).unwrap_err();
+        assert!(matches!(err, ZipError::Io(e) if e.kind() == io::ErrorKind::InvalidData));
+        assert!(!dest.exists());
+        extract(&zip, &hex_sha256(&zip), &dest).unwrap();
+        assert!(dest.join("package").join("cli.js").is_file());
+    }
+
+    #[test]
+    fn extract_concurrently() {
+        let tmp = tempdir::TempDir::new("playwright-rust-driver").unwrap();
+        let dest = tmp.path().join("v1");
+        let zip = Arc::new(small_zip());
+        let threads = (0..8)
+            .map(|_| {
+                let (zip, dest) = (zip.clone(), dest.clone());
+                std::thread::spawn(move || extract(&zip, &hex_sha256(&zip), &dest))
+            })
+            .collect::<Vec<_>>();
+        for t in threads {
+            t.join().unwrap().unwrap();
+        }
+        assert!(dest.join("playwright.sh").is_file());
+        let rest = fs::read_dir(tmp.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .filter(|n| n != "v1" && n != Driver::LOCK)
+            .collect::<Vec<_>>();
+        assert!(rest.is_empty(), "{:?}", rest);
+    }
+
+    #[test]
+    fn prune() {
+        let tmp = tempdir::TempDir::new("playwright-rust-driver").unwrap();
+        let base = tmp.path();
+        let zip = small_zip();
+        for v in vec!["v0", "v1"] {
+            extract(&zip, &hex_sha256(&zip), &base.join(v)).unwrap();
+        }
+        // Left by versions which extracted into the base directory itself
+        fs::write(base.join("playwright.sh"), "").unwrap();
+        let mut removed = super::prune(base, &base.join("v1")).unwrap();
+        removed.sort();
+        assert_eq!(removed, vec![base.join("playwright.sh"), base.join("v0")]);
+        assert!(base.join("v1").is_dir());
+        assert!(super::prune(base, &base.join("v1")).unwrap().is_empty());
+    }
 }
@@ -1,5 +1,8 @@
 use crate::imp::{core::*, prelude::*};
-pub(crate) use tokio::sync::{broadcast, broadcast::error::TryRecvError};
+pub(crate) use tokio::sync::{
+    broadcast,
+    broadcast::error::{RecvError, TryRecvError}
+};
 
 pub trait EventEmitter {
     type Event: Clone;
@@ -36,57 +39,59 @@ pub(crate) trait IsEvent: Clone {
     fn event_type(&self) -> Self::EventType;
 }
 
-#[cfg(any(feature = "rt-tokio", feature = "rt-actix"))]
 pub(crate) async fn expect_event<E>(
-    mut rx: broadcast::Receiver<E>,
+    rx: broadcast::Receiver<E>,
     evt: E::EventType,
     timeout: u32
 ) -> Result<E, Error>
 where
-    E: IsEvent + Send + Sync + 'static,
-    <E as event_emitter::IsEvent>::EventType: Send + Sync
+    E: IsEvent
 {
-    consume(&mut rx).await?;
-    let sleep = sleep(Duration::from_millis(timeout as u64));
-    let event = spawn(async move {
-        loop {
-            match rx.recv().await {
-                Ok(x) if x.event_type() == evt => break Ok(x),
-                Ok(_) => continue,
-                Err(e) => break Err(e)
-            }
-        }
-    });
-    tokio::select! {
-        _ = sleep => Err(Error::Timeout),
-        x = event => x?.map_err(Error::Event)
-    }
+    wait_for_event(rx, evt, |_| true, timeout).await
 }
 
-#[cfg(feature = "rt-async-std")]
-pub(crate) async fn expect_event<E>(
+/// Waits for the first event of `evt` which satisfies `predicate`.
+/// Subscribe with `rx` before triggering the event so that it is not missed. `timeout` of `0` waits forever.
+pub(crate) async fn wait_for_event<E, P>(
     mut rx: broadcast::Receiver<E>,
     evt: E::EventType,
+    mut predicate: P,
     timeout: u32
 ) -> Result<E, Error>
 where
-    E: IsEvent + Send + Sync + 'static,
-    <E as event_emitter::IsEvent>::EventType: Send + Sync
+    E: IsEvent,
+    P: FnMut(&E) -> bool
 {
     consume(&mut rx).await?;
-    let sleep = sleep(Duration::from_millis(timeout as u64));
-    let event = spawn(async move {
+    let event = async move {
         loop {
             match rx.recv().await {
-                Ok(x) if x.event_type() == evt => break Ok(x),
+                Ok(x) if x.event_type() == evt && predicate(&x) => break Ok(x),
                 Ok(_) => continue,
-                Err(e) => break Err(e)
+                // Skipped events are missed, but the awaited one may still come
+                Err(RecvError::Lagged(_)) => continue,
+                Err(e) => break Err(Error::Event(e))
             }
         }
-    });
+    };
+    with_timeout(event, timeout).await
+}
+
+/// Fails with [`Error::Timeout`] unless `f` completes within `timeout` milliseconds. `0` waits forever.
+pub(crate) async fn with_timeout<F, T>(f: F, timeout: u32) -> Result<T, Error>
+where
+    F: Future<Output = Result<T, Error>>
+{
+    let deadline = async move {
+        if timeout == 0 {
+            futures::future::pending::<()>().await
+        } else {
+            sleep(Duration::from_millis(timeout as u64)).await
+        }
+    };
     tokio::select! {
-        _ = sleep => Err(Error::Timeout),
-        x = event => x.map_err(Error::Event)
+        _ = deadline => Err(Error::Timeout),
+        x = f => x
     }
 }
 
@@ -105,6 +110,52 @@ where
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Evt {
+        A(i32),
+        B
+    }
+
+    impl IsEvent for Evt {
+        type EventType = bool;
+
+        fn event_type(&self) -> bool { matches!(self, Self::A(_)) }
+    }
+
+    crate::runtime_test!(wait_for_event_with_predicate, {
+        let (tx, rx) = broadcast::channel(8);
+        let wait = wait_for_event(rx, true, |e| e == &Evt::A(2), 1000);
+        let emit = async {
+            for e in vec![Evt::A(1), Evt::B, Evt::A(2), Evt::A(3)] {
+                tx.send(e).unwrap();
+            }
+        };
+        let (e, ()) = futures::join!(wait, emit);
+        assert_eq!(e.unwrap(), Evt::A(2));
+    });
+
+    crate::runtime_test!(wait_for_event_after_lag, {
+        let (tx, rx) = broadcast::channel(2);
+        let wait = wait_for_event(rx, true, |e| e == &Evt::A(2), 1000);
+        let emit = async {
+            for _ in 0..10 {
+                tx.send(Evt::A(1)).unwrap();
+            }
+            tx.send(Evt::A(2)).unwrap();
+        };
+        let (e, ()) = futures::join!(wait, emit);
+        assert_eq!(e.unwrap(), Evt::A(2));
+    });
+
+    crate::runtime_test!(wait_for_event_timeout, {
+        let (tx, rx) = broadcast::channel(8);
+        tx.send(Evt::A(1)).unwrap();
+        let e = wait_for_event(rx, true, |_| true, 50).await;
+        assert!(matches!(e, Err(Error::Timeout)), "{:?}", e);
+    });
+
     crate::runtime_test!(select, {
         use crate::imp::prelude::*;
         let first = sleep(Duration::from_millis(200u64));
@@ -1,6 +1,9 @@
 pub(crate) mod de;
+mod error;
 pub(crate) mod ser;
 
+pub use error::{ErrorKind, ErrorMessage};
+
 use std::fmt::Debug;
 use crate::imp::core::Error;
 use serde::{Deserialize, Deserializer};
@@ -93,14 +96,6 @@ pub(crate) struct ErrorWrap {
     error: ErrorMessage
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, thiserror::Error)]
-#[error("{name} {message:?}")]
-pub struct ErrorMessage {
-    pub(crate) name: String,
-    pub(crate) message: String,
-    pub(crate) stack: String
-}
-
 #[derive(Debug, Deserialize, Serialize)]
 pub(crate) struct OnlyGuid {
     pub(crate) guid: Str<Guid>
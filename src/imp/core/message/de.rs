@@ -269,7 +269,9 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
                 _ => None
             });
         let n2 = v.as_null();
-        let _ = n1.or(n2).ok_or(Error::TypeMismatch)?;
+        // Arguments of a binding call without any argument
+        let n3 = v.as_array().filter(|a| a.is_empty()).map(|_| ());
+        let _ = n1.or(n2).or(n3).ok_or(Error::TypeMismatch)?;
         visitor.visit_unit()
     }
 
@@ -747,6 +749,15 @@ mod tests {
         assert_eq!(de, None);
     }
 
+    #[test]
+    fn args() {
+        let v = serde_json::from_str(r#"[{"n": 1}, {"s": "a"}]"#).unwrap();
+        let de: (i32, String) = from_value(&v).unwrap();
+        assert_eq!(de, (1, "a".into()));
+        let v = serde_json::from_str("[]").unwrap();
+        let _: () = from_value(&v).unwrap();
+    }
+
     #[test]
     fn r#enum() {
         env_logger::builder().is_test(true).try_init().ok();
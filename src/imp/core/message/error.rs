@@ -0,0 +1,238 @@
+use std::time::Duration;
+
+/// An error which the driver responded with
+#[derive(Debug, Deserialize, Serialize, Clone, thiserror::Error)]
+#[error("{name} {message:?}")]
+pub struct ErrorMessage {
+    #[serde(default)]
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) message: String,
+    #[serde(default)]
+    pub(crate) stack: String
+}
+
+/// Kind of [`ErrorMessage`] parsed from its name and message
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    /// `TimeoutError`
+    Timeout {
+        timeout: Option<Duration>,
+        /// What was being waited for, such as `waiting for selector "#submit"`
+        action: Option<String>
+    },
+    /// The page, context or browser has been closed during the call
+    TargetClosed,
+    /// Navigation failed with a network error
+    Navigation {
+        /// `net::ERR_NAME_NOT_RESOLVED` in chromium or `NS_ERROR_UNKNOWN_HOST` in firefox
+        code: String,
+        url: Option<String>
+    },
+    /// A strict locator matched more than one element
+    StrictModeViolation {
+        selector: Option<String>,
+        count: usize
+    },
+    /// The selector could not be parsed
+    Selector,
+    Other
+}
+
+impl ErrorMessage {
+    pub fn name(&self) -> &str { &self.name }
+
+    /// The whole message including the call log
+    pub fn message(&self) -> &str { &self.message }
+
+    /// Stack trace in the driver
+    pub fn stack(&self) -> &str { &self.stack }
+
+    /// The message without the call log
+    pub fn summary(&self) -> &str {
+        match self.message.find(LOG_HEADER) {
+            Some(i) => self.message[..i].trim_end_matches(&['=', '\n'][..]),
+            None => &self.message
+        }
+    }
+
+    /// Lines between `===== logs =====` and `=====` of the message.
+    /// They are what the driver did until the error.
+    pub fn call_log(&self) -> Vec<&str> {
+        let start = match self.message.find(LOG_HEADER) {
+            Some(i) => i,
+            None => return Vec::new()
+        };
+        self.message[start..]
+            .lines()
+            .skip(1)
+            .take_while(|l| !is_separator(l))
+            .collect()
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        let summary = self.summary();
+        if self.name == "TimeoutError" {
+            return ErrorKind::Timeout {
+                timeout: parse_timeout(summary),
+                action: summary
+                    .split_once(" exceeded while ")
+                    .map(|(_, a)| a.trim_end_matches('.').to_owned())
+                    .or_else(|| self.call_log().first().map(|l| l.trim().to_owned()))
+            };
+        }
+        if let Some(rest) = summary.split("strict mode violation: ").nth(1) {
+            let first_line = rest.lines().next().unwrap_or_default();
+            if let Some((selector, rest)) = first_line.rsplit_once(" resolved to ") {
+                if let Some(count) = rest.split(' ').next().and_then(|n| n.parse().ok()) {
+                    return ErrorKind::StrictModeViolation {
+                        selector: Some(selector.trim_matches('"').to_owned()),
+                        count
+                    };
+                }
+            }
+        }
+        if let Some((code, url)) = parse_net_error(summary) {
+            return ErrorKind::Navigation { code, url };
+        }
+        if summary.contains("while parsing selector") || summary.contains("Unknown engine") {
+            return ErrorKind::Selector;
+        }
+        if TARGET_CLOSED.iter().any(|s| summary.contains(s)) {
+            return ErrorKind::TargetClosed;
+        }
+        ErrorKind::Other
+    }
+}
+
+const LOG_HEADER: &str = " logs ===";
+
+const TARGET_CLOSED: &[&str] = &[
+    "Target closed",
+    "Target page, context or browser has been closed",
+    "Browser has been closed",
+    "Browser closed",
+    "Page closed",
+    "page was closed",
+    "Context closed"
+];
+
+fn is_separator(line: &str) -> bool { !line.is_empty() && line.chars().all(|c| c == '=') }
+
+/// `Timeout 30000ms exceeded.`
+fn parse_timeout(s: &str) -> Option<Duration> {
+    let rest = &s[s.find("Timeout ")? + "Timeout ".len()..];
+    let (ms, _) = rest.split_once("ms exceeded")?;
+    ms.parse::<f64>()
+        .ok()
+        .filter(|ms| ms.is_finite() && *ms >= 0.)
+        .map(|ms| Duration::from_secs_f64(ms / 1000.))
+}
+
+/// `net::ERR_NAME_NOT_RESOLVED at https://example.com/`
+fn parse_net_error(s: &str) -> Option<(String, Option<String>)> {
+    let start = ["net::ERR_", "NS_ERROR_", "NS_BINDING_"]
+        .iter()
+        .filter_map(|p| s.find(p))
+        .min()?;
+    let rest = &s[start..];
+    let end = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == ':'))
+        .unwrap_or_else(|| rest.len());
+    let code = rest[..end].to_owned();
+    let url = rest[end..]
+        .trim_start()
+        .strip_prefix("at ")
+        .and_then(|u| u.split_whitespace().next())
+        .map(str::to_owned);
+    Some((code, url))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(name: &str, message: &str) -> ErrorMessage {
+        ErrorMessage {
+            name: name.into(),
+            message: message.into(),
+            stack: String::new()
+        }
+    }
+
+    #[test]
+    fn timeout() {
+        let e = error(
+            "TimeoutError",
+            "Timeout 100ms exceeded.\n=========================== logs ===========================\nwaiting for selector \"#submit\"\n  selector resolved to hidden <button id=\"submit\"></button>\n============================================================"
+        );
+        assert_eq!(e.summary(), "Timeout 100ms exceeded.");
+        assert_eq!(
+            e.call_log(),
+            vec![
+                "waiting for selector \"#submit\"",
+                "  selector resolved to hidden <button id=\"submit\"></button>"
+            ]
+        );
+        assert_eq!(
+            e.kind(),
+            ErrorKind::Timeout {
+                timeout: Some(Duration::from_millis(100)),
+                action: Some("waiting for selector \"#submit\"".into())
+            }
+        );
+        assert_eq!(
+            error(
+                "TimeoutError",
+                "Timeout 30000ms exceeded while waiting for event \"download\""
+            )
+            .kind(),
+            ErrorKind::Timeout {
+                timeout: Some(Duration::from_secs(30)),
+                action: Some("waiting for event \"download\"".into())
+            }
+        );
+    }
+
+    #[test]
+    fn kind() {
+        assert_eq!(
+            error("Error", "net::ERR_NAME_NOT_RESOLVED at https://nowhere.invalid/\n=========================== logs ===========================\nnavigating to \"https://nowhere.invalid/\", waiting until \"load\"\n============================================================").kind(),
+            ErrorKind::Navigation {
+                code: "net::ERR_NAME_NOT_RESOLVED".into(),
+                url: Some("https://nowhere.invalid/".into())
+            }
+        );
+        assert_eq!(
+            error("Error", "NS_ERROR_UNKNOWN_HOST").kind(),
+            ErrorKind::Navigation {
+                code: "NS_ERROR_UNKNOWN_HOST".into(),
+                url: None
+            }
+        );
+        assert_eq!(
+            error(
+                "Error",
+                "strict mode violation: \"div\" resolved to 2 elements:\n    1) <div>a</div> aka playwright.$(\"text=a\")\n    2) <div>b</div> aka playwright.$(\"text=b\")\n"
+            )
+            .kind(),
+            ErrorKind::StrictModeViolation {
+                selector: Some("div".into()),
+                count: 2
+            }
+        );
+        assert_eq!(
+            error(
+                "Error",
+                "Unknown engine \"foo\" while parsing selector foo=bar"
+            )
+            .kind(),
+            ErrorKind::Selector
+        );
+        assert_eq!(
+            error("Error", "Target closed").kind(),
+            ErrorKind::TargetClosed
+        );
+        assert_eq!(error("Error", "Something else").kind(), ErrorKind::Other);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::imp::{core::*, prelude::*};
+#[cfg(unix)]
+use std::os::unix::process::CommandExt as _;
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, BufReader, Write},
+    process::{Child, ChildStderr, ChildStdin, ChildStdout, Command, ExitStatus, Stdio},
+    sync::mpsc,
+    time::Instant
+};
+use tokio::sync::oneshot;
+
+/// The process of `playwright run-driver`.
+/// It leads its own process group so that its descendants are killed together.
+#[derive(Debug)]
+pub(crate) struct DriverProcess {
+    child: Mutex<Option<Child>>,
+    pid: u32,
+    stderr_tail: Arc<Mutex<VecDeque<String>>>,
+    /// Disconnected when stderr is closed
+    stderr_closed: Mutex<mpsc::Receiver<()>>
+}
+
+impl DriverProcess {
+    const TAIL: usize = 20;
+
+    pub(crate) fn spawn(exec: &Path) -> io::Result<(Self, ChildStdin, ChildStdout)> {
+        let mut command = Command::new(exec);
+        let child = command
+            .args(&["run-driver"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        #[cfg(unix)]
+        child.process_group(0);
+        #[cfg(target_os = "windows")]
+        child.creation_flags(0x08000000);
+        let mut child = child.spawn()?;
+        // TODO: env "NODE_OPTIONS"
+        let stdin = child.stdin.take().unwrap();
+        let stdout = child.stdout.take().unwrap();
+        let stderr = child.stderr.take().unwrap();
+        let stderr_tail = Arc::<Mutex<VecDeque<String>>>::default();
+        let stderr_closed = Self::forward_stderr(stderr, stderr_tail.clone());
+        let this = Self {
+            pid: child.id(),
+            child: Mutex::new(Some(child)),
+            stderr_tail,
+            stderr_closed: Mutex::new(stderr_closed)
+        };
+        Ok((this, stdin, stdout))
+    }
+
+    /// Passes stderr through keeping the last lines for [`Error::DriverExited`]
+    fn forward_stderr(
+        stderr: ChildStderr,
+        tail: Arc<Mutex<VecDeque<String>>>
+    ) -> mpsc::Receiver<()> {
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let _tx = tx;
+            let mut stderr = BufReader::new(stderr);
+            let mut line = Vec::new();
+            loop {
+                line.clear();
+                match stderr.read_until(b'\n', &mut line) {
+                    Ok(0) | Err(_) => break,
+                    Ok(_) => {}
+                }
+                let _ = io::stderr().write_all(&line);
+                let mut tail = tail.lock().unwrap();
+                if tail.len() == Self::TAIL {
+                    tail.pop_front();
+                }
+                tail.push_back(String::from_utf8_lossy(&line).trim_end().to_owned());
+            }
+        });
+        rx
+    }
+
+    pub(crate) fn pid(&self) -> u32 { self.pid }
+
+    /// Returns [`Error::DriverExited`] if the process exits within the timeout.
+    pub(crate) fn exit_error(&self, timeout: Duration) -> Option<Error> {
+        let status = self.wait_timeout(timeout)?;
+        // Descendants may keep stderr open
+        let _ = self
+            .stderr_closed
+            .lock()
+            .unwrap()
+            .recv_timeout(Duration::from_millis(500));
+        let stderr_tail = self
+            .stderr_tail
+            .lock()
+            .unwrap()
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(Error::DriverExited {
+            status,
+            stderr_tail
+        })
+    }
+
+    /// Reaps the process if it exits within the timeout. Blocks the current thread.
+    pub(crate) fn wait_timeout(&self, timeout: Duration) -> Option<ExitStatus> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            {
+                let mut child = self.child.lock().unwrap();
+                let c = child.as_mut()?;
+                match c.try_wait() {
+                    Ok(Some(status)) => {
+                        *child = None;
+                        return Some(status);
+                    }
+                    Ok(None) => {}
+                    Err(_) => return None
+                }
+            }
+            if Instant::now() >= deadline {
+                return None;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    /// Kills the process group and reaps the driver. Blocks the current thread.
+    pub(crate) fn kill(&self) {
+        let mut child = match self.child.lock().unwrap().take() {
+            Some(c) => c,
+            None => return
+        };
+        #[cfg(unix)]
+        unsafe {
+            libc::kill(-(self.pid as libc::pid_t), libc::SIGKILL);
+        }
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+/// Runs blocking io on another thread without depending on the async runtime
+pub(crate) async fn unblock<T, F>(f: F) -> Result<T, Error>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static
+{
+    let (tx, rx) = oneshot::channel();
+    std::thread::spawn(move || {
+        let _ = tx.send(f());
+    });
+    rx.await.map_err(|_| Error::Channel)
+}
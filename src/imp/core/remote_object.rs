@@ -60,15 +60,29 @@ impl ChannelOwner {
         RequestBody::new(self.guid.clone(), method)
     }
 
-    pub(crate) async fn send_message(
-        &self,
-        r: RequestBody
-    ) -> Result<WaitData<WaitMessageResult>, Error> {
+    pub(crate) async fn send_message(&self, r: RequestBody) -> Result<WaitMessage, Error> {
         let wait = WaitData::new();
         let r = r.set_wait(&wait);
         let ctx = upgrade(&self.ctx)?;
-        ctx.lock().unwrap().send_message(r)?;
-        Ok(wait)
+        let (slow_mo, timeout) = {
+            let ctx = ctx.lock().unwrap();
+            (ctx.slow_mo(), ctx.call_timeout())
+        };
+        if let Some(d) = slow_mo {
+            sleep(d).await;
+        }
+        let guid = r.guid.clone();
+        let method = r.method.clone();
+        let id = ctx.lock().unwrap().send_message(r)?;
+        Ok(WaitMessage {
+            wait,
+            deadline: timeout
+                .map(|d| Box::pin(sleep(d)) as Pin<Box<dyn Future<Output = ()> + Send>>),
+            ctx: self.ctx.clone(),
+            id,
+            guid,
+            method
+        })
     }
 
     pub(crate) fn children(&self) -> Vec<RemoteWeak> { self.children.lock().unwrap().to_vec() }
@@ -131,12 +145,13 @@ pub(crate) trait RemoteObject: Debug {
 mod remote_enum {
     use super::{DummyObject as Dummy, RootObject as Root, *};
     use crate::imp::{
-        artifact::Artifact, binding_call::BindingCall, browser::Browser,
-        browser_context::BrowserContext, browser_type::BrowserType,
+        api_request_context::ApiRequestContext, artifact::Artifact, binding_call::BindingCall,
+        browser::Browser, browser_context::BrowserContext, browser_type::BrowserType,
         console_message::ConsoleMessage, dialog::Dialog, element_handle::ElementHandle,
-        frame::Frame, js_handle::JsHandle, page::Page, playwright::Playwright, request::Request,
-        response::Response, route::Route, selectors::Selectors, stream::Stream,
-        websocket::WebSocket, worker::Worker
+        frame::Frame, js_handle::JsHandle, local_utils::LocalUtils, page::Page,
+        playwright::Playwright, request::Request, response::Response, route::Route,
+        selectors::Selectors, stream::Stream, tracing::Tracing, websocket::WebSocket,
+        worker::Worker
     };
 
     macro_rules! upgrade {
@@ -219,6 +234,7 @@ mod remote_enum {
         // Android
         // AndroidSocket
         // AndroidDevice
+        ApiRequestContext,
         Artifact,
         BindingCall,
         Browser,
@@ -232,6 +248,7 @@ mod remote_enum {
         ElementHandle,
         Frame,
         JsHandle,
+        LocalUtils,
         Page,
         Playwright,
         Request,
@@ -239,6 +256,7 @@ mod remote_enum {
         Route,
         Stream,
         Selectors,
+        Tracing,
         WebSocket,
         Worker
     }
@@ -250,26 +268,31 @@ mod remote_enum {
             c: ChannelOwner
         ) -> Result<RemoteArc, Error> {
             let r = match typ.as_str() {
+                "APIRequestContext" => {
+                    RemoteArc::ApiRequestContext(Arc::new(ApiRequestContext::try_new(c)?))
+                }
                 "Artifact" => RemoteArc::Artifact(Arc::new(Artifact::try_new(c)?)),
-                "BindingCall" => RemoteArc::BindingCall(Arc::new(BindingCall::new(c))),
+                "BindingCall" => RemoteArc::BindingCall(Arc::new(BindingCall::try_new(ctx, c)?)),
                 "Browser" => RemoteArc::Browser(Arc::new(Browser::try_new(c)?)),
                 "BrowserContext" => {
-                    RemoteArc::BrowserContext(Arc::new(BrowserContext::try_new(c)?))
+                    RemoteArc::BrowserContext(Arc::new(BrowserContext::try_new(ctx, c)?))
                 }
                 "BrowserType" => RemoteArc::BrowserType(Arc::new(BrowserType::try_new(c)?)),
                 "ConsoleMessage" => {
                     RemoteArc::ConsoleMessage(Arc::new(ConsoleMessage::try_new(ctx, c)?))
                 }
-                "Dialog" => RemoteArc::Dialog(Arc::new(Dialog::new(c))),
+                "Dialog" => RemoteArc::Dialog(Arc::new(Dialog::try_new(c)?)),
                 "ElementHandle" => RemoteArc::ElementHandle(Arc::new(ElementHandle::new(c))),
                 "Frame" => RemoteArc::Frame(Arc::new(Frame::try_new(ctx, c)?)),
                 "JSHandle" => RemoteArc::JsHandle(Arc::new(JsHandle::try_new(c)?)),
+                "LocalUtils" => RemoteArc::LocalUtils(Arc::new(LocalUtils::new(c))),
                 "Page" => RemoteArc::Page(Arc::new(Page::try_new(ctx, c)?)),
                 "Playwright" => RemoteArc::Playwright(Arc::new(Playwright::try_new(ctx, c)?)),
                 "Request" => RemoteArc::Request(Request::try_new(ctx, c)?),
                 "Response" => RemoteArc::Response(Arc::new(Response::try_new(ctx, c)?)),
                 "Route" => RemoteArc::Route(Arc::new(Route::try_new(ctx, c)?)),
                 "Stream" => RemoteArc::Stream(Arc::new(Stream::new(c))),
+                "Tracing" => RemoteArc::Tracing(Arc::new(Tracing::new(c))),
                 "Selectors" => RemoteArc::Selectors(Arc::new(Selectors::new(c))),
                 "WebSocket" => RemoteArc::WebSocket(Arc::new(WebSocket::try_new(c)?)),
                 "Worker" => RemoteArc::Worker(Arc::new(Worker::try_new(c)?)),
@@ -282,6 +305,7 @@ mod remote_enum {
 
 pub(crate) use remote_enum::{RemoteArc, RemoteWeak};
 
+#[derive(Debug)]
 pub(crate) struct RequestBody {
     pub(crate) guid: Str<Guid>,
     pub(crate) method: Str<Method>,
@@ -404,10 +428,57 @@ where
                 Err(TryLockError::WouldBlock) => pending!(),
                 Err(e) => Err(e).unwrap()
             };
-            if x.is_none() {
-                *x = Some(cx.waker().clone());
+            *x = Some(cx.waker().clone());
+        }
+        // The result may have been placed before the waker was registered
+        if let Ok(x) = this.place.try_lock() {
+            if let Some(x) = &*x {
+                return Poll::Ready(x.clone());
             }
         }
         Poll::Pending
     }
 }
+
+/// The response of a request. Fails with [`Error::CallTimeout`] after the deadline of the connection.
+/// The callback is unregistered when this is dropped before the response arrives.
+pub(crate) struct WaitMessage {
+    wait: WaitData<WaitMessageResult>,
+    deadline: Option<Pin<Box<dyn Future<Output = ()> + Send>>>,
+    ctx: Wm<Context>,
+    id: i32,
+    guid: Str<Guid>,
+    method: Str<Method>
+}
+
+impl Future for WaitMessage {
+    type Output = WaitMessageResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        if let Poll::Ready(x) = Pin::new(&mut this.wait).poll(cx) {
+            return Poll::Ready(x);
+        }
+        let deadline = match &mut this.deadline {
+            Some(d) => d,
+            None => return Poll::Pending
+        };
+        match deadline.as_mut().poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Err(Arc::new(Error::CallTimeout {
+                guid: this.guid.as_str().into(),
+                method: this.method.as_str().into()
+            }))),
+            Poll::Pending => Poll::Pending
+        }
+    }
+}
+
+impl Drop for WaitMessage {
+    fn drop(&mut self) {
+        if let Some(ctx) = self.ctx.upgrade() {
+            if let Ok(mut ctx) = ctx.lock() {
+                ctx.remove_callback(self.id);
+            }
+        }
+    }
+}
@@ -1,22 +1,36 @@
 use crate::imp::core::*;
 use std::{
-    convert::TryInto,
+    collections::HashMap,
+    fmt::Debug,
     io,
-    io::{Read, Write},
-    process::{ChildStdin, ChildStdout}
+    io::{BufReader, Read, Write},
+    net::{Shutdown, TcpStream, ToSocketAddrs},
+    sync::{Arc, Mutex},
+    time::Duration
 };
 use thiserror::Error;
+use tungstenite::{
+    client::{uri_mode, IntoClientRequest},
+    error::UrlError,
+    handshake::HandshakeError,
+    http::{HeaderName, HeaderValue},
+    protocol::Role,
+    stream::Mode,
+    Message, WebSocket
+};
 
-#[derive(Debug)]
-pub(super) struct Reader {
-    stdout: ChildStdout,
-    length: Option<u32>,
-    buf: Vec<u8>
+/// Receiving half of a connection to the driver
+pub(super) trait Reader: Debug + Send {
+    /// Blocks until a whole message arrives
+    fn read(&mut self) -> Result<Res, TransportError>;
 }
 
-#[derive(Debug)]
-pub(super) struct Writer {
-    stdin: ChildStdin
+/// Sending half of a connection to the driver
+pub(super) trait Writer: Debug + Send {
+    fn send(&mut self, req: &Req<'_, '_>) -> Result<(), TransportError>;
+
+    /// Interrupts the reader blocked on the other half
+    fn close(&mut self) {}
 }
 
 #[derive(Error, Debug)]
@@ -24,62 +38,338 @@ pub enum TransportError {
     #[error(transparent)]
     Serde(#[from] serde_json::error::Error),
     #[error(transparent)]
-    Io(#[from] io::Error)
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    WebSocket(#[from] tungstenite::Error)
 }
 
-impl Reader {
-    const BUFSIZE: usize = 32767;
+/// Messages prefixed with its length in little endian over pipes of `playwright run-driver`
+#[derive(Debug)]
+pub(super) struct PipeReader<R> {
+    stdout: BufReader<R>,
+    /// Reused for every message not to allocate per message
+    buf: Vec<u8>
+}
 
-    pub(super) fn new(stdout: ChildStdout) -> Self {
+#[derive(Debug)]
+pub(super) struct PipeWriter<W> {
+    /// Taken on close so that the driver sees EOF
+    stdin: Option<W>
+}
+
+impl<R: Read> PipeReader<R> {
+    const BUFSIZE: usize = 32768;
+
+    pub(super) fn new(stdout: R) -> Self {
         Self {
-            stdout,
-            length: None,
+            stdout: BufReader::with_capacity(Self::BUFSIZE, stdout),
             buf: Vec::with_capacity(Self::BUFSIZE)
         }
     }
+}
 
-    // TODO: heap efficiency
-    pub(super) fn try_read(&mut self) -> Result<Option<Res>, TransportError> {
-        let this = self;
-        {
-            if this.length.is_none() && this.buf.len() >= 4 {
-                let off = this.buf.split_off(4);
-                let bytes: &[u8] = &this.buf;
-                this.length = Some(u32::from_le_bytes(bytes.try_into().unwrap()));
-                this.buf = off;
-            }
-            match this.length.map(|u| u as usize) {
-                None => {}
-                Some(l) if this.buf.len() < l => {}
-                Some(l) => {
-                    let bytes: &[u8] = &this.buf[..l];
-                    log::debug!("RECV {}", unsafe { std::str::from_utf8_unchecked(bytes) });
-                    let msg: Res = serde_json::from_slice(bytes)?;
-                    this.length = None;
-                    this.buf = this.buf[l..].to_owned();
-                    return Ok(Some(msg));
-                }
-            }
+impl<R: Read + Debug + Send> Reader for PipeReader<R> {
+    fn read(&mut self) -> Result<Res, TransportError> {
+        let mut length = [0; 4];
+        self.stdout.read_exact(&mut length)?;
+        let length = u32::from_le_bytes(length) as usize;
+        self.buf.clear();
+        self.buf.resize(length, 0);
+        self.stdout.read_exact(&mut self.buf)?;
+        log::debug!("RECV {}", String::from_utf8_lossy(&self.buf));
+        let msg: Res = serde_json::from_slice(&self.buf)?;
+        if self.buf.capacity() > Self::BUFSIZE * 32 {
+            // Do not keep a large screenshot alive for the rest of the session
+            self.buf = Vec::with_capacity(Self::BUFSIZE);
         }
-        {
-            let mut buf = [0; Self::BUFSIZE];
-            let n = this.stdout.read(&mut buf)?;
-            this.buf.extend(&buf[..n]);
-        }
-        Ok(None)
+        Ok(msg)
     }
 }
 
-impl Writer {
-    pub(super) fn new(stdin: ChildStdin) -> Self { Self { stdin } }
+impl<W> PipeWriter<W> {
+    pub(super) fn new(stdin: W) -> Self { Self { stdin: Some(stdin) } }
+}
 
-    pub(super) fn send(&mut self, req: &Req<'_, '_>) -> Result<(), TransportError> {
+impl<W: Write + Debug + Send> Writer for PipeWriter<W> {
+    fn send(&mut self, req: &Req<'_, '_>) -> Result<(), TransportError> {
         log::debug!("SEND {:?}", &req);
         let serialized = serde_json::to_vec(&req)?;
         let length = serialized.len() as u32;
         let mut bytes = length.to_le_bytes().to_vec();
         bytes.extend(serialized);
-        self.stdin.write_all(&bytes)?;
+        let stdin = self
+            .stdin
+            .as_mut()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::BrokenPipe))?;
+        stdin.write_all(&bytes)?;
+        stdin.flush()?;
+        Ok(())
+    }
+
+    fn close(&mut self) { self.stdin = None; }
+}
+
+/// One message per text frame over a connection to `playwright run-server`
+#[derive(Debug)]
+pub(super) struct WebSocketReader {
+    socket: WebSocket<ReadHalf>,
+    /// Replies to pings and closes, so that every frame is written by a single socket
+    writer: Arc<Mutex<WebSocket<TcpStream>>>
+}
+
+#[derive(Debug)]
+pub(super) struct WebSocketWriter {
+    socket: Arc<Mutex<WebSocket<TcpStream>>>
+}
+
+/// The tcp stream of [`WebSocketReader`].
+/// Writes after the handshake are dropped, because the replies which tungstenite makes on reading are sent by the
+/// writer instead.
+#[derive(Debug)]
+pub(super) struct ReadHalf {
+    stream: TcpStream,
+    discard_writes: bool
+}
+
+impl Read for ReadHalf {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> { self.stream.read(buf) }
+}
+
+impl Write for ReadHalf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.discard_writes {
+            return Ok(buf.len());
+        }
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> { self.stream.flush() }
+}
+
+/// Opens a web socket and splits it into halves sharing the same tcp stream.
+/// This blocks until the handshake completes.
+pub(super) fn connect_web_socket(
+    ws_endpoint: &str,
+    headers: &HashMap<String, String>,
+    timeout: Option<Duration>
+) -> Result<(WebSocketReader, WebSocketWriter), TransportError> {
+    let mut request = ws_endpoint.into_client_request()?;
+    for (k, v) in headers {
+        let name = HeaderName::from_bytes(k.as_bytes()).map_err(invalid_header)?;
+        let value = HeaderValue::from_str(v).map_err(invalid_header)?;
+        request.headers_mut().insert(name, value);
+    }
+    if let Mode::Tls = uri_mode(request.uri())? {
+        return Err(tungstenite::Error::Url(UrlError::TlsFeatureNotEnabled).into());
+    }
+    let host = request
+        .uri()
+        .host()
+        .ok_or(tungstenite::Error::Url(UrlError::NoHostName))?;
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let port = request.uri().port_u16().unwrap_or(80);
+    let stream = connect_tcp((host, port), timeout)?;
+    stream.set_read_timeout(timeout)?;
+    stream.set_write_timeout(timeout)?;
+    let stream = ReadHalf {
+        stream,
+        discard_writes: false
+    };
+    // The reader keeps the socket of the handshake, which may have buffered the first messages
+    let (mut socket, _) = tungstenite::client(request, stream).map_err(|e| match e {
+        HandshakeError::Failure(e) => e,
+        HandshakeError::Interrupted(_) => tungstenite::Error::Io(io::ErrorKind::TimedOut.into())
+    })?;
+    let half = socket.get_mut();
+    half.discard_writes = true;
+    half.stream.set_read_timeout(None)?;
+    half.stream.set_write_timeout(None)?;
+    let writer = WebSocket::from_raw_socket(half.stream.try_clone()?, Role::Client, None);
+    let writer = Arc::new(Mutex::new(writer));
+    Ok((
+        WebSocketReader {
+            socket,
+            writer: writer.clone()
+        },
+        WebSocketWriter { socket: writer }
+    ))
+}
+
+fn invalid_header<E: Into<tungstenite::http::Error>>(e: E) -> tungstenite::Error {
+    tungstenite::Error::HttpFormat(e.into())
+}
+
+fn connect_tcp<A: ToSocketAddrs>(
+    addr: A,
+    timeout: Option<Duration>
+) -> Result<TcpStream, TransportError> {
+    let timeout = match timeout {
+        Some(t) => t,
+        None => return Ok(TcpStream::connect(addr)?)
+    };
+    let mut last_err = None;
+    for a in addr.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&a, timeout) {
+            Ok(s) => return Ok(s),
+            Err(e) => last_err = Some(e)
+        }
+    }
+    Err(last_err
+        .unwrap_or_else(|| io::ErrorKind::AddrNotAvailable.into())
+        .into())
+}
+
+impl Reader for WebSocketReader {
+    fn read(&mut self) -> Result<Res, TransportError> {
+        let bytes = loop {
+            match self.socket.read()? {
+                Message::Text(s) => break s.into_bytes(),
+                Message::Binary(b) => break b,
+                Message::Ping(data) => {
+                    self.writer.lock().unwrap().send(Message::Pong(data))?;
+                }
+                Message::Close(frame) => {
+                    // Already closed when the writer started the close
+                    let _ = self.writer.lock().unwrap().close(frame);
+                }
+                _ => continue
+            }
+        };
+        log::debug!("RECV {}", String::from_utf8_lossy(&bytes));
+        let msg: Res = serde_json::from_slice(&bytes)?;
+        Ok(msg)
+    }
+}
+
+impl Writer for WebSocketWriter {
+    fn send(&mut self, req: &Req<'_, '_>) -> Result<(), TransportError> {
+        log::debug!("SEND {:?}", &req);
+        let serialized = serde_json::to_string(&req)?;
+        self.socket
+            .lock()
+            .unwrap()
+            .send(Message::Text(serialized))?;
         Ok(())
     }
+
+    fn close(&mut self) {
+        let mut socket = self.socket.lock().unwrap();
+        let _ = socket.close(None);
+        let _ = socket.get_ref().shutdown(Shutdown::Both);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::imp::prelude::*;
+    use std::{io::Cursor, net::TcpListener, thread};
+
+    fn frame(s: &str) -> Vec<u8> {
+        let mut bytes = (s.len() as u32).to_le_bytes().to_vec();
+        bytes.extend(s.as_bytes());
+        bytes
+    }
+
+    #[test]
+    fn pipe_reader_splits_frames() {
+        let mut bytes = frame(r#"{"id":1,"result":{}}"#);
+        bytes.extend(frame(r#"{"id":2,"result":{}}"#));
+        let mut reader = PipeReader::new(Cursor::new(bytes));
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            if let Res::Result(r) = reader.read().unwrap() {
+                ids.push(r.id);
+            }
+        }
+        assert_eq!(ids, vec![1, 2]);
+        assert!(matches!(reader.read(), Err(TransportError::Io(_))));
+    }
+
+    #[test]
+    fn pipe_writer_frames_and_closes() {
+        let mut writer = PipeWriter::new(Vec::new());
+        let guid = Str::validate("".into()).unwrap();
+        let method = Str::validate("foo".into()).unwrap();
+        let req = Req {
+            id: 1,
+            guid: &guid,
+            method: &method,
+            params: Map::new(),
+            metadata: Default::default()
+        };
+        writer.send(&req).unwrap();
+        let bytes = writer.stdin.as_ref().unwrap();
+        let length = u32::from_le_bytes(bytes[..4].try_into().unwrap()) as usize;
+        assert_eq!(bytes.len(), length + 4);
+        writer.close();
+        assert!(writer.send(&req).is_err());
+    }
+
+    #[test]
+    fn web_socket_sends_headers() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut header = None;
+            let mut socket = tungstenite::accept_hdr(
+                stream,
+                |req: &tungstenite::handshake::server::Request, res| {
+                    header = req
+                        .headers()
+                        .get("x-playwright-browser")
+                        .map(|v| v.to_str().unwrap().to_owned());
+                    Ok(res)
+                }
+            )
+            .unwrap();
+            socket
+                .send(Message::Text(r#"{"id":1,"result":{}}"#.into()))
+                .unwrap();
+            header
+        });
+        let headers = vec![("x-playwright-browser".to_owned(), "chromium".to_owned())]
+            .into_iter()
+            .collect();
+        let endpoint = format!("ws://127.0.0.1:{}/", port);
+        let (mut reader, mut writer) =
+            connect_web_socket(&endpoint, &headers, Some(Duration::from_secs(5))).unwrap();
+        let msg = reader.read().unwrap();
+        assert!(matches!(msg, Res::Result(ResResult { id: 1, .. })));
+        writer.close();
+        assert_eq!(server.join().unwrap().as_deref(), Some("chromium"));
+    }
+
+    #[test]
+    fn web_socket_answers_pings() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = tungstenite::accept(stream).unwrap();
+            socket.send(Message::Ping(b"p".to_vec())).unwrap();
+            let pong = socket.read().unwrap();
+            socket
+                .send(Message::Text(r#"{"id":1,"result":{}}"#.into()))
+                .unwrap();
+            pong
+        });
+        let endpoint = format!("ws://127.0.0.1:{}/", port);
+        let (mut reader, mut writer) =
+            connect_web_socket(&endpoint, &HashMap::new(), Some(Duration::from_secs(5))).unwrap();
+        let msg = reader.read().unwrap();
+        assert!(matches!(msg, Res::Result(ResResult { id: 1, .. })));
+        writer.close();
+        assert_eq!(server.join().unwrap(), Message::Pong(b"p".to_vec()));
+    }
+
+    #[test]
+    fn web_socket_rejects_tls() {
+        let err = connect_web_socket("wss://127.0.0.1:1/", &HashMap::new(), None).unwrap_err();
+        assert!(matches!(
+            err,
+            TransportError::WebSocket(tungstenite::Error::Url(UrlError::TlsFeatureNotEnabled))
+        ));
+    }
 }
@@ -1,15 +1,83 @@
-use crate::imp::core::*;
+use crate::imp::{core::*, prelude::*};
 
 #[derive(Debug)]
 pub(crate) struct Dialog {
-    channel: ChannelOwner
+    channel: ChannelOwner,
+    r#type: DialogType,
+    message: String,
+    default_value: String
 }
 
 impl Dialog {
-    pub(crate) fn new(channel: ChannelOwner) -> Self { Self { channel } }
+    pub(crate) fn try_new(channel: ChannelOwner) -> Result<Self, Error> {
+        let Initializer {
+            r#type,
+            message,
+            default_value
+        } = serde_json::from_value(channel.initializer.clone())?;
+        Ok(Self {
+            channel,
+            r#type,
+            message,
+            default_value
+        })
+    }
+
+    pub(crate) fn r#type(&self) -> DialogType { self.r#type }
+
+    pub(crate) fn message(&self) -> &str { &self.message }
+
+    pub(crate) fn default_value(&self) -> &str { &self.default_value }
+
+    pub(crate) async fn accept(&self, prompt_text: Option<&str>) -> ArcResult<()> {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Args<'a> {
+            prompt_text: Option<&'a str>
+        }
+        let args = Args { prompt_text };
+        let _ = send_message!(self, "accept", args);
+        Ok(())
+    }
+
+    pub(crate) async fn dismiss(&self) -> ArcResult<()> {
+        let _ = send_message!(self, "dismiss", Map::new());
+        Ok(())
+    }
+
+    /// Called by the page when nobody listens to the dialog.
+    /// `beforeunload` is accepted so that the page can be closed, and the others are dismissed.
+    pub(crate) fn respond_default(&self, ctx: &Context) {
+        let method = match self.r#type {
+            DialogType::BeforeUnload => "accept",
+            _ => "dismiss"
+        };
+        let m: Str<Method> = method.to_owned().try_into().unwrap();
+        let r = self.channel().create_request(m).set_params(Map::new());
+        ctx.send_message_later(r);
+    }
 }
 
 impl RemoteObject for Dialog {
     fn channel(&self) -> &ChannelOwner { &self.channel }
     fn channel_mut(&mut self) -> &mut ChannelOwner { &mut self.channel }
 }
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DialogType {
+    Alert,
+    #[serde(rename = "beforeunload")]
+    BeforeUnload,
+    Confirm,
+    Prompt
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Initializer {
+    r#type: DialogType,
+    message: String,
+    default_value: String
+}
@@ -3,7 +3,9 @@ use itertools::Itertools;
 use crate::imp::{
     core::*,
     frame::Frame,
+    page::ScreenshotArgs as PageScreenshotArgs,
     prelude::*,
+    screenshot::{Masks, MaskTarget, ScreenshotOptions},
     utils::{
         ElementState, File, FloatRect, KeyboardModifier, MouseButton, Position, ScreenshotType,
         WaitForSelectorState,
@@ -204,15 +206,77 @@ impl ElementHandle {
         Ok(Some(f))
     }
 
-    pub(crate) async fn screenshot(&self, args: ScreenshotArgs<'_>) -> ArcResult<Vec<u8>> {
-        let path = args.path.clone();
-        let v = send_message!(self, "screenshot", args);
+    pub(crate) async fn screenshot(&self, mut args: ScreenshotArgs<'_>) -> ArcResult<Vec<u8>> {
+        if let Some(clip) = args.clip {
+            return self.clipped_screenshot(clip, args).await;
+        }
+        let path = args.path;
+        let frame = match &args.options.mask {
+            Some(mask) if mask.iter().any(|m| matches!(m, MaskTarget::Selector(_))) => {
+                self.owner_frame().await?
+            }
+            _ => None
+        };
+        let masks = Masks::new(&mut args.options, frame);
+        let v = masks
+            .cover(|mask| async move {
+                args.options.driver_mask = mask;
+                Ok(send_message!(self, "screenshot", args))
+            })
+            .await?;
         let b64 = only_str(&v)?;
         let bytes = base64::decode(b64).map_err(Error::InvalidBase64)?;
-        may_save(path.as_deref(), &bytes)?;
+        may_save(path, &bytes)?;
         Ok(bytes)
     }
 
+    /// The driver can't clip elements, so this takes the area of the page instead
+    async fn clipped_screenshot(
+        &self,
+        clip: FloatRect,
+        args: ScreenshotArgs<'_>
+    ) -> ArcResult<Vec<u8>> {
+        let ScreenshotArgs {
+            path,
+            timeout,
+            r#type,
+            quality,
+            mut options,
+            ..
+        } = args;
+        self.scroll_into_view_if_needed(timeout).await?;
+        let frame = self.owner_frame().await?.ok_or(Error::ObjectNotFound)?;
+        let page = upgrade(&frame)?.page().ok_or(Error::ObjectNotFound)?;
+        // Selectors are resolved in the frame of the element rather than the main frame
+        if let Some(mask) = &mut options.mask {
+            for m in mask.iter_mut() {
+                if let MaskTarget::Selector(selector) = m {
+                    *m = MaskTarget::Locator {
+                        frame: frame.clone(),
+                        selector: std::mem::take(selector)
+                    };
+                }
+            }
+        }
+        let b = self.bounding_box().await?.ok_or(Error::ObjectNotFound)?;
+        let clip = FloatRect {
+            x: b.x + clip.x,
+            y: b.y + clip.y,
+            width: clip.width.min(b.width - clip.x).max(0.),
+            height: clip.height.min(b.height - clip.y).max(0.)
+        };
+        let args = PageScreenshotArgs {
+            timeout,
+            r#type,
+            quality: quality.map(|q| q as i32),
+            full_page: None,
+            clip: Some(clip),
+            path: path.map(Path::to_owned),
+            options
+        };
+        upgrade(&page)?.screenshot(args).await
+    }
+
     pub(crate) async fn wait_for_element_state(
         &self,
         state: ElementState,
@@ -426,7 +490,11 @@ pub(crate) struct ScreenshotArgs<'a> {
     pub(crate) timeout: Option<f64>,
     pub(crate) r#type: Option<ScreenshotType>,
     pub(crate) quality: Option<i64>,
-    pub(crate) omit_background: Option<bool>,
+    /// Relative to the element
+    #[serde(skip)]
+    pub(crate) clip: Option<FloatRect>,
+    #[serde(flatten)]
+    pub(crate) options: ScreenshotOptions
 }
 
 #[skip_serializing_none]
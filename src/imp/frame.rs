@@ -1,5 +1,5 @@
 pub(crate) use crate::imp::element_handle::Opt;
-use crate::imp::{core::*, element_handle::ElementHandle, js_handle::JsHandle, page, page::Page, prelude::*, response::Response, utils::{DocumentLoadState, File, KeyboardModifier, MouseButton, Position}};
+use crate::imp::{core::*, element_handle::ElementHandle, js_handle::JsHandle, page, page::Page, prelude::*, request::Request, response::Response, utils::{DocumentLoadState, File, KeyboardModifier, MouseButton, Position, UrlMatcher}};
 use std::{collections::HashSet, iter::FromIterator};
 use crate::protocol::generated::LifecycleEvent;
 
@@ -22,15 +22,17 @@ struct Variable {
 
 macro_rules! is_checked {
     ($f: ident, $m: literal) => {
-        pub(crate) async fn $f(&self, selector: &str, timeout: Option<f64>) -> ArcResult<bool> {
-            #[skip_serializing_none]
-            #[derive(Serialize)]
-            #[serde(rename_all = "camelCase")]
-            struct Args<'a> {
-                selector: &'a str,
-                timeout: Option<f64>
-            }
-            let args = Args { selector, timeout };
+        pub(crate) async fn $f(
+            &self,
+            selector: &str,
+            timeout: Option<f64>,
+            strict: Option<bool>
+        ) -> ArcResult<bool> {
+            let args = SelectorTimeout {
+                selector,
+                timeout,
+                strict
+            };
             let v = send_message!(self, $m, args);
             let b = first(&v)
                 .ok_or(Error::InvalidParams)?
@@ -122,8 +124,17 @@ impl Frame {
         Ok(())
     }
 
-    pub(crate) async fn focus(&self, selector: &str, timeout: Option<f64>) -> ArcResult<()> {
-        let args = SelectorTimeout { selector, timeout };
+    pub(crate) async fn focus(
+        &self,
+        selector: &str,
+        timeout: Option<f64>,
+        strict: Option<bool>
+    ) -> ArcResult<()> {
+        let args = SelectorTimeout {
+            selector,
+            timeout,
+            strict
+        };
         let _ = send_message!(self, "focus", args);
         Ok(())
     }
@@ -131,9 +142,14 @@ impl Frame {
     pub(crate) async fn text_content(
         &self,
         selector: &str,
-        timeout: Option<f64>
+        timeout: Option<f64>,
+        strict: Option<bool>
     ) -> ArcResult<Option<String>> {
-        let args = SelectorTimeout { selector, timeout };
+        let args = SelectorTimeout {
+            selector,
+            timeout,
+            strict
+        };
         let v = send_message!(self, "textContent", args);
         let s = maybe_only_str(&v)?;
         Ok(s.map(Into::into))
@@ -142,9 +158,14 @@ impl Frame {
     pub(crate) async fn inner_text(
         &self,
         selector: &str,
-        timeout: Option<f64>
+        timeout: Option<f64>,
+        strict: Option<bool>
     ) -> ArcResult<String> {
-        let args = SelectorTimeout { selector, timeout };
+        let args = SelectorTimeout {
+            selector,
+            timeout,
+            strict
+        };
         let v = send_message!(self, "innerText", args);
         let s = only_str(&v)?;
         Ok(s.into())
@@ -153,9 +174,14 @@ impl Frame {
     pub(crate) async fn inner_html(
         &self,
         selector: &str,
-        timeout: Option<f64>
+        timeout: Option<f64>,
+        strict: Option<bool>
     ) -> ArcResult<String> {
-        let args = SelectorTimeout { selector, timeout };
+        let args = SelectorTimeout {
+            selector,
+            timeout,
+            strict
+        };
         let v = send_message!(self, "innerHTML", args);
         let s = only_str(&v)?;
         Ok(s.into())
@@ -165,7 +191,8 @@ impl Frame {
         &self,
         selector: &str,
         name: &str,
-        timeout: Option<f64>
+        timeout: Option<f64>,
+        strict: Option<bool>
     ) -> ArcResult<Option<String>> {
         #[skip_serializing_none]
         #[derive(Serialize)]
@@ -173,12 +200,14 @@ impl Frame {
         struct Args<'a, 'b> {
             selector: &'a str,
             name: &'b str,
-            timeout: Option<f64>
+            timeout: Option<f64>,
+            strict: Option<bool>
         }
         let args = Args {
             selector,
             name,
-            timeout
+            timeout,
+            strict
         };
         let v = send_message!(self, "getAttribute", args);
         let s = maybe_only_str(&v)?;
@@ -219,6 +248,17 @@ impl Frame {
         Ok(es)
     }
 
+    pub(crate) async fn query_count(&self, selector: &str) -> ArcResult<usize> {
+        let mut args = HashMap::new();
+        args.insert("selector", selector);
+        let v = send_message!(self, "queryCount", args);
+        let n = first(&v)
+            .ok_or(Error::InvalidParams)?
+            .as_u64()
+            .ok_or(Error::InvalidParams)?;
+        Ok(n as usize)
+    }
+
     pub(crate) async fn frame_element(&self) -> ArcResult<Weak<ElementHandle>> {
         let v = send_message!(self, "frameElement", Map::new());
         let guid = only_guid(&v)?;
@@ -370,24 +410,28 @@ impl Frame {
         &self,
         selector: &str,
         expression: &str,
-        arg: Option<T>
+        arg: Option<T>,
+        strict: Option<bool>
     ) -> ArcResult<U>
     where
         T: Serialize,
         U: DeserializeOwned
     {
+        #[skip_serializing_none]
         #[derive(Serialize)]
         #[serde(rename_all = "camelCase")]
         struct Args<'a, 'b> {
             selector: &'a str,
             expression: &'b str,
-            arg: Value
+            arg: Value,
+            strict: Option<bool>
         }
         let arg = ser::to_value(&arg).map_err(Error::SerializationPwJson)?;
         let args = Args {
             selector,
             expression,
-            arg
+            arg,
+            strict
         };
         let v = send_message!(self, "evalOnSelector", args);
         let first = first(&v).ok_or(Error::ObjectNotFound)?;
@@ -426,23 +470,27 @@ impl Frame {
         &self,
         selector: &str,
         r#type: &str,
-        event_init: Option<T>
+        event_init: Option<T>,
+        strict: Option<bool>
     ) -> ArcResult<()>
     where
         T: Serialize
     {
+        #[skip_serializing_none]
         #[derive(Serialize)]
         #[serde(rename_all = "camelCase")]
         struct Args<'a, 'b> {
             selector: &'a str,
             r#type: &'b str,
-            event_init: Value
+            event_init: Value,
+            strict: Option<bool>
         }
         let event_init = ser::to_value(&event_init).map_err(Error::SerializationPwJson)?;
         let args = Args {
             selector,
             r#type,
-            event_init
+            event_init,
+            strict
         };
         let _ = send_message!(self, "dispatchEvent", args);
         Ok(())
@@ -466,6 +514,24 @@ impl Frame {
         Ok(())
     }
 
+    pub(crate) async fn expect(&self, args: ExpectArgs<'_>) -> ArcResult<ExpectResult> {
+        let v = send_message!(self, "expect", args);
+        let ExpectResponse {
+            matches,
+            received,
+            log
+        } = serde_json::from_value((*v).clone()).map_err(Error::Serde)?;
+        let received = match received {
+            Some(x) => Some(de::from_value(&x).map_err(Error::DeserializationPwJson)?),
+            None => None
+        };
+        Ok(ExpectResult {
+            matches,
+            received,
+            log
+        })
+    }
+
     pub(crate) async fn wait_for_function(
         &self,
         args: WaitForFunctionArgs<'_>
@@ -497,9 +563,113 @@ impl Frame {
         self.var.lock().unwrap().child_frames.push(child);
     }
 
+    /// `timeout` or the default navigation timeout of the page
+    fn navigation_timeout(&self, timeout: Option<f64>) -> u32 {
+        let default = || {
+            self.page()
+                .and_then(|p| p.upgrade())
+                .map(|p| p.default_navigation_timeout())
+                .unwrap_or(Page::DEFAULT_TIMEOUT)
+        };
+        timeout.map(|t| t as u32).unwrap_or_else(default)
+    }
+
+    pub(crate) async fn wait_for_load_state(
+        &self,
+        state: Option<DocumentLoadState>,
+        timeout: Option<f64>
+    ) -> ArcResult<()> {
+        let state = state
+            .map(LifecycleEvent::from)
+            .unwrap_or(LifecycleEvent::Load);
+        let timeout = self.navigation_timeout(timeout);
+        with_timeout(self.load_state(state), timeout).await?;
+        Ok(())
+    }
+
+    pub(crate) async fn wait_for_url(
+        &self,
+        url: &UrlMatcher,
+        args: WaitForUrlArgs
+    ) -> ArcResult<()> {
+        let rx = self.subscribe_event();
+        if url.is_match(&self.url()) {
+            return self
+                .wait_for_load_state(args.wait_until, args.timeout)
+                .await;
+        }
+        self.navigated(rx, Some(url), args.wait_until, args.timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Waits for the navigation which `rx` observes, then for the response of its document.
+    /// Same-document navigations have no response.
+    pub(crate) async fn wait_for_navigation(
+        &self,
+        rx: broadcast::Receiver<Evt>,
+        wait_until: Option<DocumentLoadState>,
+        timeout: Option<f64>
+    ) -> ArcResult<Option<Weak<Response>>> {
+        let event = self.navigated(rx, None, wait_until, timeout).await?;
+        match event.request.and_then(|r| r.upgrade()) {
+            Some(request) => request.response().await,
+            None => Ok(None)
+        }
+    }
+
+    /// Resolves once the frame has reached `state`, immediately if it already has.
+    async fn load_state(&self, state: LifecycleEvent) -> Result<(), Error> {
+        let rx = self.subscribe_event();
+        if self.var.lock().unwrap().load_states.contains(&state) {
+            return Ok(());
+        }
+        let predicate = |e: &Evt| matches!(e, Evt::LoadState(x) if *x == state);
+        wait_for_event(rx, EventType::LoadState, predicate, 0).await?;
+        Ok(())
+    }
+
+    async fn navigated(
+        &self,
+        rx: broadcast::Receiver<Evt>,
+        url: Option<&UrlMatcher>,
+        wait_until: Option<DocumentLoadState>,
+        timeout: Option<f64>
+    ) -> Result<FrameNavigatedEvent, Error> {
+        let state = wait_until
+            .map(LifecycleEvent::from)
+            .unwrap_or(LifecycleEvent::Load);
+        let predicate = |e: &Evt| match e {
+            Evt::Navigated(x) => url.map(|u| u.is_match(&x.url)).unwrap_or(true),
+            _ => false
+        };
+        let wait = async {
+            let event = match wait_for_event(rx, EventType::Navigated, predicate, 0).await? {
+                Evt::Navigated(x) => x,
+                _ => unreachable!()
+            };
+            if let Some(error) = &event.error {
+                let e = ErrorMessage {
+                    name: "Error".into(),
+                    message: error.clone(),
+                    stack: String::new()
+                };
+                return Err(Error::ErrorResponded(Arc::new(e)));
+            }
+            self.load_state(state).await?;
+            Ok(event)
+        };
+        with_timeout(wait, self.navigation_timeout(timeout)).await
+    }
+
     fn on_navigated(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
         let var = &mut self.var.lock().unwrap();
-        let payload: FrameNavigatedEvent = serde_json::from_value(params.into())?;
+        let mut payload: FrameNavigatedEvent = serde_json::from_value(params.into())?;
+        payload.request = payload
+            .new_document
+            .as_ref()
+            .and_then(|d| serde_json::from_value::<OnlyGuid>(d.request.clone()).ok())
+            .and_then(|OnlyGuid { guid }| get_object!(ctx, &guid, Request).ok());
         {
             var.name = payload.name.clone();
             var.url = payload.url.clone();
@@ -597,7 +767,9 @@ impl IsEvent for Evt {
     }
 }
 
-enum Handle {
+/// The driver creates an ElementHandle instead of a JSHandle when the value is a DOM element
+#[derive(Debug, Clone)]
+pub(crate) enum Handle {
     Js(Weak<JsHandle>),
     Element(Weak<ElementHandle>)
 }
@@ -637,7 +809,8 @@ pub(crate) struct ClickArgs<'a> {
     pub(crate) timeout: Option<f64>,
     pub(crate) force: Option<bool>,
     pub(crate) no_wait_after: Option<bool>,
-    pub(crate) trial: Option<bool>
+    pub(crate) trial: Option<bool>,
+    pub(crate) strict: Option<bool>
 }
 
 impl<'a> ClickArgs<'a> {
@@ -653,7 +826,8 @@ impl<'a> ClickArgs<'a> {
             timeout: None,
             force: None,
             no_wait_after: None,
-            trial: None
+            trial: None,
+            strict: None
         }
     }
 }
@@ -664,7 +838,8 @@ impl<'a> ClickArgs<'a> {
 pub(crate) struct WaitForSelectorArgs<'a> {
     selector: &'a str,
     pub(crate) timeout: Option<f64>,
-    pub(crate) state: Option<FrameState>
+    pub(crate) state: Option<FrameState>,
+    pub(crate) strict: Option<bool>
 }
 
 impl<'a> WaitForSelectorArgs<'a> {
@@ -672,7 +847,8 @@ impl<'a> WaitForSelectorArgs<'a> {
         Self {
             selector,
             timeout: None,
-            state: None
+            state: None,
+            strict: None
         }
     }
 }
@@ -696,7 +872,8 @@ macro_rules! type_args {
             $f: &'b str,
             pub(crate) delay: Option<f64>,
             pub(crate) timeout: Option<f64>,
-            pub(crate) no_wait_after: Option<bool>
+            pub(crate) no_wait_after: Option<bool>,
+            pub(crate) strict: Option<bool>
         }
 
         impl<'a, 'b> $t<'a, 'b> {
@@ -706,7 +883,8 @@ macro_rules! type_args {
                     $f,
                     delay: None,
                     timeout: None,
-                    no_wait_after: None
+                    no_wait_after: None,
+                    strict: None
                 }
             }
         }
@@ -725,7 +903,8 @@ pub(crate) struct HoverArgs<'a> {
     pub(crate) position: Option<Position>,
     pub(crate) timeout: Option<f64>,
     pub(crate) force: Option<bool>,
-    pub(crate) trial: Option<bool>
+    pub(crate) trial: Option<bool>,
+    pub(crate) strict: Option<bool>
 }
 
 impl<'a> HoverArgs<'a> {
@@ -736,7 +915,8 @@ impl<'a> HoverArgs<'a> {
             position: None,
             timeout: None,
             force: None,
-            trial: None
+            trial: None,
+            strict: None
         }
     }
 }
@@ -770,7 +950,8 @@ pub(crate) struct TapArgs<'a> {
     pub(crate) timeout: Option<f64>,
     pub(crate) force: Option<bool>,
     pub(crate) no_wait_after: Option<bool>,
-    pub(crate) trial: Option<bool>
+    pub(crate) trial: Option<bool>,
+    pub(crate) strict: Option<bool>
 }
 
 impl<'a> TapArgs<'a> {
@@ -782,7 +963,8 @@ impl<'a> TapArgs<'a> {
             timeout: None,
             force: None,
             no_wait_after: None,
-            trial: None
+            trial: None,
+            strict: None
         }
     }
 }
@@ -794,7 +976,8 @@ pub(crate) struct FillArgs<'a, 'b> {
     selector: &'a str,
     value: &'b str,
     pub(crate) timeout: Option<f64>,
-    pub(crate) no_wait_after: Option<bool>
+    pub(crate) no_wait_after: Option<bool>,
+    pub(crate) strict: Option<bool>
 }
 
 impl<'a, 'b> FillArgs<'a, 'b> {
@@ -803,7 +986,8 @@ impl<'a, 'b> FillArgs<'a, 'b> {
             selector,
             value,
             timeout: None,
-            no_wait_after: None
+            no_wait_after: None,
+            strict: None
         }
     }
 }
@@ -813,7 +997,8 @@ impl<'a, 'b> FillArgs<'a, 'b> {
 #[serde(rename_all = "camelCase")]
 struct SelectorTimeout<'a> {
     selector: &'a str,
-    timeout: Option<f64>
+    timeout: Option<f64>,
+    strict: Option<bool>
 }
 
 #[skip_serializing_none]
@@ -825,7 +1010,8 @@ pub(crate) struct CheckArgs<'a> {
     pub(crate) timeout: Option<f64>,
     pub(crate) force: Option<bool>,
     pub(crate) no_wait_after: Option<bool>,
-    pub(crate) trial: Option<bool>
+    pub(crate) trial: Option<bool>,
+    pub(crate) strict: Option<bool>
 }
 
 impl<'a> CheckArgs<'a> {
@@ -836,7 +1022,8 @@ impl<'a> CheckArgs<'a> {
             timeout: None,
             force: None,
             no_wait_after: None,
-            trial: None
+            trial: None,
+            strict: None
         }
     }
 }
@@ -870,7 +1057,8 @@ pub(crate) struct SelectOptionArgs<'a> {
     pub(crate) elements: Option<Vec<OnlyGuid>>,
 
     pub(crate) timeout: Option<f64>,
-    pub(crate) no_wait_after: Option<bool>
+    pub(crate) no_wait_after: Option<bool>,
+    pub(crate) strict: Option<bool>
 }
 
 impl<'a> SelectOptionArgs<'a> {
@@ -880,7 +1068,8 @@ impl<'a> SelectOptionArgs<'a> {
             options: None,
             elements: None,
             timeout: None,
-            no_wait_after: None
+            no_wait_after: None,
+            strict: None
         }
     }
 }
@@ -893,7 +1082,8 @@ pub(crate) struct SetInputFilesArgs<'a> {
 
     pub(crate) files: Vec<File>,
     pub(crate) timeout: Option<f64>,
-    pub(crate) no_wait_after: Option<bool>
+    pub(crate) no_wait_after: Option<bool>,
+    pub(crate) strict: Option<bool>
 }
 
 impl<'a> SetInputFilesArgs<'a> {
@@ -902,7 +1092,8 @@ impl<'a> SetInputFilesArgs<'a> {
             selector,
             files: Vec::new(),
             timeout: None,
-            no_wait_after: None
+            no_wait_after: None,
+            strict: None
         }
     }
 }
@@ -946,6 +1137,81 @@ impl<'a> WaitForFunctionArgs<'a> {
     }
 }
 
+#[skip_serializing_none]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ExpectArgs<'a> {
+    selector: &'a str,
+    expression: &'a str,
+    pub(crate) expression_arg: Option<Value>,
+    pub(crate) expected_text: Option<Vec<ExpectedTextValue>>,
+    pub(crate) expected_number: Option<f64>,
+    pub(crate) expected_value: Option<Value>,
+    pub(crate) use_inner_text: Option<bool>,
+    pub(crate) is_not: bool,
+    pub(crate) timeout: Option<f64>
+}
+
+impl<'a> ExpectArgs<'a> {
+    pub(crate) fn new(selector: &'a str, expression: &'a str) -> Self {
+        Self {
+            selector,
+            expression,
+            expression_arg: None,
+            expected_text: None,
+            expected_number: None,
+            expected_value: None,
+            use_inner_text: None,
+            is_not: false,
+            timeout: None
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ExpectedTextValue {
+    pub(crate) string: Option<String>,
+    pub(crate) regex_source: Option<String>,
+    pub(crate) regex_flags: Option<String>,
+    pub(crate) match_substring: Option<bool>,
+    pub(crate) ignore_case: Option<bool>,
+    pub(crate) normalize_white_space: Option<bool>
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExpectResponse {
+    matches: bool,
+    received: Option<Value>,
+    #[serde(default)]
+    log: Vec<String>
+}
+
+#[derive(Debug)]
+pub(crate) struct ExpectResult {
+    pub(crate) matches: bool,
+    pub(crate) received: Option<Value>,
+    pub(crate) log: Vec<String>
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct WaitForUrlArgs {
+    pub(crate) timeout: Option<f64>,
+    pub(crate) wait_until: Option<DocumentLoadState>
+}
+
+impl From<DocumentLoadState> for LifecycleEvent {
+    fn from(s: DocumentLoadState) -> Self {
+        match s {
+            DocumentLoadState::DomContentLoaded => Self::Domcontentloaded,
+            DocumentLoadState::Load => Self::Load,
+            DocumentLoadState::NetworkIdle => Self::Networkidle
+        }
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct Initializer {
@@ -961,7 +1227,21 @@ pub struct FrameNavigatedEvent {
     url: String,
     name: String,
     new_document: Option<Document>,
-    error: Option<String>
+    error: Option<String>,
+    #[serde(skip)]
+    request: Option<Weak<Request>>
+}
+
+impl FrameNavigatedEvent {
+    pub fn url(&self) -> &str { &self.url }
+
+    pub fn name(&self) -> &str { &self.name }
+
+    /// Whether a new document was committed. `false` for same-document navigations, such as anchor links.
+    pub fn is_new_document(&self) -> bool { self.new_document.is_some() }
+
+    /// Error text when the navigation failed
+    pub fn error(&self) -> Option<&str> { self.error.as_deref() }
 }
 
 #[derive(Debug, Deserialize, Clone)]
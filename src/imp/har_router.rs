@@ -0,0 +1,137 @@
+use crate::imp::{
+    core::*,
+    local_utils::{HarAction, HarLookupArgs, LocalUtils},
+    prelude::*,
+    route::{FulfillArgs, Route, RouteHandler, RouteHandlerFn},
+    utils::{HarNotFound, Header, UrlMatcher}
+};
+
+/// Serves requests from a HAR file which the driver has opened
+#[derive(Debug)]
+pub(crate) struct HarRouter {
+    local_utils: Weak<LocalUtils>,
+    har_id: String,
+    not_found: HarNotFound
+}
+
+impl HarRouter {
+    pub(crate) async fn open(
+        local_utils: Weak<LocalUtils>,
+        har: &Path,
+        not_found: HarNotFound
+    ) -> ArcResult<Arc<Self>> {
+        let har_id = upgrade(&local_utils)?.har_open(har).await?;
+        Ok(Arc::new(Self {
+            local_utils,
+            har_id,
+            not_found
+        }))
+    }
+
+    /// Handles requests matching `url`. Those which are not in the HAR are passed to `fallback` unless they are aborted.
+    pub(crate) fn handler<F, Fut>(self: Arc<Self>, url: UrlMatcher, fallback: F) -> RouteHandler
+    where
+        F: Fn(Weak<Route>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static
+    {
+        let fallback = Arc::new(fallback);
+        let f: RouteHandlerFn = Arc::new(move |route| {
+            let this = self.clone();
+            let fallback = fallback.clone();
+            Box::pin(async move {
+                let handled = match this.handle(&route).await {
+                    Ok(handled) => handled,
+                    Err(e) => {
+                        log::error!("Routing from HAR failed: {}", e);
+                        // The route must not be left pending
+                        this.not_found(&route).await.unwrap_or_else(|e| {
+                            log::error!("Failed to abort route: {}", e);
+                            true
+                        })
+                    }
+                };
+                if !handled {
+                    fallback(route).await;
+                }
+            })
+        });
+        RouteHandler::new(url, f)
+    }
+
+    /// Returns `false` when the request should fall back
+    async fn handle(&self, route: &Weak<Route>) -> ArcResult<bool> {
+        let route = upgrade(route)?;
+        let request = upgrade(&route.request())?;
+        let headers = request
+            .headers()
+            .iter()
+            .map(|(name, value)| Header {
+                name: name.clone(),
+                value: value.clone()
+            })
+            .collect();
+        let args = HarLookupArgs {
+            har_id: &self.har_id,
+            url: request.url(),
+            method: request.method(),
+            headers,
+            post_data: request.post_data().map(base64::encode),
+            is_navigation_request: request.is_navigation_request()
+        };
+        let res = upgrade(&self.local_utils)?.har_lookup(args).await?;
+        match res.action {
+            HarAction::Redirect => {
+                let url = res.redirect_url.as_deref().unwrap_or_default();
+                route.redirect_navigation_request(url).await?;
+                return Ok(true);
+            }
+            HarAction::Fulfill => {
+                let mut args = FulfillArgs::new(res.body.as_deref().unwrap_or_default(), true);
+                args.status = res.status;
+                args.headers = res.headers;
+                route.fulfill(args).await?;
+                return Ok(true);
+            }
+            HarAction::Error => {
+                log::warn!("HAR lookup failed: {}", res.message.unwrap_or_default())
+            }
+            HarAction::NoEntry => {}
+        }
+        self.not_found(&Arc::downgrade(&route)).await
+    }
+
+    /// Returns `false` when the request should fall back
+    async fn not_found(&self, route: &Weak<Route>) -> ArcResult<bool> {
+        match self.not_found {
+            HarNotFound::Abort => {
+                upgrade(route)?.abort(None).await?;
+                Ok(true)
+            }
+            HarNotFound::Fallback => Ok(false)
+        }
+    }
+
+    /// Releases the HAR in the driver. Called when the page or the browser context closes.
+    pub(crate) fn close(&self, ctx: &Context) {
+        if let Some(local_utils) = self.local_utils.upgrade() {
+            local_utils.har_close(ctx, &self.har_id);
+        }
+    }
+}
+
+pub(crate) fn is_zip(path: &Path) -> bool {
+    path.extension()
+        .map(|e| e.eq_ignore_ascii_case("zip"))
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct RouteFromHarArgs {
+    pub(crate) url: Option<UrlMatcher>,
+    pub(crate) not_found: Option<HarNotFound>,
+    pub(crate) update: Option<bool>
+}
+
+impl RouteFromHarArgs {
+    pub(crate) fn url(&self) -> UrlMatcher { self.url.clone().unwrap_or_else(|| "**/*".into()) }
+}
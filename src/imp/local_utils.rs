@@ -0,0 +1,126 @@
+use crate::imp::{core::*, prelude::*, utils::Header};
+
+/// Helpers which the driver runs on the client machine, such as reading HAR files
+#[derive(Debug)]
+pub(crate) struct LocalUtils {
+    channel: ChannelOwner
+}
+
+impl LocalUtils {
+    pub(crate) fn new(channel: ChannelOwner) -> Self { Self { channel } }
+
+    /// Returns the id of the opened HAR, which is either a `.har` or a `.zip` file
+    pub(crate) async fn har_open(&self, file: &Path) -> ArcResult<String> {
+        #[derive(Serialize)]
+        struct Args<'a> {
+            file: &'a Path
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Res {
+            har_id: Option<String>,
+            error: Option<String>
+        }
+        let v = send_message!(self, "harOpen", Args { file });
+        let Res { har_id, error } = serde_json::from_value((*v).clone()).map_err(Error::Serde)?;
+        match (har_id, error) {
+            (Some(id), _) => Ok(id),
+            (None, error) => Err(Error::HarOpen(error.unwrap_or_default()).into())
+        }
+    }
+
+    pub(crate) async fn har_lookup(&self, args: HarLookupArgs<'_>) -> ArcResult<HarLookup> {
+        let v = send_message!(self, "harLookup", args);
+        let res = serde_json::from_value((*v).clone()).map_err(Error::Serde)?;
+        Ok(res)
+    }
+
+    /// Sent without waiting for the reply, because this is called while an event is handled
+    pub(crate) fn har_close(&self, ctx: &Context, har_id: &str) {
+        let mut params = Map::new();
+        params.insert("harId".into(), har_id.into());
+        let m: Str<Method> = "harClose".to_owned().try_into().unwrap();
+        let r = self.channel().create_request(m).set_params(params);
+        ctx.send_message_later(r);
+    }
+
+    /// Extracts the HAR of `zip_file` into `har_file` with its resources next to it
+    pub(crate) async fn har_unzip(&self, zip_file: &Path, har_file: &Path) -> ArcResult<()> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Args<'a> {
+            zip_file: &'a Path,
+            har_file: &'a Path
+        }
+        let _ = send_message!(self, "harUnzip", Args { zip_file, har_file });
+        Ok(())
+    }
+}
+
+impl RemoteObject for LocalUtils {
+    fn channel(&self) -> &ChannelOwner { &self.channel }
+    fn channel_mut(&mut self) -> &mut ChannelOwner { &mut self.channel }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HarLookupArgs<'a> {
+    pub(crate) har_id: &'a str,
+    pub(crate) url: &'a str,
+    pub(crate) method: &'a str,
+    pub(crate) headers: Vec<Header>,
+    /// base64
+    pub(crate) post_data: Option<String>,
+    pub(crate) is_navigation_request: bool
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HarLookup {
+    pub(crate) action: HarAction,
+    pub(crate) message: Option<String>,
+    #[serde(rename = "redirectURL")]
+    pub(crate) redirect_url: Option<String>,
+    pub(crate) status: Option<i32>,
+    pub(crate) headers: Option<Vec<Header>>,
+    /// base64
+    pub(crate) body: Option<String>
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum HarAction {
+    Error,
+    Redirect,
+    Fulfill,
+    NoEntry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn har_lookup() {
+        let v = serde_json::json!({
+            "action": "redirect",
+            "redirectURL": "https://example.com/"
+        });
+        let res: HarLookup = serde_json::from_value(v).unwrap();
+        assert_eq!(res.action, HarAction::Redirect);
+        assert_eq!(res.redirect_url.as_deref(), Some("https://example.com/"));
+        let v = serde_json::json!({
+            "action": "fulfill",
+            "status": 200,
+            "headers": [{ "name": "content-type", "value": "text/html" }],
+            "body": "PGh0bWw+"
+        });
+        let res: HarLookup = serde_json::from_value(v).unwrap();
+        assert_eq!(res.action, HarAction::Fulfill);
+        assert_eq!(res.headers.unwrap()[0].name, "content-type");
+        let res: HarLookup =
+            serde_json::from_value(serde_json::json!({ "action": "noentry" })).unwrap();
+        assert_eq!(res.action, HarAction::NoEntry);
+    }
+}
@@ -1,23 +1,30 @@
 use crate::imp::{
+    binding_call::{BindingCall, BindingFn, Bindings},
     browser_context::BrowserContext,
     console_message::ConsoleMessage,
     core::*,
+    dialog::Dialog,
     download::Download,
     element_handle::may_save,
     file_hooser::FileChooser,
     frame::Frame,
+    har_router::{HarRouter, RouteFromHarArgs},
     prelude::*,
     request::Request,
     response::Response,
+    route::{Route, RouteHandler, Routes},
+    screenshot::{Masks, ScreenshotOptions},
     utils::{
-        ColorScheme, DocumentLoadState, FloatRect, Header, Length, MouseButton, PdfMargins,
-        ScreenshotType, Viewport
+        ColorScheme, DocumentLoadState, FloatRect, HarNotFound, Header, Length, MouseButton,
+        PaperFormat, PdfMargins, ScreenshotType, UrlMatcher, Viewport
     },
     video::Video,
     websocket::WebSocket,
     worker::Worker
 };
 use crate::protocol::generated::LifecycleEvent;
+use futures::io::{AsyncWrite, AsyncWriteExt};
+use tokio::sync::mpsc;
 
 #[derive(Debug)]
 pub(crate) struct Page {
@@ -25,7 +32,9 @@ pub(crate) struct Page {
     main_frame: Weak<Frame>,
     browser_context: Weak<BrowserContext>,
     var: Mutex<Variable>,
-    tx: Mutex<Option<broadcast::Sender<Evt>>>
+    tx: Mutex<Option<broadcast::Sender<Evt>>>,
+    /// Receivers are the dialog handlers. Dialogs are dismissed while there are none.
+    dialog_tx: broadcast::Sender<Weak<Dialog>>
 }
 
 #[derive(Debug, Default)]
@@ -35,7 +44,11 @@ pub(crate) struct Variable {
     timeout: Option<u32>,
     navigation_timeout: Option<u32>,
     workers: Vec<Weak<Worker>>,
-    video: Option<Video>
+    video: Option<Video>,
+    routes: Routes,
+    bindings: Bindings,
+    /// HARs which are open until the page closes
+    har_routers: Vec<Arc<HarRouter>>
 }
 
 macro_rules! navigation {
@@ -80,7 +93,7 @@ macro_rules! mouse_down {
 }
 
 impl Page {
-    const DEFAULT_TIMEOUT: u32 = 30000;
+    pub(crate) const DEFAULT_TIMEOUT: u32 = 30000;
 
     pub(crate) fn try_new(ctx: &Context, channel: ChannelOwner) -> Result<Self, Error> {
         let Initializer {
@@ -102,7 +115,8 @@ impl Page {
             main_frame,
             browser_context,
             var,
-            tx: Mutex::default()
+            tx: Mutex::default(),
+            dialog_tx: broadcast::channel(16).0
         })
     }
 
@@ -241,14 +255,43 @@ impl Page {
 
     pub(crate) async fn pdf(
         &self,
-        args: PdfArgs<'_, '_, '_, '_, '_, '_, '_, '_, '_, '_>
+        args: PdfArgs<'_, '_, '_, '_, '_, '_, '_, '_, '_>
     ) -> ArcResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.pdf_to_writer(args, &mut bytes).await?;
+        Ok(bytes)
+    }
+
+    /// Decodes the PDF into `writer` chunk by chunk, and into `path` if it is given
+    pub(crate) async fn pdf_to_writer<W>(
+        &self,
+        args: PdfArgs<'_, '_, '_, '_, '_, '_, '_, '_, '_>,
+        writer: &mut W
+    ) -> ArcResult<()>
+    where
+        W: AsyncWrite + Unpin
+    {
+        // base64 decodes every 4 characters into 3 bytes
+        const CHUNK: usize = 4 * 16 * 1024;
+        if let Some(ranges) = args.page_ranges {
+            validate_page_ranges(ranges)?;
+        }
         let path = args.path.clone();
         let v = send_message!(self, "pdf", args);
         let b64 = only_str(&v)?;
-        let bytes = base64::decode(b64).map_err(Error::InvalidBase64)?;
-        may_save(path.as_deref(), &bytes)?;
-        Ok(bytes)
+        let mut file = match path {
+            Some(p) => Some(std::fs::File::create(p).map_err(Error::from)?),
+            None => None
+        };
+        for chunk in b64.as_bytes().chunks(CHUNK) {
+            let bytes = base64::decode(chunk).map_err(Error::InvalidBase64)?;
+            if let Some(file) = &mut file {
+                std::io::Write::write_all(file, &bytes).map_err(Error::from)?;
+            }
+            writer.write_all(&bytes).await.map_err(Error::from)?;
+        }
+        writer.flush().await.map_err(Error::from)?;
+        Ok(())
     }
 
     pub(crate) async fn close(&self, run_before_unload: Option<bool>) -> Result<(), Arc<Error>> {
@@ -263,9 +306,15 @@ impl Page {
         Ok(())
     }
 
-    pub(crate) async fn screenshot(&self, args: ScreenshotArgs) -> ArcResult<Vec<u8>> {
+    pub(crate) async fn screenshot(&self, mut args: ScreenshotArgs) -> ArcResult<Vec<u8>> {
         let path = args.path.clone();
-        let v = send_message!(self, "screenshot", args);
+        let masks = Masks::new(&mut args.options, Some(self.main_frame()));
+        let v = masks
+            .cover(|mask| async move {
+                args.options.driver_mask = mask;
+                Ok(send_message!(self, "screenshot", args))
+            })
+            .await?;
         let b64 = only_str(&v)?;
         let bytes = base64::decode(b64).map_err(Error::InvalidBase64)?;
         may_save(path.as_deref(), &bytes)?;
@@ -302,6 +351,13 @@ impl Page {
         let _ = send_message!(self, "setExtraHTTPHeaders", args);
         Ok(())
     }
+
+    async fn set_network_interception_enabled(&self, enabled: bool) -> ArcResult<()> {
+        let mut args = Map::new();
+        args.insert("enabled".into(), enabled.into());
+        let _ = send_message!(self, "setNetworkInterceptionEnabled", args);
+        Ok(())
+    }
 }
 
 // mutable
@@ -394,7 +450,178 @@ impl Page {
 
     pub(crate) fn video(&self) -> Option<Video> { self.var.lock().unwrap().video.clone() }
 
+    pub(crate) async fn route(&self, handler: RouteHandler) -> ArcResult<()> {
+        let (rx, first) = {
+            let routes = &mut self.var.lock().unwrap().routes;
+            let first = routes.is_empty();
+            routes.push(handler);
+            (routes.subscribe(), first)
+        };
+        if let Some(rx) = rx {
+            let this = get_object!(self.context()?.lock().unwrap(), self.guid(), Page)?;
+            spawn(Self::dispatch_routes(this, rx));
+        }
+        if first {
+            self.set_network_interception_enabled(true).await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn unroute(&self, matcher: &UrlMatcher) -> ArcResult<()> {
+        let emptied = {
+            let routes = &mut self.var.lock().unwrap().routes;
+            let had = !routes.is_empty();
+            routes.remove(matcher);
+            had && routes.is_empty()
+        };
+        if emptied {
+            self.set_network_interception_enabled(false).await?;
+        }
+        Ok(())
+    }
+
+    /// Requests which fall back go to the routes of the browser context
+    pub(crate) async fn route_from_har(&self, har: &Path, args: RouteFromHarArgs) -> ArcResult<()> {
+        let bc = upgrade(&self.browser_context)?;
+        if args.update.unwrap_or_default() {
+            return bc
+                .record_into_har(har, Some(self.guid()), &args.url())
+                .await;
+        }
+        let not_found = args.not_found.unwrap_or(HarNotFound::Abort);
+        let router = HarRouter::open(bc.local_utils()?, har, not_found).await?;
+        self.var.lock().unwrap().har_routers.push(router.clone());
+        let bc = self.browser_context.clone();
+        let handler = router.handler(args.url(), move |route| {
+            let bc = bc.clone();
+            async move {
+                if let Some(bc) = bc.upgrade() {
+                    bc.handle_route(route).await;
+                }
+            }
+        });
+        self.route(handler).await
+    }
+
+    async fn dispatch_routes(this: Weak<Self>, mut rx: mpsc::UnboundedReceiver<Weak<Route>>) {
+        while let Some(route) = rx.recv().await {
+            let this = match this.upgrade() {
+                Some(x) => x,
+                None => break
+            };
+            spawn(async move { this.handle_route(route).await });
+        }
+    }
+
+    async fn handle_route(&self, route: Weak<Route>) {
+        let url = route
+            .upgrade()
+            .and_then(|r| r.request().upgrade())
+            .map(|r| r.url().to_owned());
+        let handler = url.and_then(|u| self.var.lock().unwrap().routes.find(&u));
+        if let Some(handler) = handler {
+            handler.handle(route).await;
+        } else if let Some(bc) = self.browser_context.upgrade() {
+            bc.handle_route(route).await;
+        }
+    }
+
+    pub(crate) async fn expose_binding(&self, name: &str, f: BindingFn) -> ArcResult<()> {
+        let in_context = self
+            .browser_context
+            .upgrade()
+            .map(|c| c.has_binding(name))
+            .unwrap_or_default();
+        if in_context || self.has_binding(name) {
+            return Err(Error::FunctionAlreadyRegistered(name.into()).into());
+        }
+        let rx = {
+            let bindings = &mut self.var.lock().unwrap().bindings;
+            bindings.insert(name.into(), f);
+            bindings.subscribe()
+        };
+        if let Some(rx) = rx {
+            let this = get_object!(self.context()?.lock().unwrap(), self.guid(), Page)?;
+            spawn(Self::dispatch_binding_calls(this, rx));
+        }
+        let mut args = Map::new();
+        args.insert("name".into(), name.into());
+        let _ = send_message!(self, "exposeBinding", args);
+        Ok(())
+    }
+
+    pub(crate) fn has_binding(&self, name: &str) -> bool {
+        self.var.lock().unwrap().bindings.contains(name)
+    }
+
+    async fn dispatch_binding_calls(
+        this: Weak<Self>,
+        mut rx: mpsc::UnboundedReceiver<Weak<BindingCall>>
+    ) {
+        while let Some(call) = rx.recv().await {
+            let this = match this.upgrade() {
+                Some(x) => x,
+                None => break
+            };
+            spawn(async move { this.handle_binding_call(call).await });
+        }
+    }
+
+    async fn handle_binding_call(&self, call: Weak<BindingCall>) {
+        let f = call
+            .upgrade()
+            .and_then(|c| self.var.lock().unwrap().bindings.get(c.name()));
+        match (f, call.upgrade()) {
+            (Some(f), Some(call)) => {
+                if let Err(e) = call.call(f).await {
+                    log::warn!("Failed to respond to binding call: {}", e);
+                }
+            }
+            _ => {
+                if let Some(bc) = self.browser_context.upgrade() {
+                    bc.handle_binding_call(call).await;
+                }
+            }
+        }
+    }
+
+    fn on_dialog(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        let first = first_object(&params).ok_or(Error::InvalidParams)?;
+        let OnlyGuid { guid } = serde_json::from_value((*first).clone())?;
+        let dialog = get_object!(ctx, &guid, Dialog)?;
+        if self.dialog_tx.send(dialog.clone()).is_err() {
+            upgrade(&dialog)?.respond_default(ctx);
+        }
+        self.emit_event(Evt::Dialog(dialog));
+        Ok(())
+    }
+
+    pub(crate) fn subscribe_dialog(&self) -> broadcast::Receiver<Weak<Dialog>> {
+        self.dialog_tx.subscribe()
+    }
+
+    fn on_binding(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        let binding = params.get("binding").ok_or(Error::InvalidParams)?;
+        let call = get_object!(ctx, guid_from_params(binding)?, BindingCall)?;
+        if !self.var.lock().unwrap().bindings.emit(call) {
+            log::warn!("Binding call received without bindings");
+        }
+        Ok(())
+    }
+
+    fn on_route(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        let route = params.get("route").ok_or(Error::InvalidParams)?;
+        let route = get_object!(ctx, guid_from_params(route)?, Route)?;
+        if !self.var.lock().unwrap().routes.emit(route) {
+            log::warn!("Route event received without handlers");
+        }
+        Ok(())
+    }
+
     fn on_close(&self, ctx: &Context) -> Result<(), Error> {
+        for router in std::mem::take(&mut self.var.lock().unwrap().har_routers) {
+            router.close(ctx);
+        }
         let bc = match self.browser_context().upgrade() {
             None => return Ok(()),
             Some(b) => b
@@ -598,6 +825,10 @@ impl RemoteObject for Page {
             "download" => self.on_download(ctx, params)?,
             "video" => self.on_video(ctx, params)?,
             "filechooser" => self.on_file_chooser(ctx, params)?,
+            "route" => self.on_route(ctx, params)?,
+            "bindingCall" => self.on_binding(ctx, params)?,
+            "dialog" => self.on_dialog(ctx, params)?,
+            "pageError" => self.emit_event(Evt::PageError(JsError::try_new(params)?)),
             _ => {}
         }
         Ok(())
@@ -609,14 +840,12 @@ pub(crate) enum Evt {
     Close,
     Crash,
     Console(Weak<ConsoleMessage>),
-    /// Not Implemented Yet
-    Dialog,
+    Dialog(Weak<Dialog>),
     Download(Arc<Download>),
     /// Not Implemented Yet
     // FileChooser(FileChooser),
     DomContentLoaded,
-    /// Not Implemented Yet
-    PageError,
+    PageError(JsError),
     Request(Weak<Request>),
     Response(Weak<Response>),
     RequestFailed(Weak<Request>),
@@ -669,11 +898,11 @@ impl IsEvent for Evt {
             Self::Close => EventType::Close,
             Self::Crash => EventType::Crash,
             Self::Console(_) => EventType::Console,
-            Self::Dialog => EventType::Dialog,
+            Self::Dialog(_) => EventType::Dialog,
             Self::Download(_) => EventType::Download,
             // Self::FileChooser(_) => EventType::FileChooser,
             Self::DomContentLoaded => EventType::DomContentLoaded,
-            Self::PageError => EventType::PageError,
+            Self::PageError(_) => EventType::PageError,
             Self::Request(_) => EventType::Request,
             Self::Response(_) => EventType::Response,
             Self::RequestFailed(_) => EventType::RequestFailed,
@@ -690,6 +919,74 @@ impl IsEvent for Evt {
     }
 }
 
+/// An uncaught exception in the page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsError {
+    /// `TypeError` for example. Empty when a value other than an `Error` is thrown.
+    pub name: String,
+    pub message: String,
+    pub stack: Option<String>
+}
+
+impl JsError {
+    fn try_new(params: Map<String, Value>) -> Result<Self, Error> {
+        #[derive(Deserialize)]
+        struct De {
+            error: SerializedError
+        }
+        #[derive(Deserialize)]
+        struct SerializedError {
+            error: Option<ErrorDe>,
+            value: Option<Value>
+        }
+        #[derive(Deserialize)]
+        struct ErrorDe {
+            #[serde(default)]
+            name: String,
+            message: String,
+            stack: Option<String>
+        }
+        let De { error } = serde_json::from_value(params.into())?;
+        if let Some(ErrorDe {
+            name,
+            message,
+            stack
+        }) = error.error
+        {
+            return Ok(Self {
+                name,
+                message,
+                stack
+            });
+        }
+        let value: Value = match &error.value {
+            Some(v) => de::from_value(v).map_err(Error::DeserializationPwJson)?,
+            None => Value::Null
+        };
+        let message = match value {
+            Value::String(s) => s,
+            v => v.to_string()
+        };
+        Ok(Self {
+            name: String::new(),
+            message,
+            stack: None
+        })
+    }
+}
+
+impl std::fmt::Display for JsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.name.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.name, self.message)
+        }
+    }
+}
+
+impl std::error::Error for JsError {}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct Initializer {
@@ -783,7 +1080,7 @@ pub(crate) struct ReloadArgs {
 #[skip_serializing_none]
 #[derive(Serialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct PdfArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+pub(crate) struct PdfArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
     pub(crate) scale: Option<f64>,
     pub(crate) display_header_footer: Option<bool>,
     pub(crate) header_template: Option<&'a str>,
@@ -791,15 +1088,44 @@ pub(crate) struct PdfArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
     pub(crate) print_background: Option<bool>,
     pub(crate) landscape: Option<bool>,
     pub(crate) page_ranges: Option<&'c str>,
-    pub(crate) format: Option<&'d str>,
-    pub(crate) width: Option<Length<'e>>,
-    pub(crate) height: Option<Length<'f>>,
+    pub(crate) format: Option<PaperFormat>,
+    pub(crate) width: Option<Length<'d>>,
+    pub(crate) height: Option<Length<'e>>,
     #[serde(rename = "preferCSSPageSize")]
     pub(crate) prefer_css_page_size: Option<bool>,
-    pub(crate) margin: Option<PdfMargins<'g, 'h, 'i, 'j>>,
+    pub(crate) margin: Option<PdfMargins<'f, 'g, 'h, 'i>>,
     pub(crate) path: Option<PathBuf>
 }
 
+/// Accepts comma separated pages and ranges such as `1-5, 8, 11-13`, whose ends may be omitted like `-3` and `10-`
+fn validate_page_ranges(ranges: &str) -> Result<(), Error> {
+    if ranges.trim().is_empty() {
+        return Ok(());
+    }
+    let page = |s: &str| -> Option<Option<u32>> {
+        match s.trim() {
+            "" => Some(None),
+            s if s.bytes().all(|b| b.is_ascii_digit()) => {
+                s.parse().ok().filter(|&n| n > 0).map(Some)
+            }
+            _ => None
+        }
+    };
+    let is_valid = |range: &str| match range.split_once('-') {
+        None => matches!(page(range), Some(Some(_))),
+        Some((start, end)) => match (page(start), page(end)) {
+            (Some(Some(s)), Some(Some(e))) => s <= e,
+            (Some(s), Some(e)) => s.is_some() || e.is_some(),
+            _ => false
+        }
+    };
+    if ranges.split(',').all(is_valid) {
+        Ok(())
+    } else {
+        Err(Error::InvalidPageRanges(ranges.to_owned()))
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Serialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -807,10 +1133,11 @@ pub(crate) struct ScreenshotArgs {
     pub(crate) timeout: Option<f64>,
     pub(crate) r#type: Option<ScreenshotType>,
     pub(crate) quality: Option<i32>,
-    pub(crate) omit_background: Option<bool>,
     pub(crate) full_page: Option<bool>,
     pub(crate) clip: Option<FloatRect>,
-    pub(crate) path: Option<PathBuf>
+    pub(crate) path: Option<PathBuf>,
+    #[serde(flatten)]
+    pub(crate) options: ScreenshotOptions
 }
 
 #[skip_serializing_none]
@@ -829,3 +1156,41 @@ pub enum Media {
     Print,
     Screen
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn page_ranges() {
+        for ok in ["", "1", "1-5, 8, 11-13", "-3", "10-", " 2 - 2 "] {
+            assert!(validate_page_ranges(ok).is_ok(), "{}", ok);
+        }
+        for ng in ["0", "a", "1,", "5-1", "-", "1-2-3", "1;2", "+1"] {
+            assert!(validate_page_ranges(ng).is_err(), "{}", ng);
+        }
+    }
+
+    #[test]
+    fn js_error() {
+        let params = json!({
+            "error": {
+                "error": {
+                    "name": "TypeError",
+                    "message": "x is undefined",
+                    "stack": "TypeError: x is undefined\n    at <anonymous>:1:1"
+                }
+            }
+        });
+        let e = JsError::try_new(params.as_object().unwrap().clone()).unwrap();
+        assert_eq!(e.name, "TypeError");
+        assert_eq!(e.to_string(), "TypeError: x is undefined");
+        assert!(e.stack.unwrap().contains("<anonymous>"));
+        let params = json!({ "error": { "value": { "s": "thrown" } } });
+        let e = JsError::try_new(params.as_object().unwrap().clone()).unwrap();
+        assert_eq!(e.name, "");
+        assert_eq!(e.to_string(), "thrown");
+        assert_eq!(e.stack, None);
+    }
+}
@@ -1,7 +1,13 @@
 use crate::{
     api::{browser::ContextBuilder, browser_type::PersistentContextLauncher},
     imp::{
-        browser_type::BrowserType, core::*, impl_future::*, prelude::*, selectors::Selectors,
+        api_request_context::{ApiRequestContext, NewRequestArgs},
+        browser::Browser,
+        browser_type::BrowserType,
+        core::*,
+        impl_future::*,
+        prelude::*,
+        selectors::Selectors,
         utils::Viewport
     },
     protocol::generated::playwright as protocol
@@ -16,7 +22,8 @@ pub(crate) struct Playwright {
     firefox: Weak<BrowserType>,
     webkit: Weak<BrowserType>,
     selectors: Weak<Selectors>,
-    devices: Vec<DeviceDescriptor>
+    devices: Vec<DeviceDescriptor>,
+    pre_launched_browser: Option<Weak<Browser>>
 }
 
 impl Playwright {
@@ -43,13 +50,18 @@ impl Playwright {
             .map(TryInto::try_into)
             .collect::<Result<Vec<DeviceDescriptor>, ()>>()
             .map_err(|_| Error::InitializationError)?;
+        let pre_launched_browser = match pre_launched_browser {
+            Some(b) => Some(get_object!(ctx, &b.guid, Browser)?),
+            None => None
+        };
         Ok(Self {
             channel,
             chromium,
             firefox,
             webkit,
             selectors,
-            devices
+            devices,
+            pre_launched_browser
         })
     }
 
@@ -67,6 +79,21 @@ impl Playwright {
 
     pub(crate) fn selectors(&self) -> Weak<Selectors> { self.selectors.clone() }
 
+    /// Only present when connected to a server
+    pub(crate) fn pre_launched_browser(&self) -> Option<Weak<Browser>> {
+        self.pre_launched_browser.clone()
+    }
+
+    pub(crate) async fn new_request(
+        &self,
+        args: NewRequestArgs<'_, '_>
+    ) -> ArcResult<Weak<ApiRequestContext>> {
+        let res = send_message!(self, "newRequest", args);
+        let guid = only_guid(&res)?;
+        let r = get_object!(self.context()?.lock().unwrap(), guid, ApiRequestContext)?;
+        Ok(r)
+    }
+
     pub(crate) async fn wait_initial_object(conn: &Connection) -> Result<Weak<Self>, Error> {
         let root = {
             let ctx = upgrade(&conn.context())?;
@@ -1,4 +1,10 @@
-use crate::imp::{core::*, prelude::*, request::Request, utils::Header};
+use crate::imp::{
+    core::*,
+    prelude::*,
+    request::Request,
+    utils::{Header, UrlMatcher}
+};
+use tokio::sync::mpsc;
 
 #[derive(Debug)]
 pub(crate) struct Route {
@@ -18,7 +24,7 @@ impl Route {
     pub(crate) async fn abort(&self, err_code: Option<&str>) -> Result<(), Arc<Error>> {
         let mut args = HashMap::new();
         if let Some(x) = err_code {
-            args.insert("errCode", x);
+            args.insert("errorCode", x);
         }
         let _ = send_message!(self, "abort", args);
         Ok(())
@@ -33,6 +39,83 @@ impl Route {
         let _ = send_message!(self, "continue", args);
         Ok(())
     }
+
+    pub(crate) async fn redirect_navigation_request(&self, url: &str) -> ArcResult<()> {
+        let mut args = HashMap::new();
+        args.insert("url", url);
+        let _ = send_message!(self, "redirectNavigationRequest", args);
+        Ok(())
+    }
+}
+
+pub(crate) type RouteHandlerFn =
+    Arc<dyn Fn(Weak<Route>) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
+#[derive(Clone)]
+pub(crate) struct RouteHandler {
+    matcher: UrlMatcher,
+    handler: RouteHandlerFn
+}
+
+impl RouteHandler {
+    pub(crate) fn new(matcher: UrlMatcher, handler: RouteHandlerFn) -> Self {
+        Self { matcher, handler }
+    }
+
+    pub(crate) fn matcher(&self) -> &UrlMatcher { &self.matcher }
+
+    pub(crate) async fn handle(&self, route: Weak<Route>) { (self.handler)(route).await }
+}
+
+impl std::fmt::Debug for RouteHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RouteHandler")
+            .field("matcher", &self.matcher)
+            .finish()
+    }
+}
+
+/// Handlers registered by Page.route or BrowserContext.route.
+/// The last registered handler takes precedence.
+#[derive(Debug, Default)]
+pub(crate) struct Routes {
+    handlers: Vec<RouteHandler>,
+    tx: Option<mpsc::UnboundedSender<Weak<Route>>>
+}
+
+impl Routes {
+    pub(crate) fn is_empty(&self) -> bool { self.handlers.is_empty() }
+
+    pub(crate) fn push(&mut self, handler: RouteHandler) { self.handlers.insert(0, handler); }
+
+    pub(crate) fn remove(&mut self, matcher: &UrlMatcher) {
+        self.handlers.retain(|h| h.matcher() != matcher);
+    }
+
+    pub(crate) fn find(&self, url: &str) -> Option<RouteHandler> {
+        self.handlers
+            .iter()
+            .find(|h| h.matcher().is_match(url))
+            .cloned()
+    }
+
+    /// Opens the channel of route events.
+    /// The receiver is returned only once and its owner is responsible for dispatching.
+    pub(crate) fn subscribe(&mut self) -> Option<mpsc::UnboundedReceiver<Weak<Route>>> {
+        if self.tx.is_some() {
+            return None;
+        }
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.tx = Some(tx);
+        Some(rx)
+    }
+
+    pub(crate) fn emit(&self, route: Weak<Route>) -> bool {
+        match &self.tx {
+            Some(tx) => tx.send(route).is_ok(),
+            None => false
+        }
+    }
 }
 
 impl RemoteObject for Route {
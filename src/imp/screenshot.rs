@@ -0,0 +1,204 @@
+use crate::imp::{
+    core::*,
+    element_handle::ElementHandle,
+    frame::Frame,
+    prelude::*,
+    utils::{ScreenshotAnimations, ScreenshotCaret, ScreenshotScale}
+};
+
+/// Options which screenshots of pages and elements have in common
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ScreenshotOptions {
+    pub(crate) omit_background: Option<bool>,
+    pub(crate) animations: Option<ScreenshotAnimations>,
+    pub(crate) caret: Option<ScreenshotCaret>,
+    pub(crate) scale: Option<ScreenshotScale>,
+    /// Filled in by [`Masks::cover`] from `mask`
+    #[serde(rename = "mask")]
+    pub(crate) driver_mask: Option<Vec<DriverMask>>,
+    #[serde(skip)]
+    pub(crate) mask: Option<Vec<MaskTarget>>,
+    #[serde(skip)]
+    pub(crate) mask_color: Option<String>
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum MaskTarget {
+    /// Resolved in the main frame of a page, or in the frame of an element
+    Selector(String),
+    Locator {
+        frame: Weak<Frame>,
+        selector: String
+    },
+    Element(Weak<ElementHandle>)
+}
+
+/// A mask which the driver paints
+#[derive(Debug, Serialize)]
+pub(crate) struct DriverMask {
+    frame: OnlyGuid,
+    selector: String
+}
+
+/// Covers masked elements while a screenshot is taken.
+///
+/// The driver paints the masks, finding them by a frame and a selector. ElementHandles are given a temporary attribute
+/// so that a selector matches them. The driver has no option for the color of the masks though, so a custom color
+/// falls back to overlays which are added to the page and removed afterwards. Those are placed by the bounding boxes of
+/// the elements, which may be off for fixed or transformed elements and elements in scroll containers.
+pub(crate) struct Masks {
+    targets: Vec<MaskTarget>,
+    frame: Option<Weak<Frame>>,
+    color: Option<String>
+}
+
+impl Masks {
+    const MARK: &'static str = "(e, id) => e.setAttribute('data-playwright-mask-id', id)";
+    const UNMARK: &'static str = "e => e.removeAttribute('data-playwright-mask-id')";
+    const ADD: &'static str = r#"(elements, color) => {
+        for (const e of [].concat(elements)) {
+            const doc = e.ownerDocument;
+            const r = e.getBoundingClientRect();
+            const m = doc.createElement('div');
+            m.setAttribute('data-playwright-mask', '');
+            m.style.cssText = `position: absolute; left: ${r.left + doc.defaultView.scrollX}px;
+                top: ${r.top + doc.defaultView.scrollY}px; width: ${r.width}px; height: ${r.height}px;
+                background: ${color}; z-index: 2147483647; pointer-events: none;`;
+            doc.documentElement.appendChild(m);
+        }
+    }"#;
+    const REMOVE: &'static str = r#"(e) => {
+        const doc = e ? e.ownerDocument : document;
+        doc.querySelectorAll('[data-playwright-mask]').forEach(m => m.remove());
+    }"#;
+
+    /// Takes `mask` and `mask_color` out of `options`. `frame` resolves [`MaskTarget::Selector`].
+    pub(crate) fn new(options: &mut ScreenshotOptions, frame: Option<Weak<Frame>>) -> Self {
+        Self {
+            targets: options.mask.take().unwrap_or_default(),
+            frame,
+            color: options.mask_color.take()
+        }
+    }
+
+    /// Runs `screenshot` with the masks for the driver while the elements are marked or covered
+    pub(crate) async fn cover<T, F, Fut>(&self, screenshot: F) -> ArcResult<T>
+    where
+        F: FnOnce(Option<Vec<DriverMask>>) -> Fut,
+        Fut: Future<Output = ArcResult<T>>
+    {
+        if self.targets.is_empty() {
+            return screenshot(None).await;
+        }
+        let res = match &self.color {
+            None => match self.mark().await {
+                Ok(masks) => screenshot(Some(masks)).await,
+                Err(e) => Err(e)
+            },
+            Some(color) => match self.add(color).await {
+                Ok(()) => screenshot(None).await,
+                Err(e) => Err(e)
+            }
+        };
+        if let Err(e) = self.clean().await {
+            log::warn!("Failed to clean up masks: {}", e);
+        }
+        res
+    }
+
+    async fn mark(&self) -> ArcResult<Vec<DriverMask>> {
+        let mut masks = Vec::with_capacity(self.targets.len());
+        for target in &self.targets {
+            let mask = match self.resolve(target)? {
+                Resolved::Selector(frame, selector) => DriverMask {
+                    frame: OnlyGuid {
+                        guid: frame.guid().to_owned()
+                    },
+                    selector: selector.to_owned()
+                },
+                Resolved::Element(e) => {
+                    let frame = e.owner_frame().await?.ok_or(Error::ObjectNotFound)?;
+                    let id = e.guid().as_str().to_owned();
+                    evaluate(&e, Self::MARK, &id).await?;
+                    DriverMask {
+                        frame: OnlyGuid {
+                            guid: upgrade(&frame)?.guid().to_owned()
+                        },
+                        selector: format!("[data-playwright-mask-id=\"{}\"]", id)
+                    }
+                }
+            };
+            masks.push(mask);
+        }
+        Ok(masks)
+    }
+
+    async fn add(&self, color: &str) -> ArcResult<()> {
+        for target in &self.targets {
+            match self.resolve(target)? {
+                Resolved::Selector(frame, selector) => {
+                    let _: Value = frame
+                        .evaluate_on_selector_all(selector, Self::ADD, Some(color))
+                        .await?;
+                }
+                Resolved::Element(e) => evaluate(&e, Self::ADD, color).await?
+            }
+        }
+        Ok(())
+    }
+
+    /// Unmarks the elements, or removes the overlays from every document which has some
+    async fn clean(&self) -> ArcResult<()> {
+        for target in &self.targets {
+            match (self.resolve(target)?, &self.color) {
+                (Resolved::Selector(..), None) => {}
+                (Resolved::Element(e), None) => evaluate(&e, Self::UNMARK, ()).await?,
+                (Resolved::Selector(frame, _), Some(_)) => {
+                    let _: Value = frame.evaluate(Self::REMOVE, None::<()>).await?;
+                }
+                (Resolved::Element(e), Some(_)) => evaluate(&e, Self::REMOVE, ()).await?
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve<'b>(&self, target: &'b MaskTarget) -> ArcResult<Resolved<'b>> {
+        let r = match target {
+            MaskTarget::Selector(selector) => {
+                let frame = self.frame.as_ref().ok_or(Error::ObjectNotFound)?;
+                Resolved::Selector(upgrade(frame)?, selector)
+            }
+            MaskTarget::Locator { frame, selector } => {
+                Resolved::Selector(upgrade(frame)?, selector)
+            }
+            MaskTarget::Element(e) => Resolved::Element(upgrade(e)?)
+        };
+        Ok(r)
+    }
+}
+
+enum Resolved<'a> {
+    Selector(Arc<Frame>, &'a str),
+    Element(Arc<ElementHandle>)
+}
+
+/// Calls the function with the element as the first argument
+async fn evaluate<T: Serialize>(e: &ElementHandle, expression: &str, arg: T) -> ArcResult<()> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        expression: &'a str,
+        is_function: bool,
+        arg: Value
+    }
+    let arg = ser::to_value(&arg).map_err(Error::SerializationPwJson)?;
+    let args = Args {
+        expression,
+        is_function: true,
+        arg
+    };
+    let _ = send_message!(e, "evaluateExpression", args);
+    Ok(())
+}
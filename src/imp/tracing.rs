@@ -0,0 +1,101 @@
+use crate::imp::{core::*, prelude::*};
+
+#[derive(Debug)]
+pub(crate) struct Tracing {
+    channel: ChannelOwner,
+    var: Mutex<Variable>
+}
+
+#[derive(Debug, Default)]
+struct Variable {
+    include_sources: bool
+}
+
+impl Tracing {
+    pub(crate) fn new(channel: ChannelOwner) -> Self {
+        Self {
+            channel,
+            var: Mutex::default()
+        }
+    }
+
+    pub(crate) async fn start(&self, args: StartArgs<'_, '_>) -> ArcResult<()> {
+        self.var.lock().unwrap().include_sources = args.sources.unwrap_or_default();
+        let title = args.title;
+        let _ = send_message!(self, "tracingStart", args);
+        self.start_chunk(title).await
+    }
+
+    pub(crate) async fn start_chunk(&self, title: Option<&str>) -> ArcResult<()> {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        struct Args<'a> {
+            title: Option<&'a str>
+        }
+        let _ = send_message!(self, "tracingStartChunk", Args { title });
+        Ok(())
+    }
+
+    pub(crate) async fn stop_chunk(&self, path: Option<&Path>) -> ArcResult<()> {
+        let mode = match path {
+            None => StopChunkMode::DoNotSave,
+            Some(_) if self.var.lock().unwrap().include_sources => {
+                StopChunkMode::CompressTraceAndSources
+            }
+            Some(_) => StopChunkMode::CompressTrace
+        };
+        #[derive(Serialize)]
+        struct Args {
+            mode: StopChunkMode
+        }
+        #[derive(Deserialize)]
+        struct Res {
+            artifact: Option<OnlyGuid>
+        }
+        let v = send_message!(self, "tracingStopChunk", Args { mode });
+        let path = match path {
+            Some(path) => path,
+            None => return Ok(())
+        };
+        let Res { artifact } = serde_json::from_value((*v).clone()).map_err(Error::Serde)?;
+        // The artifact may be missing if the browser closed while stopping
+        let guid = match artifact {
+            Some(OnlyGuid { guid }) => guid,
+            None => return Ok(())
+        };
+        let artifact = get_object!(self.context()?.lock().unwrap(), &guid, Artifact)?;
+        let artifact = upgrade(&artifact)?;
+        artifact.save_as(path).await?;
+        artifact.delete().await
+    }
+
+    pub(crate) async fn stop(&self, path: Option<&Path>) -> ArcResult<()> {
+        self.stop_chunk(path).await?;
+        let _ = send_message!(self, "tracingStop", Map::new());
+        Ok(())
+    }
+}
+
+impl RemoteObject for Tracing {
+    fn channel(&self) -> &ChannelOwner { &self.channel }
+    fn channel_mut(&mut self) -> &mut ChannelOwner { &mut self.channel }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Default)]
+pub(crate) struct StartArgs<'a, 'b> {
+    pub(crate) name: Option<&'a str>,
+    #[serde(skip)]
+    pub(crate) title: Option<&'b str>,
+    pub(crate) snapshots: Option<bool>,
+    pub(crate) screenshots: Option<bool>,
+    pub(crate) sources: Option<bool>
+}
+
+#[derive(Debug, Serialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+enum StopChunkMode {
+    DoNotSave,
+    CompressTrace,
+    CompressTraceAndSources
+}
@@ -167,6 +167,31 @@ pub enum ScreenshotType {
     Png
 }
 
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreenshotAnimations {
+    /// Fast-forwards finite animations to their end and cancels infinite ones to their initial state
+    Disabled,
+    Allow
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreenshotCaret {
+    /// Hides the text caret
+    Hide,
+    Initial
+}
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreenshotScale {
+    /// A single pixel per CSS pixel, which keeps screenshots small on high-dpi devices
+    Css,
+    /// A single pixel per device pixel
+    Device
+}
+
 #[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 pub enum ElementState {
@@ -187,6 +212,34 @@ pub enum WaitForSelectorState {
     Hidden
 }
 
+/// What routing from a HAR does with requests which are not in the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HarNotFound {
+    /// Abort the request
+    Abort,
+    /// Pass the request on to other route handlers, or to the network
+    Fallback
+}
+
+/// How response bodies are stored in a recorded HAR
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum HarContentPolicy {
+    Omit,
+    /// Inline into the HAR as base64
+    Embed,
+    /// Store as separate files next to the HAR, or as entries of the `.zip`
+    Attach
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum HarMode {
+    Full,
+    /// Only the information which is needed to route from the HAR, without sizes, timings, pages and cookies
+    Minimal
+}
+
 #[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
 pub struct Header {
     pub name: String,
@@ -216,6 +269,33 @@ impl<'a> From<&'a str> for Length<'a> {
     fn from(x: &'a str) -> Self { Self::WithUnit(x) }
 }
 
+/// Paper size of a PDF
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum PaperFormat {
+    /// 8.5in x 11in
+    Letter,
+    /// 8.5in x 14in
+    Legal,
+    /// 11in x 17in
+    Tabloid,
+    /// 17in x 11in
+    Ledger,
+    /// 33.1in x 46.8in
+    A0,
+    /// 23.4in x 33.1in
+    A1,
+    /// 16.54in x 23.4in
+    A2,
+    /// 11.7in x 16.54in
+    A3,
+    /// 8.27in x 11.7in
+    A4,
+    /// 5.83in x 8.27in
+    A5,
+    /// 4.13in x 5.83in
+    A6
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, PartialEq, Clone)]
 pub struct PdfMargins<'a, 'b, 'c, 'd> {
@@ -287,3 +367,170 @@ pub struct ResponseTiming {
     /// Time immediately after the browser starts requesting the resource from the server, cache, or local resource. The value\nis given in milliseconds relative to `startTime`, -1 if not available.
     pub response_start: f64
 }
+
+/// Matches request URLs for routing and waiting.
+///
+/// A glob pattern can be given as a string, e.g. `**/api/*.json`. `*` matches any characters except `/`, `**` matches
+/// any characters including `/`, `?` matches a single character and `{a,b}` matches either alternative.
+#[derive(Clone)]
+pub enum UrlMatcher {
+    Glob(Glob),
+    Regex(regex::Regex),
+    Predicate(Arc<dyn Fn(&str) -> bool + Send + Sync>)
+}
+
+impl UrlMatcher {
+    pub fn predicate<F>(f: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static
+    {
+        Self::Predicate(Arc::new(f))
+    }
+
+    pub fn is_match(&self, url: &str) -> bool {
+        match self {
+            Self::Glob(glob) => glob.is_match(url),
+            Self::Regex(r) => r.is_match(url),
+            Self::Predicate(f) => f(url)
+        }
+    }
+}
+
+impl std::fmt::Debug for UrlMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Glob(x) => f.debug_tuple("Glob").field(&x.as_str()).finish(),
+            Self::Regex(x) => f.debug_tuple("Regex").field(x).finish(),
+            Self::Predicate(_) => f.write_str("Predicate(_)")
+        }
+    }
+}
+
+impl PartialEq for UrlMatcher {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Glob(a), Self::Glob(b)) => a.as_str() == b.as_str(),
+            (Self::Regex(a), Self::Regex(b)) => a.as_str() == b.as_str(),
+            (Self::Predicate(a), Self::Predicate(b)) => Arc::ptr_eq(a, b),
+            _ => false
+        }
+    }
+}
+
+impl From<&str> for UrlMatcher {
+    fn from(x: &str) -> Self { Self::Glob(Glob::new(x)) }
+}
+
+impl From<String> for UrlMatcher {
+    fn from(x: String) -> Self { Self::Glob(Glob::new(x)) }
+}
+
+impl From<regex::Regex> for UrlMatcher {
+    fn from(x: regex::Regex) -> Self { Self::Regex(x) }
+}
+
+/// A glob pattern of [`UrlMatcher`], which is compiled once when it is created.
+#[derive(Debug, Clone)]
+pub struct Glob {
+    pattern: String,
+    /// `None` when the pattern is invalid, e.g. has an unclosed `{`, and matches nothing
+    regex: Option<regex::Regex>
+}
+
+impl Glob {
+    pub fn new<S: Into<String>>(pattern: S) -> Self {
+        let pattern = pattern.into();
+        let regex = glob_to_regex(&pattern).ok();
+        Self { pattern, regex }
+    }
+
+    pub fn as_str(&self) -> &str { &self.pattern }
+
+    pub fn is_match(&self, url: &str) -> bool {
+        self.regex
+            .as_ref()
+            .map(|r| r.is_match(url))
+            .unwrap_or_default()
+    }
+}
+
+fn glob_to_regex(glob: &str) -> Result<regex::Regex, regex::Error> {
+    let chars: Vec<char> = glob.chars().collect();
+    let mut tokens = String::from("^");
+    let mut in_group = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '*' => {
+                let before_deep = i.checked_sub(1).map(|j| chars[j]);
+                let mut star_count = 1;
+                while chars.get(i + 1) == Some(&'*') {
+                    star_count += 1;
+                    i += 1;
+                }
+                let after_deep = chars.get(i + 1).copied();
+                let is_deep = star_count > 1
+                    && matches!(before_deep, None | Some('/'))
+                    && matches!(after_deep, None | Some('/'));
+                if is_deep {
+                    tokens.push_str("((?:[^/]*(?:/|$))*)");
+                    i += 1;
+                } else {
+                    tokens.push_str("([^/]*)");
+                }
+            }
+            '?' => tokens.push('.'),
+            '{' => {
+                in_group = true;
+                tokens.push('(');
+            }
+            '}' => {
+                in_group = false;
+                tokens.push(')');
+            }
+            ',' if in_group => tokens.push('|'),
+            _ => tokens.push_str(&regex::escape(&c.to_string()))
+        }
+        i += 1;
+    }
+    tokens.push('$');
+    regex::Regex::new(&tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob() {
+        let m = |glob: &str, url: &str| UrlMatcher::from(glob).is_match(url);
+        assert!(m("**/*.js", "https://localhost:8080/foo.js"));
+        assert!(!m("**/*.css", "https://localhost:8080/foo.js"));
+        assert!(m("*.js", "foo.js"));
+        assert!(!m("*.js", "https://localhost:8080/foo.js"));
+        assert!(m(
+            "http://localhost:8080/?imple/path.js",
+            "http://localhost:8080/Simple/path.js"
+        ));
+        assert!(m("**/{a,b}.js", "https://localhost:8080/a.js"));
+        assert!(m("**/{a,b}.js", "https://localhost:8080/b.js"));
+        assert!(!m("**/{a,b}.js", "https://localhost:8080/c.js"));
+        assert!(m("**/api/**", "https://localhost:8080/api/v1/users"));
+        assert!(m("https://example.com/", "https://example.com/"));
+        assert!(!m("https://example.com/", "https://example.com/?q=1"));
+        assert!(!m("**/{a,b.js", "https://localhost:8080/a.js"));
+    }
+
+    #[test]
+    fn url_matcher_eq() {
+        let f = UrlMatcher::predicate(|u| u.ends_with(".png"));
+        assert_eq!(f, f.clone());
+        assert_ne!(f, UrlMatcher::predicate(|u| u.ends_with(".png")));
+        assert_eq!(
+            UrlMatcher::from("**/*"),
+            UrlMatcher::from("**/*".to_owned())
+        );
+        assert!(f.is_match("https://example.com/a.png"));
+    }
+}
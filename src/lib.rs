@@ -5,9 +5,12 @@ extern crate serde;
 extern crate serde_with;
 
 pub mod api;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod har;
 mod imp;
 
-pub use crate::imp::core::{Driver, Error};
+pub use crate::imp::core::{Driver, Error, ErrorKind, ErrorMessage};
 pub use api::playwright::Playwright;
 
 #[doc(hidden)]
@@ -0,0 +1,106 @@
+#![cfg(feature = "blocking")]
+use playwright::blocking::{Browser, Playwright};
+
+fn launch() -> (Playwright, Browser) {
+    let playwright = Playwright::initialize().unwrap();
+    playwright.install_chromium().unwrap();
+    let browser = playwright
+        .chromium()
+        .launcher()
+        .headless(true)
+        .launch()
+        .unwrap();
+    (playwright, browser)
+}
+
+#[test]
+fn evaluate() {
+    let (_playwright, browser) = launch();
+    let context = browser.context_builder().build().unwrap();
+    let page = context.new_page().unwrap();
+    assert_eq!(context.pages().unwrap(), vec![page.clone()]);
+    page.set_content_builder("<title>blocking</title><p>hello</p>")
+        .set_content()
+        .unwrap();
+    assert_eq!(page.title().unwrap(), "blocking");
+    let sum: i32 = page.evaluate("([a, b]) => a + b", (1, 2)).unwrap();
+    assert_eq!(sum, 3);
+    let href: String = page.eval("() => location.href").unwrap();
+    assert_eq!(href, "about:blank");
+    context.close().unwrap();
+    browser.close().unwrap();
+}
+
+#[test]
+fn locator() {
+    let (_playwright, browser) = launch();
+    let page = browser
+        .context_builder()
+        .build()
+        .unwrap()
+        .new_page()
+        .unwrap();
+    page.set_content_builder(
+        r#"<input id="name"><button onclick="this.textContent = document.querySelector('#name').value">go</button>"#
+    )
+    .set_content()
+    .unwrap();
+    page.locator("#name").fill_builder("rust").fill().unwrap();
+    let button = page.locator("button");
+    button.click_builder().click().unwrap();
+    assert_eq!(button.inner_text(None).unwrap(), "rust");
+    assert!(button.is_visible(None).unwrap());
+    assert_eq!(page.locator("input, button").count().unwrap(), 2);
+    let err = page
+        .locator("input, button")
+        .click_builder()
+        .timeout(100.)
+        .click()
+        .unwrap_err();
+    assert!(err.is_strict_mode_violation(), "{:?}", err);
+    let err = page
+        .wait_for_selector_builder("#missing")
+        .timeout(100.)
+        .wait_for_selector()
+        .unwrap_err();
+    assert!(err.is_timeout(), "{:?}", err);
+}
+
+#[test]
+fn screenshot() {
+    let (_playwright, browser) = launch();
+    let page = browser
+        .context_builder()
+        .viewport(Some(playwright::api::Viewport {
+            width: 320,
+            height: 240
+        }))
+        .build()
+        .unwrap()
+        .new_page()
+        .unwrap();
+    let png = page.screenshot_builder().screenshot().unwrap();
+    assert_eq!(&png[1..4], b"PNG");
+}
+
+#[test]
+fn across_threads() {
+    let (playwright, browser) = launch();
+    let page = browser
+        .context_builder()
+        .build()
+        .unwrap()
+        .new_page()
+        .unwrap();
+    let title = std::thread::spawn(move || {
+        page.set_content_builder("<title>thread</title>")
+            .set_content()
+            .unwrap();
+        page.title().unwrap()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(title, "thread");
+    playwright.close().unwrap();
+    assert!(!browser.exists());
+}
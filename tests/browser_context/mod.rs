@@ -1,7 +1,12 @@
 use super::Which;
-use playwright::api::{
-    browser::RecordVideo, Browser, BrowserContext, BrowserType, Cookie, LocalStorageEntry,
-    OriginState, StorageState
+use playwright::{
+    api::{
+        browser::{RecordHar, RecordVideo},
+        expect, Browser, BrowserContext, BrowserType, Cookie, HarContentPolicy, HarMode,
+        HarNotFound, LocalStorageEntry, OriginState, StorageState
+    },
+    har::Har,
+    Playwright
 };
 
 pub async fn all(
@@ -19,6 +24,10 @@ pub async fn all(
     cookies_should_work(&c).await;
     add_init_script_should_work(&c).await;
     pages_should_work(&c).await;
+    request_should_work(&c, port).await;
+    tracing_should_work(&c, port).await;
+    route_from_har(browser, port).await;
+    record_har(browser, port).await;
     c
 }
 
@@ -192,3 +201,218 @@ async fn storage_state(c: &BrowserContext, port: u16) {
         ]
     );
 }
+
+async fn request_should_work(c: &BrowserContext, port: u16) {
+    let request = c.request();
+    let res = request
+        .get_builder(&super::url_static(port, "/form.html"))
+        .fetch()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), 200);
+    assert!(res.ok());
+    assert!(res.header_value("Content-Type").is_some());
+    expect(&res).to_be_ok().await.unwrap();
+    assert_eq!(
+        res.text().await.unwrap(),
+        std::fs::read_to_string("tests/server/form.html").unwrap()
+    );
+    res.dispose().await.unwrap();
+    assert!(res.body().await.is_err());
+    // cookies are shared with the browser context in both directions
+    let origin = super::origin(port);
+    c.add_cookies(&[Cookie::with_url("ctx", "from-context", origin.as_str())])
+        .await
+        .unwrap();
+    let echo = request
+        .get_builder(&super::url_cookie(port, "/echo"))
+        .fetch()
+        .await
+        .unwrap();
+    assert!(echo
+        .text()
+        .await
+        .unwrap()
+        .split("; ")
+        .any(|c| c == "ctx=from-context"));
+    request
+        .get_builder(&super::url_cookie(port, "/set"))
+        .fetch()
+        .await
+        .unwrap();
+    let cookies = c.cookies(&[origin]).await.unwrap();
+    assert!(cookies
+        .iter()
+        .any(|c| c.name == "api" && c.value == "from-response"));
+    let missing = request
+        .get_builder(&super::url_static(port, "/missing.html"))
+        .fetch()
+        .await
+        .unwrap();
+    assert_eq!(missing.status(), 404);
+    expect(&missing).not().to_be_ok().await.unwrap();
+    assert!(expect(&missing).to_be_ok().await.is_err());
+    assert!(request
+        .get_builder(&super::url_static(port, "/missing.html"))
+        .fail_on_status_code(true)
+        .fetch()
+        .await
+        .is_err());
+}
+
+pub async fn standalone_request(playwright: &Playwright, port: u16) {
+    let request = playwright
+        .request()
+        .new_context_builder()
+        .new_context()
+        .await
+        .unwrap();
+    let res = request
+        .get_builder(&super::url_static(port, "/form.html"))
+        .fetch()
+        .await
+        .unwrap();
+    assert_eq!(
+        res.text().await.unwrap(),
+        std::fs::read_to_string("tests/server/form.html").unwrap()
+    );
+    // cookies are kept in the request context
+    request
+        .get_builder(&super::url_cookie(port, "/set"))
+        .fetch()
+        .await
+        .unwrap();
+    let echo = request
+        .get_builder(&super::url_cookie(port, "/echo"))
+        .fetch()
+        .await
+        .unwrap();
+    assert_eq!(echo.text().await.unwrap(), "api=from-response");
+    request.dispose().await.unwrap();
+}
+
+async fn tracing_should_work(c: &BrowserContext, port: u16) {
+    let tracing = c.tracing();
+    tracing
+        .start_builder()
+        .screenshots(true)
+        .snapshots(true)
+        .start()
+        .await
+        .unwrap();
+    let page = c.new_page().await.unwrap();
+    page.goto_builder(&super::url_static(port, "/empty.html"))
+        .goto()
+        .await
+        .unwrap();
+    // `start` opens the first chunk
+    let chunk = super::temp_dir().join("trace-chunk.zip");
+    tracing.stop_chunk(Some(&chunk)).await.unwrap();
+    assert!(chunk.metadata().unwrap().len() > 0);
+    tracing.start_chunk(Some("chunk")).await.unwrap();
+    page.reload_builder().reload().await.unwrap();
+    let path = super::temp_dir().join("trace.zip");
+    tracing.stop(Some(&path)).await.unwrap();
+    assert!(path.metadata().unwrap().len() > 0);
+    page.close(None).await.unwrap();
+}
+
+async fn route_from_har(browser: &Browser, port: u16) {
+    let har = super::temp_dir().join("route_from_har.har");
+    let url = super::url_static(port, "/empty.html");
+    let c = browser.context_builder().build().await.unwrap();
+    c.route_from_har_builder(&har)
+        .url("**/empty.html")
+        .update(true)
+        .route_from_har()
+        .await
+        .unwrap();
+    let page = c.new_page().await.unwrap();
+    page.goto_builder(&url).goto().await.unwrap();
+    c.close().await.unwrap();
+    let recorded = Har::from_path(&har).unwrap();
+    assert_eq!(
+        recorded.entries().url("**/empty.html").status(200).count(),
+        1
+    );
+    assert_eq!(recorded.entries().url("**/empty2.html").count(), 0);
+    assert_eq!(recorded.summary().failed, 0);
+    // the replayed body differs from the live server
+    let mut json: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(&har).unwrap()).unwrap();
+    for entry in json["log"]["entries"].as_array_mut().unwrap() {
+        let content = entry["response"]["content"].as_object_mut().unwrap();
+        content.remove("encoding");
+        content.remove("_file");
+        content.insert("text".into(), "from har".into());
+        content.insert("size".into(), 8.into());
+    }
+    std::fs::write(&har, serde_json::to_vec(&json).unwrap()).unwrap();
+
+    let c = browser.context_builder().build().await.unwrap();
+    c.route_from_har_builder(&har)
+        .route_from_har()
+        .await
+        .unwrap();
+    let page = c.new_page().await.unwrap();
+    // empty2.html is not in the HAR, falls back to the context and is aborted there
+    page.route_from_har_builder(&har)
+        .url("**/empty2.html")
+        .not_found(HarNotFound::Fallback)
+        .route_from_har()
+        .await
+        .unwrap();
+    let response = page.goto_builder(&url).goto().await.unwrap().unwrap();
+    assert_eq!(response.status().unwrap(), 200);
+    assert_eq!(response.text().await.unwrap(), "from har");
+    let missing = super::url_static(port, "/empty2.html");
+    assert!(page.goto_builder(&missing).goto().await.is_err());
+    c.close().await.unwrap();
+}
+
+async fn record_har(browser: &Browser, port: u16) {
+    let dir = super::temp_dir();
+    let url = super::url_static(port, "/empty.html");
+    let har = dir.join("record_har.har");
+    let c = browser
+        .context_builder()
+        .record_har(RecordHar {
+            content: Some(HarContentPolicy::Omit),
+            mode: Some(HarMode::Minimal),
+            url_filter: Some("**/empty.html".into()),
+            ..RecordHar::new(&har)
+        })
+        .build()
+        .await
+        .unwrap();
+    let page = c.new_page().await.unwrap();
+    page.goto_builder(&url).goto().await.unwrap();
+    page.goto_builder(&super::url_static(port, "/empty2.html"))
+        .goto()
+        .await
+        .ok();
+    c.close().await.unwrap();
+    let recorded = Har::from_path(&har).unwrap();
+    assert_eq!(recorded.entries().count(), 1);
+    let entry = recorded.entries().next().unwrap();
+    assert!(entry.request.url.ends_with("/empty.html"));
+    assert_eq!(entry.response.content.text, None);
+
+    let zip = dir.join("record_har.zip");
+    let c = browser
+        .context_builder()
+        .record_har(RecordHar::new(&zip))
+        .build()
+        .await
+        .unwrap();
+    let page = c.new_page().await.unwrap();
+    page.goto_builder(&url).goto().await.unwrap();
+    c.close().await.unwrap();
+    let recorded = Har::from_path(&zip).unwrap();
+    let entry = recorded.entries().url("**/empty.html").next().unwrap();
+    assert!(entry.response.content.file.is_some());
+    assert_eq!(entry.response.content.body().unwrap(), None);
+    let recorded = Har::from_path_with_bodies(&zip).unwrap();
+    let entry = recorded.entries().url("**/empty.html").next().unwrap();
+    assert!(entry.response.content.body().unwrap().is_some());
+}
@@ -33,6 +33,7 @@ async fn should_handle_timeout(t: &BrowserType) {
     let result = t.launcher().timeout(0.1).launch().await;
     assert!(result.is_err());
     let err = result.err().unwrap();
+    assert!(err.is_timeout(), "{:?}", err);
     match &*err {
         playwright::Error::ErrorResponded(_) => {}
         e => {
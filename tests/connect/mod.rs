@@ -1,6 +1,103 @@
 use super::{free_local_port, install_browser, playwright_with_driver, Which};
 use playwright::api::{page, Browser, BrowserType};
 use serde::Deserialize;
+use std::process::{Child, Command};
+
+pub(super) async fn connect(which: Which) {
+    let mut playwright = playwright_with_driver().await;
+    install_browser(&playwright, which);
+    let browser_type = match which {
+        Which::Chromium => playwright.chromium(),
+        Which::Firefox => playwright.firefox(),
+        Which::Webkit => playwright.webkit()
+    };
+    let port = free_local_port().unwrap();
+    let mut server = run_server(&mut playwright, port);
+    let ws_endpoint = format!("ws://127.0.0.1:{}/", port);
+    let mut browser = None;
+    for _ in 0..50 {
+        match browser_type
+            .connect_builder(&ws_endpoint)
+            .timeout(1000.)
+            .connect()
+            .await
+        {
+            Ok(b) => {
+                browser = Some(b);
+                break;
+            }
+            Err(_) => tokio::time::sleep(std::time::Duration::from_millis(100)).await
+        }
+    }
+    let browser = browser.expect("run-server is not available");
+    let context = browser.context_builder().build().await.unwrap();
+    let page = context.new_page().await.unwrap();
+    page.set_content_builder("<p>remote</p>")
+        .set_content()
+        .await
+        .unwrap();
+    let text = page.text_content("p", None).await.unwrap();
+    assert_eq!(text.as_deref(), Some("remote"));
+    assert!(browser_type
+        .connect_builder("wss://127.0.0.1:1/")
+        .connect()
+        .await
+        .is_err());
+    browser.close().await.unwrap();
+    drop(browser);
+    server.kill().unwrap();
+    server.wait().unwrap();
+}
+
+fn run_server(playwright: &mut playwright::Playwright, port: u16) -> Child {
+    Command::new(playwright.driver().executable())
+        .args(&["run-server", "--port", &port.to_string()])
+        .spawn()
+        .unwrap()
+}
+
+pub(super) async fn launch_server(which: Which) {
+    let playwright = playwright_with_driver().await;
+    install_browser(&playwright, which);
+    let browser_type = match which {
+        Which::Chromium => playwright.chromium(),
+        Which::Firefox => playwright.firefox(),
+        Which::Webkit => playwright.webkit()
+    };
+    let server = browser_type
+        .launch_server_builder()
+        .ws_path("/launch-server")
+        .launch_server()
+        .await
+        .unwrap();
+    assert!(server.ws_endpoint().starts_with("ws://"));
+    assert!(server.ws_endpoint().ends_with("/launch-server"));
+    assert_ne!(server.process(), 0);
+    {
+        let browser = browser_type
+            .connect_builder(server.ws_endpoint())
+            .connect()
+            .await
+            .unwrap();
+        let context = browser.context_builder().build().await.unwrap();
+        let page = context.new_page().await.unwrap();
+        page.set_content_builder("<p>server</p>")
+            .set_content()
+            .await
+            .unwrap();
+        let text = page.text_content("p", None).await.unwrap();
+        assert_eq!(text.as_deref(), Some("server"));
+    }
+    server.close().await.unwrap();
+    assert!(browser_type
+        .connect_builder(server.ws_endpoint())
+        .timeout(1000.)
+        .connect()
+        .await
+        .is_err());
+    // Idempotent after the process exited
+    server.kill().await.unwrap();
+}
 
 pub(super) async fn connect_over_cdp(which: Which) {
     let playwright = playwright_with_driver().await;
@@ -90,15 +187,10 @@ async fn fetch_ws_endpoint(browser_type: &BrowserType, port: u16) -> String {
     let browser_context = browser.context_builder().build().await.unwrap();
     let page = browser_context.new_page().await.unwrap();
     let url = format!("http://localhost:{}/json/version/", port);
-    let (event, goto) = tokio::join!(
-        page.expect_event(page::EventType::Response),
-        page.goto_builder(&url).goto()
-    );
-    goto.unwrap();
-    let response = match event {
-        Ok(page::Event::Response(res)) => res,
-        _ => unreachable!()
-    };
+    let response = page
+        .expect_response(url.as_str(), || page.goto_builder(&url).goto())
+        .await
+        .unwrap();
     let text = response.text().await.unwrap();
     #[derive(Debug, Deserialize)]
     #[serde(rename_all = "camelCase")]
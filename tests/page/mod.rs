@@ -1,6 +1,9 @@
 use super::Which;
 use futures::stream::StreamExt;
-use playwright::api::{page, BrowserContext, File, Geolocation, Page, Viewport};
+use playwright::api::{
+    console_message::ConsoleType, dialog::DialogType, expect, page, BrowserContext,
+    DocumentLoadState, File, Geolocation, Page, PaperFormat, UrlMatcher, Viewport
+};
 
 macro_rules! concurrent {
     ($which:expr, $($e:expr),*) => {
@@ -23,8 +26,13 @@ pub async fn all(c: &BrowserContext, port: u16, which: Which) {
         navigations(&page, port).await;
     }
     front_should_work(c, &page).await;
+    route_should_work(c, port).await;
     concurrent!(
         which,
+        expose_should_work(c),
+        locator_should_work(c),
+        expect_should_work(c),
+        dialog_should_work(c),
         set_extra_http_headers(c, port),
         focus_should_work(c),
         reload_should_worker(c),
@@ -34,6 +42,9 @@ pub async fn all(c: &BrowserContext, port: u16, which: Which) {
         pointer(c),
         viewport(c),
         download(c, port),
+        expect_helpers(c, port),
+        navigation_waits(c, port),
+        page_error_and_console_args(c),
         workers_should_work(c, port, which),
         accessibility(c),
         query_selector_and_eval(c),
@@ -382,12 +393,107 @@ async fn screenshot_should_work(p: &Page) {
         .await
         .unwrap();
     assert!(path.is_file());
+    screenshot_options_should_work(p).await;
+}
+
+async fn screenshot_options_should_work(p: &Page) {
+    use playwright::api::{
+        FloatRect, Mask, ScreenshotAnimations, ScreenshotCaret, ScreenshotScale
+    };
+    use std::convert::TryInto;
+    p.set_content_builder(
+        r#"<div id="a" style="width: 100px; height: 50px">a</div><input id="b" value="b">"#
+    )
+    .set_content()
+    .await
+    .unwrap();
+    let a = p.query_selector("#a").await.unwrap().unwrap();
+    let png = p
+        .screenshot_builder()
+        .animations(ScreenshotAnimations::Disabled)
+        .caret(ScreenshotCaret::Hide)
+        .scale(ScreenshotScale::Css)
+        .mask(vec!["#b"])
+        .mask_color("#00FF00")
+        .screenshot()
+        .await
+        .unwrap();
+    assert_eq!(&png[1..4], b"PNG");
+    let unmasked = p
+        .screenshot_builder()
+        .animations(ScreenshotAnimations::Disabled)
+        .caret(ScreenshotCaret::Hide)
+        .scale(ScreenshotScale::Css)
+        .screenshot()
+        .await
+        .unwrap();
+    assert_ne!(png, unmasked);
+    // Overlays are removed after the screenshot
+    let masks: usize = p
+        .eval("() => document.querySelectorAll('[data-playwright-mask]').length")
+        .await
+        .unwrap();
+    assert_eq!(masks, 0);
+    // Masks without a color are painted by the driver
+    let png = p
+        .screenshot_builder()
+        .mask(vec![Mask::from("#b"), Mask::from(&a)])
+        .screenshot()
+        .await
+        .unwrap();
+    assert_eq!(&png[1..4], b"PNG");
+    let unmasked = p.screenshot_builder().screenshot().await.unwrap();
+    assert_ne!(png, unmasked);
+    let marked: usize = p
+        .eval("() => document.querySelectorAll('[data-playwright-mask-id]').length")
+        .await
+        .unwrap();
+    assert_eq!(marked, 0);
+    let png = a
+        .screenshot_builder()
+        .await
+        .clip(FloatRect {
+            x: 10.,
+            y: 10.,
+            width: 200.,
+            height: 20.
+        })
+        .mask(vec![&a])
+        .screenshot()
+        .await
+        .unwrap();
+    assert_eq!(&png[1..4], b"PNG");
+    // The clip is cut off at the right edge of the element
+    assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()), 90);
+    let png = p
+        .locator("#a")
+        .screenshot_builder()
+        .mask(vec![&p.locator("#b")])
+        .screenshot()
+        .await
+        .unwrap();
+    assert_eq!(&png[1..4], b"PNG");
 }
 
 async fn pdf_should_work(p: &Page) {
     let path = super::temp_dir().join("pdf.pdf");
-    p.pdf_builder().path(path.clone()).pdf().await.unwrap();
-    assert!(path.is_file());
+    let pdf = p.pdf_builder().path(path.clone()).pdf().await.unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+    assert_eq!(std::fs::read(&path).unwrap(), pdf);
+    let mut streamed = Vec::new();
+    p.pdf_builder()
+        .format(PaperFormat::A4)
+        .page_ranges("1")
+        .pdf_to_writer(&mut streamed)
+        .await
+        .unwrap();
+    assert!(streamed.starts_with(b"%PDF"));
+    let err = p.pdf_builder().page_ranges("3-1").pdf().await.unwrap_err();
+    assert!(
+        matches!(*err, playwright::Error::InvalidPageRanges(_)),
+        "{:?}",
+        err
+    );
 }
 
 async fn emulate_media(p: &Page) {
@@ -465,6 +571,193 @@ async fn pointer(c: &BrowserContext) {
     close(&p).await;
 }
 
+async fn route_should_work(c: &BrowserContext, port: u16) {
+    let page = new(c).await;
+    let url = super::url_static(port, "/empty.html");
+    page.route("**/*", |route| async move {
+        route
+            .fulfill_builder("<title>first</title>", false)
+            .await
+            .content_type("text/html")
+            .fulfill()
+            .await
+    })
+    .await
+    .unwrap();
+    page.route("**/empty.html", |route| async move {
+        route
+            .fulfill_builder("<title>last</title>", false)
+            .await
+            .content_type("text/html")
+            .fulfill()
+            .await
+    })
+    .await
+    .unwrap();
+    page.goto_builder(&url).goto().await.unwrap();
+    assert_eq!(page.title().await.unwrap(), "last");
+    page.unroute("**/empty.html").await.unwrap();
+    page.goto_builder(&url).goto().await.unwrap();
+    assert_eq!(page.title().await.unwrap(), "first");
+    page.unroute("**/*").await.unwrap();
+    c.route("**/empty.html", |route| async move { route.abort(None).await })
+        .await
+        .unwrap();
+    assert!(page.goto_builder(&url).goto().await.is_err());
+    c.unroute("**/empty.html").await.unwrap();
+    let response = page.goto_builder(&url).goto().await.unwrap();
+    assert_eq!(response.unwrap().status().unwrap(), 200);
+    close(&page).await;
+}
+
+async fn expose_should_work(c: &BrowserContext) {
+    let page = new(c).await;
+    page.expose_function(
+        "add",
+        |(a, b): (i32, i32)| async move { Ok::<_, String>(a + b) }
+    )
+    .await
+    .unwrap();
+    let sum: i32 = page.eval("() => window.add(1, 2)").await.unwrap();
+    assert_eq!(sum, 3);
+    page.expose_function(
+        "fail",
+        |(): ()| async move { Err::<(), _>("failed in rust") }
+    )
+    .await
+    .unwrap();
+    let message: String = page
+        .eval("() => window.fail().catch(e => e.message)")
+        .await
+        .unwrap();
+    assert!(message.contains("failed in rust"));
+    assert!(page
+        .expose_function("add", |(): ()| async move { Ok::<_, String>(()) })
+        .await
+        .is_err());
+    c.expose_binding("pageUrl", |source, (): ()| async move {
+        source.page.url().map_err(|e| e.to_string())
+    })
+    .await
+    .unwrap();
+    let url: String = page.eval("() => window.pageUrl()").await.unwrap();
+    assert_eq!(url, page.url().unwrap());
+    close(&page).await;
+}
+
+async fn locator_should_work(c: &BrowserContext) {
+    let page = new(c).await;
+    page.set_content_builder(
+        r#"<ul><li>one</li><li>two <b>bold</b></li></ul><input type="checkbox" /><input id="text" />"#
+    )
+    .set_content()
+    .await
+    .unwrap();
+    let items = page.locator("li");
+    assert_eq!(items.count().await.unwrap(), 2);
+    assert_eq!(
+        items.all_inner_texts().await.unwrap(),
+        vec!["one".to_owned(), "two bold".to_owned()]
+    );
+    assert_eq!(items.first().inner_text(None).await.unwrap(), "one");
+    assert_eq!(items.last().inner_text(None).await.unwrap(), "two bold");
+    assert_eq!(
+        items.nth(1).locator("b").inner_text(None).await.unwrap(),
+        "bold"
+    );
+    let filtered = items.filter(Some("TWO"), None).unwrap();
+    assert_eq!(filtered.count().await.unwrap(), 1);
+    let has = items.filter(None, Some(&page.locator("b"))).unwrap();
+    assert_eq!(has.inner_text(None).await.unwrap(), "two bold");
+    // strict mode violation
+    assert!(items.inner_text(Some(1000.)).await.is_err());
+    let checkbox = page.locator("input[type=checkbox]");
+    checkbox.check_builder().check().await.unwrap();
+    assert!(checkbox.is_checked(None).await.unwrap());
+    let text = page.locator("#text");
+    text.fill_builder("foo").fill().await.unwrap();
+    text.press_builder("End").press().await.unwrap();
+    // re-resolved after re-render
+    page.eval::<()>("() => document.querySelector('#text').outerHTML = '<input id=\"text\" />'")
+        .await
+        .unwrap();
+    text.fill_builder("bar").fill().await.unwrap();
+    assert_eq!(
+        text.evaluate::<(), String>("e => e.value", None)
+            .await
+            .unwrap(),
+        "bar"
+    );
+    assert!(!text
+        .screenshot_builder()
+        .screenshot()
+        .await
+        .unwrap()
+        .is_empty());
+    close(&page).await;
+}
+
+async fn dialog_should_work(c: &BrowserContext) {
+    let page = new(c).await;
+    // dismissed automatically without subscribers
+    let _: () = page.eval("() => alert('yo')").await.unwrap();
+    // event subscribers are not dialog handlers
+    let _events = page.subscribe_event().unwrap();
+    let _: () = page.eval("() => alert('yo')").await.unwrap();
+    let mut rx = page.subscribe_dialog().unwrap();
+    let handle = async {
+        let dialog = rx.next().await.unwrap();
+        assert_eq!(dialog.r#type().unwrap(), DialogType::Prompt);
+        assert_eq!(dialog.message().unwrap(), "question");
+        assert_eq!(dialog.default_value().unwrap(), "yes");
+        dialog.accept(Some("answer")).await.unwrap();
+    };
+    let (_, answer) = tokio::join!(
+        handle,
+        page.eval::<String>("() => prompt('question', 'yes')")
+    );
+    assert_eq!(answer.unwrap(), "answer");
+    page.close(None).await.unwrap();
+}
+
+async fn expect_should_work(c: &BrowserContext) {
+    let page = new(c).await;
+    page.set_content_builder(
+        r#"<title>expect</title><p class="a b">hello</p><input type="checkbox" checked />"#
+    )
+    .set_content()
+    .await
+    .unwrap();
+    // re-render after the assertion started polling
+    page.eval::<()>(
+        "() => setTimeout(() => document.querySelector('p').textContent = 'world', 100)"
+    )
+    .await
+    .unwrap();
+    let p = page.locator("p");
+    expect(&p).to_have_text("world").await.unwrap();
+    expect(&p).to_contain_text("orl").await.unwrap();
+    expect(&p).to_have_class("a b").await.unwrap();
+    expect(&p).to_have_count(1).await.unwrap();
+    expect(&p).to_be_visible().await.unwrap();
+    expect(&page.locator("input"))
+        .to_be_checked()
+        .await
+        .unwrap();
+    expect(&page.locator("span")).to_be_hidden().await.unwrap();
+    expect(&page).to_have_title("expect").await.unwrap();
+    let err = expect(&p)
+        .not()
+        .timeout(100.)
+        .to_have_text("world")
+        .await
+        .unwrap_err();
+    let message = err.to_string();
+    assert!(message.starts_with("Locator expected not to have text"));
+    assert!(message.contains("Received: \"world\""));
+    close(&page).await;
+}
+
 async fn new(c: &BrowserContext) -> Page {
     let page = c.new_page().await.unwrap();
     set_timeout(&page).await;
@@ -491,6 +784,160 @@ async fn input(c: &BrowserContext) {
     close(&p).await;
 }
 
+async fn expect_helpers(c: &BrowserContext, port: u16) {
+    let p = new(c).await;
+    let url = super::url_static(port, "/empty.html");
+    let request = p
+        .expect_request("**/empty.html", || p.goto_builder(&url).goto())
+        .await
+        .unwrap();
+    assert_eq!(request.url().unwrap(), url);
+    let response = p
+        .expect_response(url.as_str(), || p.reload_builder().reload())
+        .await
+        .unwrap();
+    assert!(response.ok().unwrap());
+    // dialogs opened while waiting are dismissed
+    let response = p
+        .expect_response(url.as_str(), || async {
+            p.eval::<()>("() => alert('waiting')").await?;
+            p.reload_builder().reload().await
+        })
+        .await
+        .unwrap();
+    assert!(response.ok().unwrap());
+    let message = p
+        .expect_console_message(|| p.eval::<i32>("() => { console.log('hello'); return 1; }"))
+        .await
+        .unwrap();
+    assert_eq!(message.text().unwrap(), "hello");
+    let (b, _) = tokio::join!(
+        p.wait_for_event(
+            page::EventType::Console,
+            |e| match e {
+                page::Event::Console(m) => m.text().unwrap() == "b",
+                _ => false
+            },
+            None
+        ),
+        p.eval::<i32>("() => { console.log('a'); console.log('b'); return 1; }")
+    );
+    match b.unwrap() {
+        page::Event::Console(m) => assert_eq!(m.text().unwrap(), "b"),
+        e => unreachable!("{:?}", e)
+    }
+    let popup = p
+        .expect_popup(|| p.eval::<i32>("() => { window.open('about:blank'); return 1; }"))
+        .await
+        .unwrap();
+    let opened = c
+        .expect_page(|| p.eval::<i32>("() => { window.open('about:blank'); return 1; }"))
+        .await
+        .unwrap();
+    assert_ne!(popup, opened);
+    let err = p
+        .wait_for_event(page::EventType::Download, |_| true, Some(10.))
+        .await
+        .unwrap_err();
+    assert!(err.is_timeout());
+    close(&opened).await;
+    close(&popup).await;
+    close(&p).await;
+}
+
+async fn navigation_waits(c: &BrowserContext, port: u16) {
+    let p = new(c).await;
+    let url = super::url_static(port, "/empty.html");
+    let url2 = super::url_static(port, "/empty2.html");
+    p.goto_builder(&url).goto().await.unwrap();
+    p.wait_for_load_state(None, None).await.unwrap();
+    p.wait_for_load_state(Some(DocumentLoadState::DomContentLoaded), None)
+        .await
+        .unwrap();
+    p.wait_for_url_builder("**/empty.html")
+        .wait_for_url()
+        .await
+        .unwrap();
+    let script = format!("() => {{ location.href = '{}'; return 1; }}", url2);
+    let (waited, _) = tokio::join!(
+        p.wait_for_url_builder(regex::Regex::new(r"empty2\.html$").unwrap())
+            .wait_until(DocumentLoadState::Load)
+            .wait_for_url(),
+        p.eval::<i32>(&script)
+    );
+    waited.unwrap();
+    assert_eq!(p.url().unwrap(), url2);
+    let script = format!("() => {{ location.href = '{}'; return 1; }}", url);
+    let response = p
+        .expect_navigation(|| p.eval::<i32>(&script))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.url().unwrap(), url);
+    let response = p
+        .expect_navigation(|| p.eval::<i32>("() => { location.hash = 'anchor'; return 1; }"))
+        .await
+        .unwrap();
+    assert!(response.is_none());
+    p.set_default_navigation_timeout(10).await.unwrap();
+    let err = p
+        .wait_for_url_builder(UrlMatcher::predicate(|u| u.ends_with("/nowhere")))
+        .wait_for_url()
+        .await
+        .unwrap_err();
+    assert!(err.is_timeout(), "{:?}", err);
+    close(&p).await;
+}
+
+async fn page_error_and_console_args(c: &BrowserContext) {
+    let p = new(c).await;
+    let (e, _) = tokio::join!(
+        p.expect_event(page::EventType::PageError),
+        p.eval::<i32>("() => { setTimeout(() => { throw new TypeError('boom'); }); return 1; }")
+    );
+    match e.unwrap() {
+        page::Event::PageError(e) => {
+            assert_eq!(e.name, "TypeError");
+            assert_eq!(e.message, "boom");
+            assert!(e.stack.unwrap().contains("boom"));
+        }
+        e => unreachable!("{:?}", e)
+    }
+    let message = p
+        .expect_console_message(|| {
+            p.eval::<i32>("() => { console.warn('hello', 5, {foo: 'bar'}); return 1; }")
+        })
+        .await
+        .unwrap();
+    assert_eq!(message.r#type().unwrap(), ConsoleType::Warning);
+    let mut args = message
+        .args()
+        .unwrap()
+        .into_iter()
+        .map(|a| a.js_handle().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(args.len(), 3);
+    assert_eq!(args[0].json_value::<String>().await.unwrap(), "hello");
+    assert_eq!(args[1].json_value::<i32>().await.unwrap(), 5);
+    let foo: std::collections::HashMap<String, String> = args[2].json_value().await.unwrap();
+    assert_eq!(foo["foo"], "bar");
+    let message = p
+        .expect_console_message(|| {
+            p.eval::<i32>("() => { console.log('a', document.body, 'b'); return 1; }")
+        })
+        .await
+        .unwrap();
+    let mut args = message.args().unwrap().into_iter();
+    let mut a = args.next().unwrap().js_handle().unwrap();
+    assert_eq!(a.json_value::<String>().await.unwrap(), "a");
+    let body = args.next().unwrap().element_handle().unwrap();
+    assert_eq!(body.inner_html().await.unwrap(), "");
+    let mut b = args.next().unwrap().js_handle().unwrap();
+    assert_eq!(b.json_value::<String>().await.unwrap(), "b");
+    assert!(args.next().is_none());
+    close(&p).await;
+}
+
 async fn set_extra_http_headers(c: &BrowserContext, port: u16) {
     let p = new(c).await;
     p.set_extra_http_headers(vec![("hoge".into(), "hoge".into())])
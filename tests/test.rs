@@ -36,6 +36,14 @@ playwright::runtime_test!(chromium_devices, devices(Which::Chromium).await);
 playwright::runtime_test!(firefox_devices, devices(Which::Chromium).await);
 // playwright::runtime_test!(webkit_devices, devices(Which::Webkit).await);
 
+playwright::runtime_test!(close, close_playwright(Which::Chromium).await);
+
+playwright::runtime_test!(request, api_request().await);
+
+playwright::runtime_test!(connect, connect::connect(Which::Chromium).await);
+
+playwright::runtime_test!(launch_server, connect::launch_server(Which::Chromium).await);
+
 playwright::runtime_test!(
     connect_over_cdp,
     connect::connect_over_cdp(Which::Chromium).await
@@ -68,6 +76,26 @@ async fn devices(which: Which) {
     devices::all(&playwright, port, which).await;
 }
 
+async fn close_playwright(which: Which) {
+    let playwright = playwright_with_driver().await;
+    install_browser(&playwright, which);
+    let browser_type = match which {
+        Which::Chromium => playwright.chromium(),
+        Which::Firefox => playwright.firefox(),
+        Which::Webkit => playwright.webkit()
+    };
+    let browser = browser_type.launcher().launch().await.unwrap();
+    playwright.close().await.unwrap();
+    assert!(!browser.exists());
+}
+
+async fn api_request() {
+    let port = free_local_port().unwrap();
+    start_test_server(port).await;
+    let playwright = playwright_with_driver().await;
+    browser_context::standalone_request(&playwright, port).await;
+}
+
 fn install_browser(p: &Playwright, which: Which) {
     match which {
         Which::Webkit => p.install_webkit(),
@@ -107,7 +135,12 @@ async fn start_test_server(port: u16) {
     let download = warp::path("download")
         .and(warp::fs::dir("tests/server"))
         .with(warp::reply::with::headers(headers));
-    let route = r#static.or(download);
+    let set_cookie = warp::path!("cookie" / "set")
+        .map(|| warp::reply::with_header("", "Set-Cookie", "api=from-response; Path=/"));
+    let echo_cookie = warp::path!("cookie" / "echo")
+        .and(warp::header::optional::<String>("cookie"))
+        .map(|cookie: Option<String>| cookie.unwrap_or_default());
+    let route = r#static.or(download).or(set_cookie).or(echo_cookie);
     spawn(async move {
         warp::serve(route).run(([127, 0, 0, 1], port)).await;
     });
@@ -126,6 +159,18 @@ async fn start_test_server(port: u16) {
         }))
         .serve_dir("tests/server/")
         .unwrap();
+    app.at("/cookie/set").get(|_| async {
+        let mut res = tide::Response::new(200);
+        res.insert_header("Set-Cookie", "api=from-response; Path=/");
+        Ok(res)
+    });
+    app.at("/cookie/echo")
+        .get(|req: tide::Request<()>| async move {
+            Ok(req
+                .header("Cookie")
+                .map(|v| v.to_string())
+                .unwrap_or_default())
+        });
     spawn(async move {
         app.listen(format!("127.0.0.1:{}", port)).await.unwrap();
     });
@@ -148,6 +193,10 @@ fn url_download(port: u16, path: &str) -> String {
     format!("http://localhost:{}/download{}", port, path)
 }
 
+fn url_cookie(port: u16, path: &str) -> String {
+    format!("http://localhost:{}/cookie{}", port, path)
+}
+
 fn origin(port: u16) -> String { format!("http://localhost:{}", port) }
 
 fn temp_dir() -> PathBuf { std::env::temp_dir().join("test-playwright-rust") }